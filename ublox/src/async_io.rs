@@ -0,0 +1,234 @@
+use std::{
+    collections::VecDeque,
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    io::{AsyncRead, AsyncWrite, AsyncWriteExt},
+    ready,
+    stream::Stream,
+};
+
+use crate::{
+    error::{MemWriterError, ParserError},
+    parser::Parser,
+    ubx_packets::{PacketOwned, UbxPacketCreator},
+};
+
+const READ_BUF_LEN: usize = 1024;
+
+/// Asynchronous version of `PacketReader`: a `Stream` of packets read from
+/// any `futures::io::AsyncRead`.
+///
+/// The stream ends when the reader reaches EOF. A packet that could not be
+/// parsed is reported as an error of kind `InvalidData` containing the
+/// `ParserError`, the stream can be polled further after that.
+///
+/// Tokio users can adapt their readers with the `compat` module of `tokio-util`.
+pub struct PacketStream<R> {
+    reader: R,
+    parser: Parser<Vec<u8>>,
+    read_buf: [u8; READ_BUF_LEN],
+    pending: VecDeque<Result<PacketOwned, ParserError>>,
+    eof: bool,
+}
+
+impl<R: AsyncRead + Unpin> PacketStream<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            parser: Parser::default(),
+            read_buf: [0; READ_BUF_LEN],
+            pending: VecDeque::new(),
+            eof: false,
+        }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the underlying reader. Buffered data which is not
+    /// returned as packet yet is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for PacketStream<R> {
+    type Item = io::Result<PacketOwned>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(packet) = this.pending.pop_front() {
+                return Poll::Ready(Some(
+                    packet.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+                ));
+            }
+            if this.eof {
+                return Poll::Ready(None);
+            }
+
+            match ready!(Pin::new(&mut this.reader).poll_read(cx, &mut this.read_buf)) {
+                Ok(0) => this.eof = true,
+                Ok(nbytes) => {
+                    let mut it = this.parser.consume(&this.read_buf[..nbytes]);
+                    while let Some(packet) = it.next() {
+                        this.pending.push_back(packet.map(|x| x.to_owned()));
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            }
+        }
+    }
+}
+
+/// Writes packets to any `futures::io::AsyncWrite`
+pub struct PacketWriter<W> {
+    writer: W,
+    buf: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> PacketWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Writes a packet created by a builder, for example
+    /// `CfgRateBuilder { .. }`
+    pub async fn write_packet<P: UbxPacketCreator>(&mut self, packet: P) -> io::Result<()> {
+        self.buf.clear();
+        packet
+            .create_packet(&mut self.buf)
+            .map_err(|err| match err {
+                MemWriterError::Custom(err) => err,
+                MemWriterError::NotEnoughMem => {
+                    io::Error::new(io::ErrorKind::OutOfMemory, "not enough memory")
+                }
+            })?;
+        self.writer.write_all(&self.buf).await?;
+        self.writer.flush().await
+    }
+
+    /// Writes already serialized packet bytes, like the output of
+    /// `into_packet_bytes()` or `into_packet_vec()`
+    pub async fn write_bytes(&mut self, packet: &[u8]) -> io::Result<()> {
+        self.writer.write_all(packet).await?;
+        self.writer.flush().await
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ubx_packets::*;
+    use futures::{channel::mpsc, executor::block_on, SinkExt, StreamExt, TryStreamExt};
+
+    static ACK_ACK: [u8; 10] = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x6, 0x1, 0xf, 0x38];
+
+    /// In-memory pipe: bytes sent to the returned sender can be read from the returned reader
+    fn pipe() -> (mpsc::Sender<io::Result<Vec<u8>>>, impl AsyncRead + Unpin) {
+        let (tx, rx) = mpsc::channel(16);
+        (tx, rx.into_async_read())
+    }
+
+    #[test]
+    fn stream_over_pipe() {
+        let (mut tx, rx) = pipe();
+        let mut stream = PacketStream::new(rx);
+
+        block_on(async {
+            let mut data = vec![0x24, 0x00];
+            data.extend_from_slice(&ACK_ACK);
+            data.extend_from_slice(&ACK_ACK[..4]);
+            tx.send(Ok(data)).await.unwrap();
+            tx.send(Ok(ACK_ACK[4..].to_vec())).await.unwrap();
+            tx.close_channel();
+
+            for _ in 0..2 {
+                match stream.next().await {
+                    Some(Ok(PacketOwned::AckAck(ack))) => {
+                        let ack = ack.as_packet_ref();
+                        assert_eq!((ack.class(), ack.msg_id()), (6, 1));
+                    }
+                    _ => panic!(),
+                }
+            }
+            assert!(stream.next().await.is_none());
+        });
+    }
+
+    #[test]
+    fn stream_invalid_packet() {
+        let mut data = ACK_ACK.to_vec();
+        data[7] = 5;
+        data.extend_from_slice(&ACK_ACK);
+        let mut stream = PacketStream::new(futures::io::Cursor::new(data));
+
+        block_on(async {
+            let err = stream.next().await.unwrap().unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            assert!(matches!(
+                stream.next().await,
+                Some(Ok(PacketOwned::AckAck(_)))
+            ));
+            assert!(stream.next().await.is_none());
+        });
+    }
+
+    #[test]
+    fn writer_roundtrip() {
+        let mut writer = PacketWriter::new(Vec::new());
+        let nav5 = CfgNav5Builder {
+            pacc: 21,
+            ..CfgNav5Builder::default()
+        };
+
+        block_on(async {
+            writer
+                .write_packet(CfgNav5Builder {
+                    pacc: 21,
+                    ..CfgNav5Builder::default()
+                })
+                .await
+                .unwrap();
+            writer.write_bytes(&ACK_ACK).await.unwrap();
+        });
+
+        let mut expect = nav5.into_packet_bytes().to_vec();
+        expect.extend_from_slice(&ACK_ACK);
+        assert_eq!(writer.get_ref(), &expect);
+
+        let stream = PacketStream::new(futures::io::Cursor::new(writer.into_inner()));
+        let packets: Vec<_> = block_on(stream.try_collect()).unwrap();
+        assert_eq!(packets.len(), 2);
+        match packets[0] {
+            PacketOwned::CfgNav5(ref packet) => assert_eq!(packet.as_packet_ref().pacc(), 21),
+            _ => panic!(),
+        }
+        assert!(matches!(packets[1], PacketOwned::AckAck(_)));
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    io::{self, Read, Write},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::DeviceError,
+    reader::PacketReader,
+    ubx_packets::{
+        LogInfo, LogRetrieve, LogRetrieveBuilder, LogRetrievePosExtraOwned, LogRetrievePosOwned,
+        LogRetrieveStringOwned, PacketOwned, PacketRef, UbxPacketMeta, UbxPacketRequest,
+        SYNC_CHAR_1, SYNC_CHAR_2,
+    },
+};
+
+/// Default time to wait for a response to a single attempt
+const DEFAULT_RESPONSE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Default number of retries after the first attempt
+const DEFAULT_RETRIES: u8 = 2;
+
+/// Class of the configuration packets, which are acknowledged even when polled
+const CFG_CLASS: u8 = 0x06;
+
+/// Driver for u-blox devices, on top of any transport which can be read and
+/// written, like a serial port or a TCP socket.
+///
+/// Takes care of matching `AckAck`/`AckNak` and poll responses to the sent packets.
+/// Packets received while waiting for a response, and packets received in
+/// `update`, are passed to the handler set by `set_handler`.
+///
+/// ```no_run
+/// # fn example(port: std::net::TcpStream) -> Result<(), ublox::DeviceError> {
+/// use std::time::Duration;
+/// use ublox::{CfgMsgAllPortsBuilder, Device, MonVer, NavPosVelTime, PacketOwned};
+///
+/// port.set_read_timeout(Some(Duration::from_millis(100)))?;
+/// let mut device = Device::new(port);
+/// device.set_handler(|packet| println!("{:?}", packet));
+/// device.send_with_ack(
+///     &CfgMsgAllPortsBuilder::set_rate_for::<NavPosVelTime>([0, 1, 0, 0, 0, 0]).into_packet_bytes(),
+/// )?;
+/// if let PacketOwned::MonVer(ver) = device.poll::<MonVer>()? {
+///     println!("SW version: {}", ver.as_packet_ref().software_version());
+/// }
+/// loop {
+///     device.update()?;
+/// }
+/// # }
+/// ```
+pub struct Device<T> {
+    reader: PacketReader<T>,
+    response_timeout: Duration,
+    retries: u8,
+    handler: Option<Box<dyn FnMut(PacketOwned) + Send>>,
+}
+
+/// How a received packet relates to the request
+enum Response {
+    Matched,
+    Rejected,
+    /// Late or duplicated response to an earlier request, dropped
+    Stale,
+    Unrelated,
+}
+
+/// Outcome of a single request attempt
+enum Reply {
+    Response(PacketOwned),
+    Nak,
+    Timeout,
+}
+
+impl<T: Read + Write> Device<T> {
+    /// The transport must not block forever on reads: give it a read timeout
+    /// (`set_read_timeout` for a `TcpStream`, the timeout of a serial port)
+    /// shorter than the response timeout, or make it non-blocking. Otherwise
+    /// the response timeout and the retries have no effect.
+    pub fn new(transport: T) -> Self {
+        Self {
+            reader: PacketReader::new(transport),
+            response_timeout: DEFAULT_RESPONSE_TIMEOUT,
+            retries: DEFAULT_RETRIES,
+            handler: None,
+        }
+    }
+
+    /// Time to wait for the response to each attempt, 1 s by default
+    pub fn set_response_timeout(&mut self, timeout: Duration) {
+        self.response_timeout = timeout;
+    }
+
+    /// How many times a packet is resent, if there is no response to it,
+    /// 2 by default
+    pub fn set_retries(&mut self, retries: u8) {
+        self.retries = retries;
+    }
+
+    /// Handler for all packets which are not a response to a request
+    pub fn set_handler<F>(&mut self, handler: F)
+    where
+        F: FnMut(PacketOwned) + Send + 'static,
+    {
+        self.handler = Some(Box::new(handler));
+    }
+
+    /// Writes raw data, without waiting for any response
+    pub fn write_all(&mut self, data: &[u8]) -> io::Result<()> {
+        let transport = self.reader.get_mut();
+        transport.write_all(data)?;
+        transport.flush()
+    }
+
+    /// Sends the UBX `packet` and waits for the device to acknowledge it,
+    /// resending it on timeouts.
+    ///
+    /// Fails with an error of kind `InvalidInput` if `packet` is not an UBX packet.
+    pub fn send_with_ack(&mut self, packet: &[u8]) -> Result<(), DeviceError> {
+        if packet.len() < 8 || packet[..2] != [SYNC_CHAR_1, SYNC_CHAR_2] {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "not an UBX packet").into());
+        }
+        let (class, msg_id) = (packet[2], packet[3]);
+        self.request((class, msg_id), packet, |packet| match packet {
+            PacketRef::AckAck(ack) if (ack.class(), ack.msg_id()) == (class, msg_id) => {
+                Response::Matched
+            }
+            PacketRef::AckNak(nak) if (nak.class(), nak.msg_id()) == (class, msg_id) => {
+                Response::Rejected
+            }
+            _ => Response::Unrelated,
+        })
+        .map(|_| ())
+    }
+
+    /// Requests a `P` packet from the device, and waits for it.
+    ///
+    /// For configuration packets, also waits for the `AckAck` which follows the
+    /// response, so it can't be taken for the acknowledgement of a later request.
+    pub fn poll<P: UbxPacketMeta>(&mut self) -> Result<PacketOwned, DeviceError> {
+        let request = UbxPacketRequest::request_for::<P>().into_packet_bytes();
+        let response = self.request((P::CLASS, P::ID), &request, |packet| match packet {
+            PacketRef::AckNak(nak) if nak.is_nak_for::<P>() => Response::Rejected,
+            _ if packet.class_and_msg_id() == (P::CLASS, P::ID) => Response::Matched,
+            _ => Response::Unrelated,
+        })?;
+        if P::CLASS == CFG_CLASS {
+            // A missing acknowledgement doesn't make the response invalid
+            self.wait_response(&mut |packet: &PacketRef| match packet {
+                PacketRef::AckAck(ack) if ack.is_ack_for::<P>() => Response::Matched,
+                PacketRef::AckNak(nak) if nak.is_nak_for::<P>() => Response::Matched,
+                _ => Response::Unrelated,
+            })?;
+        }
+        Ok(response)
+    }
+
+    /// Downloads all entries of the receiver's log, requesting them in pages
+    /// of `LogRetrieve::MAX_ENTRY_COUNT` entries.
+    ///
+    /// u-blox recommends to stop recording before retrieving the log. Lost
+    /// entries are requested again, up to the number of retries.
+    pub fn download_log(&mut self) -> Result<LogDownload<'_, T>, DeviceError> {
+        let request = UbxPacketRequest::request_for::<LogInfo>().into_packet_bytes();
+        let info = self.request(
+            (LogInfo::CLASS, LogInfo::ID),
+            &request,
+            |packet| match packet {
+                PacketRef::LogInfo(_) => Response::Matched,
+                PacketRef::AckNak(nak) if nak.is_nak_for::<LogInfo>() => Response::Rejected,
+                _ => Response::Unrelated,
+            },
+        )?;
+        let entry_count = match info {
+            PacketOwned::LogInfo(info) => info.as_packet_ref().entry_count(),
+            _ => unreachable!("only LogInfo is matched"),
+        };
+        Ok(LogDownload {
+            device: self,
+            next_index: 0,
+            requested_end: 0,
+            end: entry_count,
+        })
+    }
+
+    /// Waits for the next packet and passes it to the handler.
+    ///
+    /// Returns an error of kind `TimedOut` if nothing was received within the
+    /// response timeout, and of kind `UnexpectedEof` if the transport is closed.
+    pub fn update(&mut self) -> io::Result<()> {
+        self.reader.set_timeout(Some(self.response_timeout));
+        match self.reader.next_packet()? {
+            Some(packet) => {
+                self.dispatch(packet);
+                Ok(())
+            }
+            None => Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+    }
+
+    pub fn get_ref(&self) -> &T {
+        self.reader.get_ref()
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.reader.get_mut()
+    }
+
+    pub fn into_inner(self) -> T {
+        self.reader.into_inner()
+    }
+
+    fn request<F>(
+        &mut self,
+        (class, msg_id): (u8, u8),
+        packet: &[u8],
+        mut matcher: F,
+    ) -> Result<PacketOwned, DeviceError>
+    where
+        F: FnMut(&PacketRef) -> Response,
+    {
+        for _ in 0..=self.retries {
+            self.write_all(packet)?;
+            match self.wait_response(&mut matcher)? {
+                Reply::Response(packet) => return Ok(packet),
+                Reply::Nak => return Err(DeviceError::Nak { class, msg_id }),
+                Reply::Timeout => {}
+            }
+        }
+        Err(DeviceError::Timeout { class, msg_id })
+    }
+
+    fn wait_response<F>(&mut self, matcher: &mut F) -> io::Result<Reply>
+    where
+        F: FnMut(&PacketRef) -> Response,
+    {
+        let deadline = Instant::now() + self.response_timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining == Duration::from_secs(0) {
+                return Ok(Reply::Timeout);
+            }
+            self.reader.set_timeout(Some(remaining));
+            let packet = match self.reader.next_packet() {
+                Ok(Some(packet)) => packet,
+                Ok(None) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Err(err) if err.kind() == io::ErrorKind::TimedOut => return Ok(Reply::Timeout),
+                // Malformed packet, it can't be the response we are waiting for
+                Err(err) if err.kind() == io::ErrorKind::InvalidData => continue,
+                Err(err) => return Err(err),
+            };
+            let response = matcher(&packet.as_packet_ref());
+            match response {
+                Response::Matched => return Ok(Reply::Response(packet)),
+                Response::Rejected => return Ok(Reply::Nak),
+                Response::Stale => {}
+                Response::Unrelated => self.dispatch(packet),
+            }
+        }
+    }
+
+    fn dispatch(&mut self, packet: PacketOwned) {
+        if let Some(ref mut handler) = self.handler {
+            handler(packet);
+        }
+    }
+}
+
+/// Entry of the receiver's log, see `Device::download_log`
+#[derive(Debug, Clone)]
+pub enum LogEntry {
+    Position(LogRetrievePosOwned),
+    PositionExtra(LogRetrievePosExtraOwned),
+    String(LogRetrieveStringOwned),
+}
+
+impl LogEntry {
+    /// Index of the entry in the log
+    pub fn index(&self) -> u32 {
+        match self {
+            LogEntry::Position(entry) => entry.as_packet_ref().entry_index(),
+            LogEntry::PositionExtra(entry) => entry.as_packet_ref().entry_index(),
+            LogEntry::String(entry) => entry.as_packet_ref().entry_index(),
+        }
+    }
+}
+
+fn log_entry_index(packet: &PacketRef) -> Option<u32> {
+    match packet {
+        PacketRef::LogRetrievePos(entry) => Some(entry.entry_index()),
+        PacketRef::LogRetrievePosExtra(entry) => Some(entry.entry_index()),
+        PacketRef::LogRetrieveString(entry) => Some(entry.entry_index()),
+        _ => None,
+    }
+}
+
+/// Matches the log entry `index`. The other entries up to `requested_end`
+/// arrived out of order, or late from a previous request, and are requested
+/// again, so they are dropped.
+fn entry_matcher(index: u32, requested_end: u32) -> impl FnMut(&PacketRef) -> Response {
+    move |packet| match packet {
+        PacketRef::AckNak(nak) if nak.is_nak_for::<LogRetrieve>() => Response::Rejected,
+        _ => match log_entry_index(packet) {
+            Some(entry) if entry == index => Response::Matched,
+            Some(entry) if entry < requested_end => Response::Stale,
+            _ => Response::Unrelated,
+        },
+    }
+}
+
+/// Iterator over the entries of the receiver's log, returned by `Device::download_log`.
+///
+/// Stops after the first error.
+pub struct LogDownload<'a, T> {
+    device: &'a mut Device<T>,
+    next_index: u32,
+    /// End of the entries requested with the last `LogRetrieve`
+    requested_end: u32,
+    end: u32,
+}
+
+impl<'a, T: Read + Write> LogDownload<'a, T> {
+    fn next_entry(&mut self) -> Result<LogEntry, DeviceError> {
+        let index = self.next_index;
+
+        let mut reply = Reply::Timeout;
+        if index < self.requested_end {
+            let mut matcher = entry_matcher(index, self.requested_end);
+            reply = self.device.wait_response(&mut matcher)?;
+        }
+        let packet = match reply {
+            Reply::Response(packet) => packet,
+            Reply::Nak => {
+                return Err(DeviceError::Nak {
+                    class: LogRetrieve::CLASS,
+                    msg_id: LogRetrieve::ID,
+                })
+            }
+            // Request the next page, or the rest of the current one if an entry was lost
+            Reply::Timeout => {
+                let entry_count = (self.end - index).min(LogRetrieve::MAX_ENTRY_COUNT);
+                self.requested_end = index + entry_count;
+                let request = LogRetrieveBuilder {
+                    start_number: index,
+                    entry_count,
+                    ..LogRetrieveBuilder::default()
+                }
+                .into_packet_bytes();
+                let matcher = entry_matcher(index, self.requested_end);
+                self.device
+                    .request((LogRetrieve::CLASS, LogRetrieve::ID), &request, matcher)?
+            }
+        };
+
+        match packet {
+            PacketOwned::LogRetrievePos(entry) => Ok(LogEntry::Position(entry)),
+            PacketOwned::LogRetrievePosExtra(entry) => Ok(LogEntry::PositionExtra(entry)),
+            PacketOwned::LogRetrieveString(entry) => Ok(LogEntry::String(entry)),
+            _ => unreachable!("only log entries are matched"),
+        }
+    }
+}
+
+impl<'a, T: Read + Write> Iterator for LogDownload<'a, T> {
+    type Item = Result<LogEntry, DeviceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.end {
+            return None;
+        }
+        let entry = self.next_entry();
+        match entry {
+            Ok(_) => self.next_index += 1,
+            Err(_) => self.next_index = self.end,
+        }
+        Some(entry)
+    }
+}
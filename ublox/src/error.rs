@@ -1,5 +1,7 @@
 use core::fmt;
 
+use crate::ubx_packets::GnssId;
+
 #[derive(Debug)]
 pub enum MemWriterError<E> {
     NotEnoughMem,
@@ -25,6 +27,14 @@ pub enum ParserError {
         expect: u16,
         got: u16,
     },
+    InvalidNmeaChecksum {
+        expect: u8,
+        got: u8,
+    },
+    InvalidRtcmCrc {
+        expect: u32,
+        got: u32,
+    },
     InvalidField {
         packet: &'static str,
         field: &'static str,
@@ -48,6 +58,16 @@ impl fmt::Display for ParserError {
                 "Not valid packet's checksum, expect {:x}, got {:x}",
                 expect, got
             ),
+            ParserError::InvalidNmeaChecksum { expect, got } => write!(
+                f,
+                "Not valid NMEA sentence's checksum, expect {:02X}, got {:02X}",
+                expect, got
+            ),
+            ParserError::InvalidRtcmCrc { expect, got } => write!(
+                f,
+                "Not valid RTCM frame's CRC, expect {:06x}, got {:06x}",
+                expect, got
+            ),
             ParserError::InvalidField { packet, field } => {
                 write!(f, "Invalid field {} of packet {}", field, packet)
             }
@@ -72,11 +92,120 @@ impl fmt::Display for ParserError {
 #[cfg(feature = "std")]
 impl std::error::Error for ParserError {}
 
+/// Error while building a configuration value from a scaled value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CfgValError {
+    /// The value (in the unit of the item) can't be stored in the item
+    OutOfRange {
+        item: &'static str,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+}
+
+impl fmt::Display for CfgValError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CfgValError::OutOfRange {
+                item,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "Value {} of {} is out of range [{}, {}]",
+                value, item, min, max
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CfgValError {}
+
+/// Error returned by the builders of packets with a variable length payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuilderError {
+    /// More items or bytes in `field` than the packet can hold
+    TooLong {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuilderError::TooLong { field, len, max } => {
+                write!(f, "Length {} of {} is more than {}", len, field, max)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuilderError {}
+
+/// Error returned by `CfgGnssBuilder::validate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CfgGnssError {
+    /// More tracking channels to use than available in hardware
+    TooManyChannels { channels: u8, hardware: u8 },
+    /// The reserved channels of the enabled GNSS exceed the channels to use
+    ReservedChannels { reserved: u16, channels: u8 },
+    /// Maximum number of tracking channels lower than the reserved ones
+    MaxBelowReserved { gnss_id: GnssId },
+    /// Major GNSS not supported by the receiver
+    UnsupportedGnss { gnss_id: GnssId },
+    /// More major GNSS enabled than the receiver can track simultaneously
+    TooManyGnss { enabled: u8, simultaneous: u8 },
+}
+
+impl fmt::Display for CfgGnssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CfgGnssError::TooManyChannels { channels, hardware } => write!(
+                f,
+                "{} tracking channels to use, only {} available",
+                channels, hardware
+            ),
+            CfgGnssError::ReservedChannels { reserved, channels } => write!(
+                f,
+                "{} tracking channels reserved, only {} to use",
+                reserved, channels
+            ),
+            CfgGnssError::MaxBelowReserved { gnss_id } => write!(
+                f,
+                "Maximum tracking channels of {:?} below the reserved ones",
+                gnss_id
+            ),
+            CfgGnssError::UnsupportedGnss { gnss_id } => {
+                write!(f, "{:?} not supported by the receiver", gnss_id)
+            }
+            CfgGnssError::TooManyGnss {
+                enabled,
+                simultaneous,
+            } => write!(
+                f,
+                "{} major GNSS enabled, only {} supported simultaneously",
+                enabled, simultaneous
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CfgGnssError {}
+
 #[derive(Debug, Clone, Copy)]
 pub enum DateTimeError {
     InvalidDate,
     InvalidTime,
     InvalidNanoseconds,
+    /// The offset between the GNSS time scale and UTC is not known yet
+    UnknownLeapSeconds,
 }
 
 impl fmt::Display for DateTimeError {
@@ -85,9 +214,63 @@ impl fmt::Display for DateTimeError {
             DateTimeError::InvalidDate => f.write_str("invalid date"),
             DateTimeError::InvalidTime => f.write_str("invalid time"),
             DateTimeError::InvalidNanoseconds => f.write_str("invalid nanoseconds"),
+            DateTimeError::UnknownLeapSeconds => f.write_str("unknown leap seconds"),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for DateTimeError {}
+
+/// Error returned by `Device` requests
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum DeviceError {
+    Io(std::io::Error),
+    /// The device answered with `AckNak`
+    Nak {
+        class: u8,
+        msg_id: u8,
+    },
+    /// No response, even after all retries
+    Timeout {
+        class: u8,
+        msg_id: u8,
+    },
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for DeviceError {
+    fn from(err: std::io::Error) -> Self {
+        DeviceError::Io(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for DeviceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceError::Io(err) => write!(f, "I/O error: {}", err),
+            DeviceError::Nak { class, msg_id } => write!(
+                f,
+                "Packet rejected by device, class {:#04x}, id {:#04x}",
+                class, msg_id
+            ),
+            DeviceError::Timeout { class, msg_id } => write!(
+                f,
+                "No response from device, class {:#04x}, id {:#04x}",
+                class, msg_id
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeviceError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DeviceError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -53,6 +53,37 @@
 //! # }
 //! ```
 //!
+//! With the `std` feature, `PacketReader` wraps any `std::io::Read` (a serial port, a file, a socket) and takes care of this loop, returning packets one by one from its blocking `next_packet()` method. On top of it, `Device` sends packets to a receiver and waits for their `AckAck`/`AckNak`, polls packets with `poll::<MonVer>()` or downloads the on-board log with `download_log()`, while delivering all other packets to a handler.
+//!
+//! The optional `async` feature adds `PacketStream`, a `futures::Stream` of packets read from an `AsyncRead`, and `PacketWriter` to send packets to an `AsyncWrite`.
+//!
+//! The returned `PacketRef` borrows the parser's buffer. If you need to keep a packet around, or send it to another thread, convert it with `to_owned()` into a `PacketOwned`, and use `as_packet_ref()` to access its fields again.
+//!
+//! `consume()` skips everything which is not a UBX packet. If NMEA or RTCM3 output is enabled on the same port, use `consume_any()` instead, which also yields NMEA sentences and RTCM3 frames with a valid checksum:
+//! ```
+//! use ublox::{AnyPacketRef, FixedLinearBuffer, Parser};
+//!
+//! let mut buf = [0; 256];
+//! let mut parser = Parser::new(FixedLinearBuffer::new(&mut buf[..]));
+//! let mut it = parser.consume_any(b"$GNRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V*33\r\n");
+//! while let Some(frame) = it.next() {
+//!     match frame {
+//!         Ok(AnyPacketRef::Ubx(packet)) => {
+//!             // A UBX packet, same as returned by `consume()`
+//!         }
+//!         Ok(AnyPacketRef::Nmea(sentence)) => {
+//!             assert_eq!(sentence.sentence_id(), "RMC");
+//!         }
+//!         Ok(AnyPacketRef::Rtcm(frame)) => {
+//!             // Forward `frame.as_bytes()` to the rover
+//!         }
+//!         Err(_) => {
+//!             // Received a malformed packet or sentence
+//!         }
+//!     }
+//! }
+//! ```
+//!
 //! no_std Support
 //! ==============
 //!
@@ -73,11 +104,32 @@ extern crate core;
 extern crate serde;
 
 pub use crate::{
-    error::{DateTimeError, MemWriterError, ParserError},
-    parser::{FixedLinearBuffer, Parser, ParserIter, UnderlyingBuffer},
+    error::{BuilderError, CfgGnssError, CfgValError, DateTimeError, MemWriterError, ParserError},
+    nmea::NmeaSentenceRef,
+    parser::{
+        AnyPacketRef, AnyParserIter, FixedLinearBuffer, Parser, ParserIter, UnderlyingBuffer,
+    },
+    rtcm::RtcmPacketRef,
     ubx_packets::*,
 };
 
+#[cfg(feature = "async")]
+pub use crate::async_io::{PacketStream, PacketWriter};
+#[cfg(feature = "std")]
+pub use crate::{
+    device::{Device, LogDownload, LogEntry},
+    error::DeviceError,
+    reader::PacketReader,
+};
+
+#[cfg(feature = "async")]
+mod async_io;
+#[cfg(feature = "std")]
+mod device;
 mod error;
+mod nmea;
 mod parser;
+#[cfg(feature = "std")]
+mod reader;
+mod rtcm;
 mod ubx_packets;
@@ -0,0 +1,109 @@
+use core::fmt;
+
+/// First character of every NMEA 0183 sentence
+pub(crate) const NMEA_START: u8 = b'$';
+
+/// NMEA 0183 limits sentences to 82 characters, but u-blox receivers exceed that
+/// for some proprietary `PUBX` messages, so we are a bit more permissive here.
+pub(crate) const MAX_NMEA_SENTENCE_LEN: usize = 1024;
+
+/// Length of the `*hh\r\n` trailer of a sentence
+pub(crate) const NMEA_TRAILER_LEN: usize = 5;
+
+/// NMEA checksum: XOR of all characters between `$` and `*`
+pub(crate) fn nmea_checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0, |acc, b| acc ^ b)
+}
+
+pub(crate) fn hex_digit_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        _ => None,
+    }
+}
+
+/// NMEA sentence found in the byte stream, with valid checksum.
+///
+/// Contains a reference to the whole sentence, starting with `$` and ending
+/// with `*hh\r\n`.
+pub struct NmeaSentenceRef<'a>(&'a [u8]);
+
+impl<'a> NmeaSentenceRef<'a> {
+    /// Caller must make sure that `bytes` is a printable ASCII sentence
+    /// in the `$...*hh\r\n` form
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    /// Raw sentence, including the leading `$` and trailing `*hh\r\n`
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Raw sentence as string, including the leading `$` and trailing `*hh\r\n`
+    pub fn as_str(&self) -> &'a str {
+        core::str::from_utf8(self.0).expect("parser should only accept ASCII sentences")
+    }
+
+    /// Everything between `$` and `*`, for example `GNGGA,092725.00,4717.11399,N,...`
+    pub fn data(&self) -> &'a str {
+        let s = self.as_str();
+        &s[1..s.len() - NMEA_TRAILER_LEN]
+    }
+
+    /// Address field, for example `GNGGA` or `PUBX`
+    pub fn address(&self) -> &'a str {
+        self.data().split(',').next().unwrap_or("")
+    }
+
+    /// Talker identifier, for example `GN` or `GP`. Proprietary sentences
+    /// (like `$PUBX`) have the talker identifier `P`.
+    pub fn talker_id(&self) -> &'a str {
+        let address = self.address();
+        let talker_len = if address.starts_with('P') { 1 } else { 2 };
+        address.get(..talker_len).unwrap_or(address)
+    }
+
+    /// Sentence identifier, for example `GGA` or `RMC`. For proprietary
+    /// sentences this is the manufacturer code, `UBX` for `$PUBX`.
+    pub fn sentence_id(&self) -> &'a str {
+        let address = self.address();
+        address.get(self.talker_id().len()..).unwrap_or("")
+    }
+
+    /// Data fields following the address field
+    pub fn fields(&self) -> impl Iterator<Item = &'a str> {
+        let mut it = self.data().split(',');
+        it.next();
+        it
+    }
+
+    /// Checksum transmitted with the sentence
+    pub fn checksum(&self) -> u8 {
+        let pos = self.0.len() - NMEA_TRAILER_LEN + 1;
+        let hi = hex_digit_value(self.0[pos]).expect("parser should validate checksum digits");
+        let lo = hex_digit_value(self.0[pos + 1]).expect("parser should validate checksum digits");
+        (hi << 4) | lo
+    }
+}
+
+impl<'a> fmt::Debug for NmeaSentenceRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NmeaSentenceRef")
+            .field(&self.as_str().trim_end())
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for NmeaSentenceRef<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str().trim_end())
+    }
+}
@@ -3,6 +3,11 @@ use alloc::vec::Vec;
 
 use crate::{
     error::ParserError,
+    nmea::{
+        hex_digit_value, nmea_checksum, NmeaSentenceRef, MAX_NMEA_SENTENCE_LEN, NMEA_START,
+        NMEA_TRAILER_LEN,
+    },
+    rtcm::{Crc24qCalc, RtcmPacketRef, RTCM_CRC_LEN, RTCM_HEADER_LEN, RTCM_PREAMBLE},
     ubx_packets::{match_packet, PacketRef, MAX_PAYLOAD_LEN, SYNC_CHAR_1, SYNC_CHAR_2},
 };
 
@@ -210,6 +215,13 @@ impl<T: UnderlyingBuffer> Parser<T> {
 
         ParserIter { buf }
     }
+
+    /// Like `consume`, but the returned iterator also yields NMEA sentences
+    /// and RTCM3 frames found between UBX packets, instead of skipping them.
+    pub fn consume_any<'a>(&'a mut self, new_data: &'a [u8]) -> AnyParserIter<'a, T> {
+        let buf = DualBuffer::new(&mut self.buf, new_data);
+        AnyParserIter { buf }
+    }
 }
 
 /// Stores two buffers: A "base" and a "new" buffer. Exposes these as the same buffer,
@@ -421,6 +433,175 @@ impl UbxChecksumCalc {
     }
 }
 
+/// Result of inspecting the start of the buffer for a frame header
+enum Framing {
+    /// Not enough data to decide yet
+    Incomplete,
+    /// The first bytes can not start a frame and should be skipped
+    Skip(usize),
+    /// Header is valid, contains the length of the frame (UBX: of the payload)
+    Frame(usize),
+}
+
+/// Buffer should start with `SYNC_CHAR_1`
+fn ubx_framing<T: UnderlyingBuffer>(buf: &DualBuffer<T>) -> Framing {
+    if buf.len() < 2 {
+        return Framing::Incomplete;
+    }
+    if buf[1] != SYNC_CHAR_2 {
+        return Framing::Skip(1);
+    }
+
+    if buf.len() < 6 {
+        return Framing::Incomplete;
+    }
+
+    let pack_len: usize = u16::from_le_bytes([buf[4], buf[5]]).into();
+    if pack_len > usize::from(MAX_PAYLOAD_LEN) {
+        return Framing::Skip(2);
+    }
+    Framing::Frame(pack_len)
+}
+
+fn extract_ubx_packet<'b, T: UnderlyingBuffer>(
+    buf: &'b mut DualBuffer<T>,
+    pack_len: usize,
+) -> Option<Result<PacketRef<'b>, ParserError>> {
+    if !buf.can_drain_and_take(6, pack_len + 2) {
+        if buf.potential_lost_bytes() > 0 {
+            // We ran out of space, drop this packet and move on
+            buf.drain(2);
+            return Some(Err(ParserError::OutOfMemory {
+                required_size: pack_len + 2,
+            }));
+        }
+        return None;
+    }
+    let mut checksummer = UbxChecksumCalc::new();
+    let (a, b) = buf.peek_raw(2..(4 + pack_len + 2));
+    checksummer.update(a);
+    checksummer.update(b);
+    let (ck_a, ck_b) = checksummer.result();
+
+    let (expect_ck_a, expect_ck_b) = (buf[6 + pack_len], buf[6 + pack_len + 1]);
+    if (ck_a, ck_b) != (expect_ck_a, expect_ck_b) {
+        buf.drain(2);
+        return Some(Err(ParserError::InvalidChecksum {
+            expect: u16::from_le_bytes([expect_ck_a, expect_ck_b]),
+            got: u16::from_le_bytes([ck_a, ck_b]),
+        }));
+    }
+    let class_id = buf[2];
+    let msg_id = buf[3];
+    buf.drain(6);
+    let msg_data = match buf.take(pack_len + 2) {
+        Ok(x) => x,
+        Err(e) => {
+            return Some(Err(e));
+        }
+    };
+    Some(match_packet(
+        class_id,
+        msg_id,
+        &msg_data[..msg_data.len() - 2], // Exclude the checksum
+    ))
+}
+
+/// Buffer should start with `NMEA_START`, returns the length of the whole
+/// sentence including the `\r\n` terminator
+fn nmea_framing<T: UnderlyingBuffer>(buf: &DualBuffer<T>) -> Framing {
+    for i in 1..buf.len() {
+        let len = i + 1;
+        if len > MAX_NMEA_SENTENCE_LEN {
+            return Framing::Skip(1);
+        }
+        match buf[i] {
+            b'\n' => {
+                if len < 1 + NMEA_TRAILER_LEN
+                    || buf[i - 1] != b'\r'
+                    || buf[len - NMEA_TRAILER_LEN] != b'*'
+                    || hex_digit_value(buf[i - 3]).is_none()
+                    || hex_digit_value(buf[i - 2]).is_none()
+                {
+                    return Framing::Skip(1);
+                }
+                return Framing::Frame(len);
+            }
+            // Start of the next sentence, so this one is truncated
+            NMEA_START => return Framing::Skip(1),
+            b'\r' => {}
+            0x20..=0x7e => {}
+            _ => return Framing::Skip(1),
+        }
+    }
+    Framing::Incomplete
+}
+
+fn extract_nmea_sentence<'b, T: UnderlyingBuffer>(
+    buf: &'b mut DualBuffer<T>,
+    len: usize,
+) -> Option<Result<NmeaSentenceRef<'b>, ParserError>> {
+    if !buf.can_drain_and_take(0, len) {
+        if buf.potential_lost_bytes() > 0 {
+            // We ran out of space, drop this sentence and move on
+            buf.drain(1);
+            return Some(Err(ParserError::OutOfMemory { required_size: len }));
+        }
+        return None;
+    }
+    let (a, b) = buf.peek_raw(1..(len - NMEA_TRAILER_LEN));
+    let got = nmea_checksum(a) ^ nmea_checksum(b);
+    let expect =
+        (hex_digit_value(buf[len - 4]).unwrap() << 4) | hex_digit_value(buf[len - 3]).unwrap();
+    if got != expect {
+        buf.drain(1);
+        return Some(Err(ParserError::InvalidNmeaChecksum { expect, got }));
+    }
+    Some(buf.take(len).map(NmeaSentenceRef::new))
+}
+
+/// Buffer should start with `RTCM_PREAMBLE`, returns the length of the payload
+fn rtcm_framing<T: UnderlyingBuffer>(buf: &DualBuffer<T>) -> Framing {
+    if buf.len() < RTCM_HEADER_LEN {
+        return Framing::Incomplete;
+    }
+    // The 6 bits before the length are reserved and always zero
+    if buf[1] & 0xfc != 0 {
+        return Framing::Skip(1);
+    }
+    Framing::Frame((usize::from(buf[1]) << 8) | usize::from(buf[2]))
+}
+
+fn extract_rtcm_packet<'b, T: UnderlyingBuffer>(
+    buf: &'b mut DualBuffer<T>,
+    payload_len: usize,
+) -> Option<Result<RtcmPacketRef<'b>, ParserError>> {
+    let frame_len = RTCM_HEADER_LEN + payload_len + RTCM_CRC_LEN;
+    if !buf.can_drain_and_take(0, frame_len) {
+        if buf.potential_lost_bytes() > 0 {
+            // We ran out of space, drop this frame and move on
+            buf.drain(1);
+            return Some(Err(ParserError::OutOfMemory {
+                required_size: frame_len,
+            }));
+        }
+        return None;
+    }
+    let mut crc = Crc24qCalc::new();
+    let (a, b) = buf.peek_raw(0..(RTCM_HEADER_LEN + payload_len));
+    crc.update(a);
+    crc.update(b);
+    let got = crc.result();
+
+    let crc_pos = RTCM_HEADER_LEN + payload_len;
+    let expect = u32::from_be_bytes([0, buf[crc_pos], buf[crc_pos + 1], buf[crc_pos + 2]]);
+    if got != expect {
+        buf.drain(1);
+        return Some(Err(ParserError::InvalidRtcmCrc { expect, got }));
+    }
+    Some(buf.take(frame_len).map(RtcmPacketRef::new))
+}
+
 /// Iterator over data stored in `Parser` buffer
 pub struct ParserIter<'a, T: UnderlyingBuffer> {
     buf: DualBuffer<'a, T>,
@@ -436,47 +617,6 @@ impl<'a, T: UnderlyingBuffer> ParserIter<'a, T> {
         None
     }
 
-    fn extract_packet(&mut self, pack_len: usize) -> Option<Result<PacketRef, ParserError>> {
-        if !self.buf.can_drain_and_take(6, pack_len + 2) {
-            if self.buf.potential_lost_bytes() > 0 {
-                // We ran out of space, drop this packet and move on
-                self.buf.drain(2);
-                return Some(Err(ParserError::OutOfMemory {
-                    required_size: pack_len + 2,
-                }));
-            }
-            return None;
-        }
-        let mut checksummer = UbxChecksumCalc::new();
-        let (a, b) = self.buf.peek_raw(2..(4 + pack_len + 2));
-        checksummer.update(a);
-        checksummer.update(b);
-        let (ck_a, ck_b) = checksummer.result();
-
-        let (expect_ck_a, expect_ck_b) = (self.buf[6 + pack_len], self.buf[6 + pack_len + 1]);
-        if (ck_a, ck_b) != (expect_ck_a, expect_ck_b) {
-            self.buf.drain(2);
-            return Some(Err(ParserError::InvalidChecksum {
-                expect: u16::from_le_bytes([expect_ck_a, expect_ck_b]),
-                got: u16::from_le_bytes([ck_a, ck_b]),
-            }));
-        }
-        let class_id = self.buf[2];
-        let msg_id = self.buf[3];
-        self.buf.drain(6);
-        let msg_data = match self.buf.take(pack_len + 2) {
-            Ok(x) => x,
-            Err(e) => {
-                return Some(Err(e));
-            }
-        };
-        return Some(match_packet(
-            class_id,
-            msg_id,
-            &msg_data[..msg_data.len() - 2], // Exclude the checksum
-        ));
-    }
-
     #[allow(clippy::should_implement_trait)]
     /// Analog of `core::iter::Iterator::next`, should be switched to
     /// trait implementation after merge of https://github.com/rust-lang/rust/issues/44265
@@ -491,24 +631,86 @@ impl<'a, T: UnderlyingBuffer> ParserIter<'a, T> {
             };
             self.buf.drain(pos);
 
-            if self.buf.len() < 2 {
-                return None;
-            }
-            if self.buf[1] != SYNC_CHAR_2 {
-                self.buf.drain(1);
-                continue;
+            match ubx_framing(&self.buf) {
+                Framing::Incomplete => return None,
+                Framing::Skip(n) => self.buf.drain(n),
+                Framing::Frame(pack_len) => return extract_ubx_packet(&mut self.buf, pack_len),
             }
+        }
+        None
+    }
+}
 
-            if self.buf.len() < 6 {
-                return None;
-            }
+/// Any frame which `AnyParserIter` can find in the byte stream
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AnyPacketRef<'a> {
+    Ubx(PacketRef<'a>),
+    Nmea(NmeaSentenceRef<'a>),
+    Rtcm(RtcmPacketRef<'a>),
+}
+
+/// Iterator over data stored in `Parser` buffer, which yields
+/// UBX packets, NMEA sentences and RTCM3 frames
+pub struct AnyParserIter<'a, T: UnderlyingBuffer> {
+    buf: DualBuffer<'a, T>,
+}
+
+impl<'a, T: UnderlyingBuffer> AnyParserIter<'a, T> {
+    fn find_sync(&self) -> Option<usize> {
+        (0..self.buf.len()).find(|&i| {
+            let b = self.buf[i];
+            b == SYNC_CHAR_1 || b == NMEA_START || b == RTCM_PREAMBLE
+        })
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    /// Analog of `core::iter::Iterator::next`, see `ParserIter::next`
+    pub fn next(&mut self) -> Option<Result<AnyPacketRef<'_>, ParserError>> {
+        while self.buf.len() > 0 {
+            let pos = match self.find_sync() {
+                Some(x) => x,
+                None => {
+                    self.buf.clear();
+                    return None;
+                }
+            };
+            self.buf.drain(pos);
 
-            let pack_len: usize = u16::from_le_bytes([self.buf[4], self.buf[5]]).into();
-            if pack_len > usize::from(MAX_PAYLOAD_LEN) {
-                self.buf.drain(2);
-                continue;
+            match self.buf[0] {
+                SYNC_CHAR_1 => match ubx_framing(&self.buf) {
+                    Framing::Incomplete => return None,
+                    Framing::Skip(n) => self.buf.drain(n),
+                    Framing::Frame(pack_len) => {
+                        return extract_ubx_packet(&mut self.buf, pack_len)
+                            .map(|res| res.map(AnyPacketRef::Ubx));
+                    }
+                },
+                RTCM_PREAMBLE => match rtcm_framing(&self.buf) {
+                    Framing::Incomplete => return None,
+                    Framing::Skip(n) => self.buf.drain(n),
+                    Framing::Frame(payload_len) => {
+                        return extract_rtcm_packet(&mut self.buf, payload_len)
+                            .map(|res| res.map(AnyPacketRef::Rtcm));
+                    }
+                },
+                _ => match nmea_framing(&self.buf) {
+                    Framing::Incomplete => {
+                        if self.buf.potential_lost_bytes() > 0 {
+                            // Sentence can't fit into the buffer, drop it
+                            let required_size = self.buf.len() + 1;
+                            self.buf.drain(1);
+                            return Some(Err(ParserError::OutOfMemory { required_size }));
+                        }
+                        return None;
+                    }
+                    Framing::Skip(n) => self.buf.drain(n),
+                    Framing::Frame(len) => {
+                        return extract_nmea_sentence(&mut self.buf, len)
+                            .map(|res| res.map(AnyPacketRef::Nmea));
+                    }
+                },
             }
-            return self.extract_packet(pack_len);
         }
         None
     }
@@ -987,6 +1189,255 @@ mod test {
         }
         assert!(it.next().is_none());
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parser_packet_to_owned() {
+        let bytes = CfgNav5Builder {
+            pacc: 21,
+            ..CfgNav5Builder::default()
+        }
+        .into_packet_bytes();
+
+        let owned = {
+            let mut parser = Parser::default();
+            let mut it = parser.consume(&bytes);
+            let owned = match it.next() {
+                Some(Ok(packet)) => packet.to_owned(),
+                _ => panic!(),
+            };
+            assert!(it.next().is_none());
+            owned
+        };
+
+        let owned = std::thread::spawn(move || owned).join().unwrap();
+        assert_eq!(owned.class_and_msg_id(), (CfgNav5::CLASS, CfgNav5::ID));
+        match owned.as_packet_ref() {
+            PacketRef::CfgNav5(packet) => {
+                assert_eq!(packet.pacc(), 21);
+                assert_eq!(packet.as_bytes(), &bytes[6..bytes.len() - 2]);
+            }
+            _ => panic!(),
+        }
+    }
+
+    static NMEA_GGA: &[u8] =
+        b"$GNGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*45\r\n";
+    static UBX_ACK_ACK: [u8; 10] = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x4, 0x5, 0x11, 0x38];
+
+    #[test]
+    fn parser_nmea_sentence() {
+        let mut buffer = [0; 128];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut it = parser.consume_any(NMEA_GGA);
+        match it.next() {
+            Some(Ok(AnyPacketRef::Nmea(sentence))) => {
+                assert_eq!(sentence.as_bytes(), NMEA_GGA);
+                assert_eq!(sentence.talker_id(), "GN");
+                assert_eq!(sentence.sentence_id(), "GGA");
+                assert_eq!(sentence.checksum(), 0x45);
+                assert_eq!(sentence.fields().next(), Some("092725.00"));
+                assert_eq!(sentence.fields().count(), 14);
+            }
+            _ => assert!(false),
+        }
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn parser_nmea_proprietary_sentence() {
+        let bytes = b"$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,\
+            0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F\r\n";
+        let mut buffer = [0; 128];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut it = parser.consume_any(bytes);
+        match it.next() {
+            Some(Ok(AnyPacketRef::Nmea(sentence))) => {
+                assert_eq!(sentence.address(), "PUBX");
+                assert_eq!(sentence.talker_id(), "P");
+                assert_eq!(sentence.sentence_id(), "UBX");
+                assert_eq!(sentence.fields().next(), Some("00"));
+            }
+            _ => assert!(false),
+        }
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn parser_nmea_interleaved_with_ubx() {
+        let mut buffer = [0; 128];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut bytes = [0; 2 * UBX_ACK_ACK.len() + 75];
+        bytes[..10].copy_from_slice(&UBX_ACK_ACK);
+        bytes[10..85].copy_from_slice(NMEA_GGA);
+        bytes[85..].copy_from_slice(&UBX_ACK_ACK);
+
+        // Feed data in small chunks, to split frames between calls
+        let mut found = [false; 3];
+        let mut idx = 0;
+        for chunk in bytes.chunks(7) {
+            let mut it = parser.consume_any(chunk);
+            while let Some(pack) = it.next() {
+                match (idx, pack) {
+                    (0, Ok(AnyPacketRef::Ubx(PacketRef::AckAck(_))))
+                    | (1, Ok(AnyPacketRef::Nmea(_)))
+                    | (2, Ok(AnyPacketRef::Ubx(PacketRef::AckAck(_)))) => found[idx] = true,
+                    _ => assert!(false),
+                }
+                idx += 1;
+            }
+        }
+        assert_eq!(found, [true; 3]);
+        assert!(parser.is_buffer_empty());
+    }
+
+    #[test]
+    fn parser_nmea_invalid_checksum() {
+        let mut bytes = [0; 75];
+        bytes.copy_from_slice(NMEA_GGA);
+        bytes[7] = b'1';
+
+        let mut buffer = [0; 128];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut it = parser.consume_any(&bytes);
+        assert_eq!(
+            it.next().map(|x| x.err()),
+            Some(Some(ParserError::InvalidNmeaChecksum {
+                expect: 0x45,
+                got: 0x44
+            }))
+        );
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn parser_nmea_truncated_sentence() {
+        let mut bytes = [0; 20 + 75];
+        bytes[..20].copy_from_slice(&NMEA_GGA[..20]);
+        bytes[20..].copy_from_slice(NMEA_GGA);
+
+        let mut buffer = [0; 128];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut it = parser.consume_any(&bytes);
+        match it.next() {
+            Some(Ok(AnyPacketRef::Nmea(sentence))) => {
+                assert_eq!(sentence.as_bytes(), NMEA_GGA);
+            }
+            _ => assert!(false),
+        }
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn parser_skips_nmea_by_default() {
+        let mut buffer = [0; 128];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut bytes = [0; UBX_ACK_ACK.len() + 75];
+        bytes[..75].copy_from_slice(NMEA_GGA);
+        bytes[75..].copy_from_slice(&UBX_ACK_ACK);
+
+        let mut it = parser.consume(&bytes);
+        match it.next() {
+            Some(Ok(PacketRef::AckAck(_))) => {}
+            _ => assert!(false),
+        }
+        assert!(it.next().is_none());
+    }
+
+    #[rustfmt::skip]
+    static RTCM_1005: [u8; 25] = [
+        0xd3, 0x00, 0x13, // Header
+        0x3e, 0xd7, 0xd3, 0x02, 0x02, 0x98, 0x0e, 0xde, 0xef, 0x34, 0xb4, 0xbd, 0x62, 0xac,
+        0x09, 0x41, 0x98, 0x6f, 0x33, // Payload
+        0x36, 0x0b, 0x98, // CRC-24Q
+    ];
+
+    #[test]
+    fn parser_rtcm_frame() {
+        let mut buffer = [0; 64];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut it = parser.consume_any(&RTCM_1005);
+        match it.next() {
+            Some(Ok(AnyPacketRef::Rtcm(frame))) => {
+                assert_eq!(frame.message_number(), Some(1005));
+                assert_eq!(frame.as_bytes(), &RTCM_1005[..]);
+                assert_eq!(frame.payload(), &RTCM_1005[3..22]);
+            }
+            _ => assert!(false),
+        }
+        assert!(it.next().is_none());
+    }
+
+    #[test]
+    fn parser_rtcm_interleaved_with_ubx_and_nmea() {
+        let mut buffer = [0; 128];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut bytes = [0; 25 + 75 + 10 + 25];
+        bytes[..25].copy_from_slice(&RTCM_1005);
+        bytes[25..100].copy_from_slice(NMEA_GGA);
+        bytes[100..110].copy_from_slice(&UBX_ACK_ACK);
+        bytes[110..].copy_from_slice(&RTCM_1005);
+
+        let mut found = [false; 4];
+        let mut idx = 0;
+        for chunk in bytes.chunks(9) {
+            let mut it = parser.consume_any(chunk);
+            while let Some(pack) = it.next() {
+                match (idx, pack) {
+                    (0, Ok(AnyPacketRef::Rtcm(_)))
+                    | (1, Ok(AnyPacketRef::Nmea(_)))
+                    | (2, Ok(AnyPacketRef::Ubx(PacketRef::AckAck(_))))
+                    | (3, Ok(AnyPacketRef::Rtcm(_))) => found[idx] = true,
+                    _ => assert!(false),
+                }
+                idx += 1;
+            }
+        }
+        assert_eq!(found, [true; 4]);
+        assert!(parser.is_buffer_empty());
+    }
+
+    #[test]
+    fn parser_rtcm_invalid_crc() {
+        let mut bytes = [0; 25 + 10];
+        bytes[..25].copy_from_slice(&RTCM_1005);
+        bytes[5] ^= 0x01;
+        bytes[25..].copy_from_slice(&UBX_ACK_ACK);
+
+        let mut buffer = [0; 64];
+        let buffer = FixedLinearBuffer::new(&mut buffer);
+        let mut parser = Parser::new(buffer);
+
+        let mut it = parser.consume_any(&bytes);
+        match it.next() {
+            Some(Err(ParserError::InvalidRtcmCrc { expect, got })) => {
+                assert_eq!(expect, 0x0036_0b98);
+                assert_ne!(got, expect);
+            }
+            _ => assert!(false),
+        }
+        match it.next() {
+            Some(Ok(AnyPacketRef::Ubx(PacketRef::AckAck(_)))) => {}
+            _ => assert!(false),
+        }
+        assert!(it.next().is_none());
+    }
 }
 
 #[test]
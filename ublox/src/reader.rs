@@ -0,0 +1,231 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{error::ParserError, parser::Parser, ubx_packets::PacketOwned};
+
+const READ_BUF_LEN: usize = 1024;
+
+/// Time to wait before reading again from a non-blocking reader without data
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Blocking packet reader on top of any `std::io::Read`, like a serial port,
+/// a file with a recorded UBX log or a TCP socket.
+///
+/// ```
+/// use ublox::{PacketReader, PacketRef};
+///
+/// let log: &[u8] = &[0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x6, 0x1, 0xf, 0x38];
+/// let mut reader = PacketReader::new(log);
+/// while let Some(packet) = reader.next_packet().unwrap() {
+///     if let PacketRef::AckAck(ack) = packet.as_packet_ref() {
+///         assert_eq!((ack.class(), ack.msg_id()), (6, 1));
+///     }
+/// }
+/// ```
+pub struct PacketReader<R> {
+    reader: R,
+    parser: Parser<Vec<u8>>,
+    read_buf: [u8; READ_BUF_LEN],
+    pending: VecDeque<Result<PacketOwned, ParserError>>,
+    timeout: Option<Duration>,
+}
+
+impl<R: Read> PacketReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            parser: Parser::default(),
+            read_buf: [0; READ_BUF_LEN],
+            pending: VecDeque::new(),
+            timeout: None,
+        }
+    }
+
+    /// Maximum time `next_packet` waits for a complete packet. `None` (the default)
+    /// means wait forever.
+    ///
+    /// Timeouts reported by the underlying reader (for example by a serial port
+    /// with its own read timeout) are not errors, `next_packet` keeps reading
+    /// until this timeout expires.
+    ///
+    /// The timeout is only checked between reads, so a blocking reader needs
+    /// its own read timeout (like `TcpStream::set_read_timeout`), otherwise
+    /// `next_packet` waits for as long as the reader does. Non-blocking readers
+    /// are polled every millisecond.
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Blocks until the next packet is received.
+    ///
+    /// Returns `Ok(None)` if the underlying reader reached EOF. A packet that
+    /// could not be parsed is reported as an error of kind `InvalidData`
+    /// containing the `ParserError`, and if no packet arrives in time an error
+    /// of kind `TimedOut` is returned. In both cases it is fine to call
+    /// `next_packet` again.
+    pub fn next_packet(&mut self) -> io::Result<Option<PacketOwned>> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            if let Some(packet) = self.pending.pop_front() {
+                return packet
+                    .map(Some)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err));
+            }
+
+            match self.reader.read(&mut self.read_buf) {
+                Ok(0) => return Ok(None),
+                Ok(nbytes) => {
+                    let mut it = self.parser.consume(&self.read_buf[..nbytes]);
+                    while let Some(packet) = it.next() {
+                        self.pending.push_back(packet.map(|x| x.to_owned()));
+                    }
+                }
+                Err(err)
+                    if err.kind() == io::ErrorKind::TimedOut
+                        || err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    let remaining =
+                        deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+                    thread::sleep(remaining.map_or(POLL_INTERVAL, |r| r.min(POLL_INTERVAL)));
+                }
+                Err(err) => return Err(err),
+            }
+
+            if self.pending.is_empty() {
+                if let Some(deadline) = deadline {
+                    if Instant::now() >= deadline {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "no packet received before timeout",
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gives access to the underlying reader, for example to write
+    /// packets to a serial port
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the underlying reader. Buffered data which is not
+    /// returned as packet yet is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ubx_packets::*;
+
+    static ACK_ACK: [u8; 10] = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x6, 0x1, 0xf, 0x38];
+
+    /// Returns data in small chunks, with timeouts in between
+    struct SlowReader {
+        data: Vec<u8>,
+        pos: usize,
+        timeout_next: bool,
+    }
+
+    impl Read for SlowReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.timeout_next = !self.timeout_next;
+            if !self.timeout_next {
+                return Err(io::Error::new(io::ErrorKind::TimedOut, "timeout"));
+            }
+            let n = buf.len().min(3).min(self.data.len() - self.pos);
+            buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn reader_splitted_packets() {
+        let mut data = vec![0x00, 0x24, 0x11];
+        data.extend_from_slice(&ACK_ACK);
+        data.extend_from_slice(&ACK_ACK);
+        let mut reader = PacketReader::new(SlowReader {
+            data,
+            pos: 0,
+            timeout_next: false,
+        });
+
+        for _ in 0..2 {
+            match reader.next_packet().unwrap().unwrap().as_packet_ref() {
+                PacketRef::AckAck(ack) => assert_eq!((ack.class(), ack.msg_id()), (6, 1)),
+                _ => panic!(),
+            }
+        }
+        assert!(reader.next_packet().unwrap().is_none());
+    }
+
+    #[test]
+    fn reader_invalid_packet() {
+        let mut data = ACK_ACK.to_vec();
+        data[7] = 5;
+        data.extend_from_slice(&ACK_ACK);
+        let mut reader = PacketReader::new(&data[..]);
+
+        let err = reader.next_packet().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.get_ref().unwrap().is::<ParserError>());
+        match reader.next_packet().unwrap().unwrap() {
+            PacketOwned::AckAck(_) => {}
+            _ => panic!(),
+        }
+        assert!(reader.next_packet().unwrap().is_none());
+    }
+
+    #[test]
+    fn reader_timeout() {
+        struct NoData;
+        impl Read for NoData {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                std::thread::sleep(Duration::from_millis(1));
+                Err(io::Error::new(io::ErrorKind::TimedOut, "timeout"))
+            }
+        }
+
+        let mut reader = PacketReader::new(NoData);
+        reader.set_timeout(Some(Duration::from_millis(20)));
+        let err = reader.next_packet().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn reader_would_block() {
+        struct NonBlocking {
+            reads: usize,
+        }
+        impl Read for NonBlocking {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                self.reads += 1;
+                Err(io::ErrorKind::WouldBlock.into())
+            }
+        }
+
+        let mut reader = PacketReader::new(NonBlocking { reads: 0 });
+        reader.set_timeout(Some(Duration::from_millis(20)));
+        let err = reader.next_packet().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        // Polled with a delay in between, not in a busy loop
+        assert!(reader.get_ref().reads <= 21);
+    }
+}
@@ -0,0 +1,110 @@
+use core::fmt;
+
+/// First byte of every RTCM 3.x frame
+pub(crate) const RTCM_PREAMBLE: u8 = 0xd3;
+
+/// Length of the frame header: preamble, 6 reserved bits and 10 bits of payload length
+pub(crate) const RTCM_HEADER_LEN: usize = 3;
+
+/// Length of the CRC-24Q at the end of the frame
+pub(crate) const RTCM_CRC_LEN: usize = 3;
+
+/// For CRC-24Q calculation on the fly
+pub(crate) struct Crc24qCalc {
+    crc: u32,
+}
+
+impl Crc24qCalc {
+    pub(crate) fn new() -> Self {
+        Self { crc: 0 }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        const POLY: u32 = 0x0186_4cfb;
+        let mut crc = self.crc;
+        for byte in bytes.iter() {
+            crc ^= u32::from(*byte) << 16;
+            for _ in 0..8 {
+                crc <<= 1;
+                if crc & 0x0100_0000 != 0 {
+                    crc ^= POLY;
+                }
+            }
+        }
+        self.crc = crc;
+    }
+
+    pub(crate) fn result(self) -> u32 {
+        self.crc & 0x00ff_ffff
+    }
+}
+
+/// RTCM 3.x frame found in the byte stream, with valid CRC.
+///
+/// Contains a reference to the whole frame, including the header and
+/// the CRC, so it can be forwarded as is.
+pub struct RtcmPacketRef<'a>(&'a [u8]);
+
+impl<'a> RtcmPacketRef<'a> {
+    /// Caller must make sure that `bytes` is a complete frame
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    /// Raw frame, including the header and the CRC
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Message payload, without header and CRC
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        &self.0[RTCM_HEADER_LEN..self.0.len() - RTCM_CRC_LEN]
+    }
+
+    /// Message number (for example 1005 or 1077), stored in the first 12 bits
+    /// of the payload. Returns `None` for payloads too short to contain it.
+    pub fn message_number(&self) -> Option<u16> {
+        let payload = self.payload();
+        if payload.len() < 2 {
+            return None;
+        }
+        Some((u16::from(payload[0]) << 4) | (u16::from(payload[1]) >> 4))
+    }
+}
+
+impl<'a> fmt::Debug for RtcmPacketRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RtcmPacketRef")
+            .field("message_number", &self.message_number())
+            .field("payload_len", &self.payload().len())
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for RtcmPacketRef<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("RtcmPacketRef", 2)?;
+        state.serialize_field("message_number", &self.message_number())?;
+        state.serialize_field("payload", self.payload())?;
+        state.end()
+    }
+}
+
+#[test]
+fn test_crc24q() {
+    let frame = [
+        0xd3, 0x00, 0x13, 0x3e, 0xd7, 0xd3, 0x02, 0x02, 0x98, 0x0e, 0xde, 0xef, 0x34, 0xb4, 0xbd,
+        0x62, 0xac, 0x09, 0x41, 0x98, 0x6f, 0x33, 0x36, 0x0b, 0x98,
+    ];
+    let mut crc = Crc24qCalc::new();
+    crc.update(&frame[..10]);
+    crc.update(&frame[10..22]);
+    assert_eq!(crc.result(), 0x0036_0b98);
+}
@@ -3,6 +3,8 @@ mod packets;
 mod types;
 
 use crate::error::MemWriterError;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 pub use packets::*;
 pub use types::*;
 
@@ -91,6 +93,38 @@ pub struct UbxUnknownPacketRef<'a> {
     pub msg_id: u8,
 }
 
+/// Owned version of `UbxUnknownPacketRef`
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UbxUnknownPacketOwned {
+    pub payload: Vec<u8>,
+    pub class: u8,
+    pub msg_id: u8,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl UbxUnknownPacketOwned {
+    pub fn as_packet_ref(&self) -> UbxUnknownPacketRef<'_> {
+        UbxUnknownPacketRef {
+            payload: &self.payload,
+            class: self.class,
+            msg_id: self.msg_id,
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'a> UbxUnknownPacketRef<'a> {
+    pub fn to_owned(&self) -> UbxUnknownPacketOwned {
+        UbxUnknownPacketOwned {
+            payload: self.payload.to_vec(),
+            class: self.class,
+            msg_id: self.msg_id,
+        }
+    }
+}
+
 /// Request specific packet
 pub struct UbxPacketRequest {
     req_class: u8,
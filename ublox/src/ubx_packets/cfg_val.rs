@@ -1,5 +1,13 @@
-use super::{AlignmentToReferenceTime, CfgInfMask, DataBits, Parity, StopBits, OdoProfile};
+use super::{
+    AlignmentToReferenceTime, CfgDgnssMode, CfgInfMask, CfgNav5DynModel, CfgNav5FixMode,
+    CfgNav5UtcStandard, DataBits, OdoProfile, Parity, StopBits,
+};
+use crate::error::{CfgValError, ParserError};
+use num_traits::float::FloatCore;
 
+/// Configuration item key, as used by CFG-VALSET, CFG-VALGET and CFG-VALDEL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct KeyId(u32);
 
 pub enum StorageSize {
@@ -24,6 +32,16 @@ impl StorageSize {
 impl KeyId {
     pub(crate) const SIZE: usize = 4;
 
+    /// Key with raw ID `id`, for example a wildcard like `0x0fff_ffff` (all items)
+    /// or `0x1052_ffff` (all items of group CFG-UART1) for CFG-VALGET
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    pub const fn value(&self) -> u32 {
+        self.0
+    }
+
     pub const fn value_size(&self) -> StorageSize {
         match (self.0 >> 28) & 0b111 {
             1 => StorageSize::OneBit,
@@ -46,95 +64,199 @@ impl KeyId {
     pub const fn item_id(&self) -> u8 {
         self.0 as u8
     }
+
+    pub fn extend_to<T>(&self, buf: &mut T) -> usize
+    where
+        T: core::iter::Extend<u8>,
+    {
+        let bytes = self.0.to_le_bytes();
+        // TODO: extend all the bytes in one extend() call when we bump MSRV
+        for b in bytes.iter() {
+            buf.extend(core::iter::once(*b));
+        }
+        bytes.len()
+    }
+}
+
+/// Splits `buf` into the key and its value, ignoring any bytes after the value
+fn split_key_value(buf: &[u8]) -> Result<(KeyId, &[u8]), ParserError> {
+    if buf.len() < KeyId::SIZE {
+        return Err(ParserError::InvalidPacketLen {
+            packet: "CfgVal",
+            expect: KeyId::SIZE,
+            got: buf.len(),
+        });
+    }
+    let key_id = KeyId(u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]));
+    let len = KeyId::SIZE + key_id.value_size().to_usize();
+    if buf.len() < len {
+        return Err(ParserError::InvalidPacketLen {
+            packet: "CfgVal",
+            expect: len,
+            got: buf.len(),
+        });
+    }
+    Ok((key_id, &buf[KeyId::SIZE..len]))
 }
 
+/// Returns `None` if the value is out of range for the type
 macro_rules! from_cfg_v_bytes {
     ($buf:expr, bool) => {
         match $buf[0] {
-            0 => false,
-            1 => true,
-            _ => unreachable!(),
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
         }
     };
     ($buf:expr, u8) => {
-        $buf[0]
+        Some($buf[0])
+    };
+    ($buf:expr, i8) => {
+        Some($buf[0] as i8)
     };
     ($buf:expr, u16) => {
-        u16::from_le_bytes([$buf[0], $buf[1]])
+        Some(u16::from_le_bytes([$buf[0], $buf[1]]))
     };
     ($buf:expr, i16) => {
-        i16::from_le_bytes([$buf[0], $buf[1]])
+        Some(i16::from_le_bytes([$buf[0], $buf[1]]))
     };
     ($buf:expr, u32) => {
-        u32::from_le_bytes([$buf[0], $buf[1], $buf[2], $buf[3]])
+        Some(u32::from_le_bytes([$buf[0], $buf[1], $buf[2], $buf[3]]))
+    };
+    ($buf:expr, i32) => {
+        Some(i32::from_le_bytes([$buf[0], $buf[1], $buf[2], $buf[3]]))
+    };
+    ($buf:expr, f32) => {
+        Some(f32::from_le_bytes([$buf[0], $buf[1], $buf[2], $buf[3]]))
     };
     ($buf:expr, u64) => {
-        u64::from_le_bytes([
+        Some(u64::from_le_bytes([
+            $buf[0], $buf[1], $buf[2], $buf[3], $buf[4], $buf[5], $buf[6], $buf[7],
+        ]))
+    };
+    ($buf:expr, f64) => {
+        Some(f64::from_le_bytes([
             $buf[0], $buf[1], $buf[2], $buf[3], $buf[4], $buf[5], $buf[6], $buf[7],
-        ])
+        ]))
     };
     ($buf:expr, CfgInfMask) => {
-        CfgInfMask::from_bits_truncate($buf[0])
+        Some(CfgInfMask::from_bits_truncate($buf[0]))
     };
     ($buf:expr, DataBits) => {
         match $buf[0] {
-            0 => DataBits::Eight,
-            1 => DataBits::Seven,
-            _ => unreachable!(),
+            0 => Some(DataBits::Eight),
+            1 => Some(DataBits::Seven),
+            _ => None,
         }
     };
     ($buf:expr, Parity) => {
         match $buf[0] {
-            0 => Parity::None,
-            1 => Parity::Odd,
-            2 => Parity::Even,
-            _ => unreachable!(),
+            0 => Some(Parity::None),
+            1 => Some(Parity::Odd),
+            2 => Some(Parity::Even),
+            _ => None,
         }
     };
     ($buf:expr, StopBits) => {
         match $buf[0] {
-            0 => StopBits::Half,
-            1 => StopBits::One,
-            2 => StopBits::OneHalf,
-            3 => StopBits::Two,
-            _ => unreachable!(),
+            0 => Some(StopBits::Half),
+            1 => Some(StopBits::One),
+            2 => Some(StopBits::OneHalf),
+            3 => Some(StopBits::Two),
+            _ => None,
         }
     };
     ($buf:expr, AlignmentToReferenceTime) => {
         match $buf[0] {
-            0 => AlignmentToReferenceTime::Utc,
-            1 => AlignmentToReferenceTime::Gps,
-            2 => AlignmentToReferenceTime::Glo,
-            3 => AlignmentToReferenceTime::Bds,
-            4 => AlignmentToReferenceTime::Gal,
-            _ => unreachable!(),
+            0 => Some(AlignmentToReferenceTime::Utc),
+            1 => Some(AlignmentToReferenceTime::Gps),
+            2 => Some(AlignmentToReferenceTime::Glo),
+            3 => Some(AlignmentToReferenceTime::Bds),
+            4 => Some(AlignmentToReferenceTime::Gal),
+            _ => None,
         }
     };
     ($buf:expr, TpPulse) => {
         match $buf[0] {
-            0 => TpPulse::Period,
-            1 => TpPulse::Freq,
-            _ => unreachable!(),
+            0 => Some(TpPulse::Period),
+            1 => Some(TpPulse::Freq),
+            _ => None,
         }
     };
     ($buf:expr, TpPulseLength) => {
         match $buf[0] {
-            0 => TpPulseLength::Ratio,
-            1 => TpPulseLength::Length,
-            _ => unreachable!(),
+            0 => Some(TpPulseLength::Ratio),
+            1 => Some(TpPulseLength::Length),
+            _ => None,
         }
     };
   // TODO: Make this work and replace OdoProfile with enum
     ($buf:expr, OdoProfile) => {
       match $buf[0] {
-          0 => OdoProfile::Running,
-          1 => OdoProfile::Cycling,
-          2 => OdoProfile::Swimming,
-          3 => OdoProfile::Car,
-          4 => OdoProfile::Custom,
-          _ => unreachable!(),
+          0 => Some(OdoProfile::Running),
+          1 => Some(OdoProfile::Cycling),
+          2 => Some(OdoProfile::Swimming),
+          3 => Some(OdoProfile::Car),
+          4 => Some(OdoProfile::Custom),
+          _ => None,
       }
   };
+    ($buf:expr, CfgNav5DynModel) => {
+        match $buf[0] {
+            0 => Some(CfgNav5DynModel::Portable),
+            2 => Some(CfgNav5DynModel::Stationary),
+            3 => Some(CfgNav5DynModel::Pedestrian),
+            4 => Some(CfgNav5DynModel::Automotive),
+            5 => Some(CfgNav5DynModel::Sea),
+            6 => Some(CfgNav5DynModel::AirborneWithLess1gAcceleration),
+            7 => Some(CfgNav5DynModel::AirborneWithLess2gAcceleration),
+            8 => Some(CfgNav5DynModel::AirborneWith4gAcceleration),
+            9 => Some(CfgNav5DynModel::WristWornWatch),
+            10 => Some(CfgNav5DynModel::Bike),
+            11 => Some(CfgNav5DynModel::Mower),
+            12 => Some(CfgNav5DynModel::EScooter),
+            _ => None,
+        }
+    };
+    ($buf:expr, PmpRate) => {
+        match u16::from_le_bytes([$buf[0], $buf[1]]) {
+            600 => Some(PmpRate::B600),
+            1200 => Some(PmpRate::B1200),
+            2400 => Some(PmpRate::B2400),
+            4800 => Some(PmpRate::B4800),
+            _ => None,
+        }
+    };
+    ($buf:expr, CfgDgnssMode) => {
+        match $buf[0] {
+            2 => Some(CfgDgnssMode::RtkFloat),
+            3 => Some(CfgDgnssMode::RtkFixed),
+            _ => None,
+        }
+    };
+    ($buf:expr, CfgNav5FixMode) => {
+        match $buf[0] {
+            1 => Some(CfgNav5FixMode::Only2D),
+            2 => Some(CfgNav5FixMode::Only3D),
+            3 => Some(CfgNav5FixMode::Auto2D3D),
+            _ => None,
+        }
+    };
+    ($buf:expr, CfgNav5UtcStandard) => {
+        match $buf[0] {
+            0 => Some(CfgNav5UtcStandard::Automatic),
+            3 => Some(CfgNav5UtcStandard::Usno),
+            5 => Some(CfgNav5UtcStandard::Eu),
+            6 => Some(CfgNav5UtcStandard::UtcSu),
+            7 => Some(CfgNav5UtcStandard::UtcChina),
+            8 => Some(CfgNav5UtcStandard::UtcIndia),
+            _ => None,
+        }
+    };
+    // Enums declared with `cfg_enum!`
+    ($buf:expr, $enum_type:ident) => {
+        $enum_type::from_raw($buf[0])
+    };
 }
 
 macro_rules! into_cfg_kv_bytes {
@@ -154,6 +276,9 @@ macro_rules! into_cfg_kv_bytes {
     ($this:expr, u8) => {{
       into_cfg_kv_bytes!(@inner [$this.0])
     }};
+    ($this:expr, i8) => {{
+      into_cfg_kv_bytes!(@inner [$this.0 as u8])
+    }};
     ($this:expr, u16) => {{
       let bytes = $this.0.to_le_bytes();
       into_cfg_kv_bytes!(@inner [bytes[0], bytes[1]])
@@ -166,10 +291,22 @@ macro_rules! into_cfg_kv_bytes {
       let bytes = $this.0.to_le_bytes();
       into_cfg_kv_bytes!(@inner [bytes[0], bytes[1], bytes[2], bytes[3]])
     }};
+    ($this:expr, i32) => {{
+      let bytes = $this.0.to_le_bytes();
+      into_cfg_kv_bytes!(@inner [bytes[0], bytes[1], bytes[2], bytes[3]])
+    }};
+    ($this:expr, f32) => {{
+      let bytes = $this.0.to_bits().to_le_bytes();
+      into_cfg_kv_bytes!(@inner [bytes[0], bytes[1], bytes[2], bytes[3]])
+    }};
     ($this:expr, u64) => {{
       let bytes = $this.0.to_le_bytes();
       into_cfg_kv_bytes!(@inner [bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
     }};
+    ($this:expr, f64) => {{
+      let bytes = $this.0.to_bits().to_le_bytes();
+      into_cfg_kv_bytes!(@inner [bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
+    }};
     ($this:expr, CfgInfMask) => {
       into_cfg_kv_bytes!(@inner [
         $this.0.bits()
@@ -223,25 +360,96 @@ macro_rules! into_cfg_kv_bytes {
           $this.0 as u8
       ])
     };
+    ($this:expr, PmpRate) => {{
+      let bytes = ($this.0 as u16).to_le_bytes();
+      into_cfg_kv_bytes!(@inner [bytes[0], bytes[1]])
+    }};
+    // Other one byte enums, like `CfgNav5DynModel` or the ones declared with `cfg_enum!`
+    ($this:expr, $enum_type:ident) => {
+      into_cfg_kv_bytes!(@inner [
+          $this.0 as u8
+      ])
+    };
+}
+
+/// Declares an enum stored in one byte, for configuration items
+macro_rules! cfg_enum {
+  (
+    $(#[$enum_comment:meta])*
+    pub enum $name:ident {
+      $(
+        $(#[$variant_comment:meta])*
+        $variant:ident = $value:literal,
+      )*
+    }
+  ) => {
+    $(#[$enum_comment])*
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum $name {
+      $(
+        $(#[$variant_comment])*
+        $variant = $value,
+      )*
+    }
+
+    impl $name {
+      const fn from_raw(value: u8) -> Option<Self> {
+        match value {
+          $(
+            $value => Some(Self::$variant),
+          )*
+          _ => None,
+        }
+      }
+    }
+  }
+}
+
+/// Range of a scaled item, either given explicitly or the range of the raw type
+macro_rules! cfg_scaled_limit {
+  (min, $raw_type:ident, $scale:expr, $limit:expr) => { $limit };
+  (max, $raw_type:ident, $scale:expr, $limit:expr) => { $limit };
+  (min, $raw_type:ident, $scale:expr) => { $raw_type::MIN as f64 * $scale };
+  (max, $raw_type:ident, $scale:expr) => { $raw_type::MAX as f64 * $scale };
 }
 
 macro_rules! cfg_val {
   (
     $(
       $(#[$class_comment:meta])*
-      $cfg_item:ident, $cfg_key_id:expr, $cfg_value_type:ident,
+      $cfg_item:ident, $cfg_key_id:expr, $cfg_value_type:ident
+      $(
+        as $scaled_fn:ident(
+          scale = $scale:expr, unit = $unit:literal $(, min = $min:expr, max = $max:expr)?
+        )
+      )?,
     )*
   ) => {
     #[derive(Debug, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
     #[non_exhaustive]
     pub enum CfgVal {
       $(
         $(#[$class_comment])*
         $cfg_item($cfg_value_type),
       )*
+      /// Item not known by this crate, with its raw little-endian value
+      /// in the first `key.value_size()` bytes
+      Unknown(KeyId, [u8; 8]),
     }
 
     impl CfgVal {
+      pub const fn key_id(&self) -> KeyId {
+        match self {
+          $(
+            Self::$cfg_item(_) => $cfg_item::KEY,
+          )*
+          Self::Unknown(key_id, _) => *key_id,
+        }
+      }
+
       pub const fn len(&self) -> usize {
         match self {
           $(
@@ -249,19 +457,26 @@ macro_rules! cfg_val {
               $cfg_item::SIZE
             }
           )*
+          Self::Unknown(key_id, _) => KeyId::SIZE + key_id.value_size().to_usize(),
         }
       }
 
-      #[track_caller]
-      pub fn parse(buf: &[u8]) -> Self {
-        let key_id = u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]);
-        match key_id {
+      /// Parses the key and value at the start of `buf`. Keys not known by this crate
+      /// are returned as `CfgVal::Unknown`.
+      pub fn parse(buf: &[u8]) -> Result<Self, ParserError> {
+        let (key_id, value) = split_key_value(buf)?;
+        match key_id.0 {
           $(
             $cfg_key_id => {
-              Self::$cfg_item(from_cfg_v_bytes!(&buf[4..], $cfg_value_type))
+              from_cfg_v_bytes!(value, $cfg_value_type)
+                .map(Self::$cfg_item)
+                .ok_or(ParserError::InvalidField {
+                  packet: "CfgVal",
+                  field: stringify!($cfg_item),
+                })
             },
           )*
-          _ => unimplemented!("unknown key ID: 0x{:8X}", key_id),
+          _ => Ok(Self::unknown(key_id, value)),
         }
       }
 
@@ -281,6 +496,57 @@ macro_rules! cfg_val {
               bytes_len
             }
           )*
+          Self::Unknown(key_id, value) => {
+            let value_len = key_id.value_size().to_usize();
+            key_id.extend_to(buf);
+            for b in value[..value_len].iter() {
+              buf.extend(core::iter::once(*b));
+            }
+            KeyId::SIZE + value_len
+          }
+        }
+      }
+
+      $(
+        $(
+          #[doc = concat!("`CfgVal::", stringify!($cfg_item), "` from a value in ", $unit, ".")]
+          #[doc = ""]
+          #[doc = concat!("Returns an error if the value is outside of [`", stringify!($cfg_item), "::MIN`, `", stringify!($cfg_item), "::MAX`].")]
+          pub fn $scaled_fn(value: f64) -> Result<Self, CfgValError> {
+            if !($cfg_item::MIN..=$cfg_item::MAX).contains(&value) {
+              return Err(CfgValError::OutOfRange {
+                item: stringify!($cfg_item),
+                value,
+                min: $cfg_item::MIN,
+                max: $cfg_item::MAX,
+              });
+            }
+            Ok(Self::$cfg_item(FloatCore::round(value / $scale) as $cfg_value_type))
+          }
+        )?
+      )*
+
+      /// Value converted to `unit()`, `None` for items without scale and unit
+      pub fn scaled_value(&self) -> Option<f64> {
+        match self {
+          $(
+            $(
+              Self::$cfg_item(raw) => Some(f64::from(*raw) * $scale),
+            )?
+          )*
+          _ => None,
+        }
+      }
+
+      /// Unit of `scaled_value()`
+      pub fn unit(&self) -> Option<&'static str> {
+        match self {
+          $(
+            $(
+              Self::$cfg_item(_) => Some($unit),
+            )?
+          )*
+          _ => None,
         }
       }
 
@@ -293,16 +559,32 @@ macro_rules! cfg_val {
               kv.len()
             }
           )*
+          Self::Unknown(key_id, value) => {
+            let value_len = key_id.value_size().to_usize();
+            buf[..KeyId::SIZE].copy_from_slice(&key_id.0.to_le_bytes());
+            buf[KeyId::SIZE..KeyId::SIZE + value_len].copy_from_slice(&value[..value_len]);
+            KeyId::SIZE + value_len
+          }
         }
       }
     }
 
     $(
-      struct $cfg_item(pub $cfg_value_type);
+      $(#[$class_comment])*
+      pub struct $cfg_item(pub $cfg_value_type);
 
       impl $cfg_item {
-        const KEY: KeyId = KeyId($cfg_key_id);
+        pub const KEY: KeyId = KeyId($cfg_key_id);
         const SIZE: usize = KeyId::SIZE + Self::KEY.value_size().to_usize();
+        $(
+          /// Multiplier from the raw value to `UNIT`
+          pub const SCALE: f64 = $scale;
+          pub const UNIT: &'static str = $unit;
+          /// Smallest valid value, in `UNIT`
+          pub const MIN: f64 = cfg_scaled_limit!(min, $cfg_value_type, $scale $(, $min)?);
+          /// Largest valid value, in `UNIT`
+          pub const MAX: f64 = cfg_scaled_limit!(max, $cfg_value_type, $scale $(, $max)?);
+        )?
 
         pub const fn into_cfg_kv_bytes(self) -> [u8; Self::SIZE] {
           into_cfg_kv_bytes!(self, $cfg_value_type)
@@ -392,7 +674,7 @@ cfg_val! {
   // CFG-RATE-*
   /// Nominal time between GNSS measurements
   /// (e.g. 100ms results in 10Hz measurement rate, 1000ms = 1Hz measurement rate)
-  RateMeas,              0x30210001, u16,
+  RateMeas,              0x30210001, u16 as rate_meas_ms(scale = 1.0, unit = "ms"),
   /// Ratio of number of measurements to number of navigation solutions
   RateNav,               0x30210002, u16,
   /// Time system to which measurements are aligned
@@ -402,11 +684,17 @@ cfg_val! {
   /// The priority messages are: UBX-NAV-PVT, UBX-NAV-POSECEF, UBX-NAV-POSLLH, UBX-NAV-VELECEF, UBX-NAV-VELNED, UBX-NAV-HPPOSECEF, UBX-NAV-HPPOSLLH, UBX-ESF-INS, UBX-NAV-ATT, UBX-NAV-PVAT, NMEA-Standard-DTM, NMEA-Standard-RMC, NMEA-Standard-VTG, NMEA-Standard-GNS, NMEA-Standard-GGA, NMEA-Standard-GLL, NMEA-Standard-THS and NMEA-PUBX-POSITION. Note that some of these messages are not available on some products.
   /// The allowed range for the priority navigation mode is 0-30 Hz.
   /// See section Priority navigation mode in the integration manual.
-  RateNavPrio,           0x20210004, u8,
+  RateNavPrio,           0x20210004, u8 as rate_nav_prio_hz(scale = 1.0, unit = "Hz", min = 0.0, max = 30.0),
 
   // Sensor Fusion Core config
   /// Use ADR/UDR sensor fusion
   UseSf, 0x10080001, bool,
+  /// X coordinate of the IMU-frame to CRP-frame lever arm in cm
+  SfcoreImu2CrpLaX, 0x30080002, i16 as sfcore_imu2crp_la_x_m(scale = 0.01, unit = "m"),
+  /// Y coordinate of the IMU-frame to CRP-frame lever arm in cm
+  SfcoreImu2CrpLaY, 0x30080003, i16 as sfcore_imu2crp_la_y_m(scale = 0.01, unit = "m"),
+  /// Z coordinate of the IMU-frame to CRP-frame lever arm in cm
+  SfcoreImu2CrpLaZ, 0x30080004, i16 as sfcore_imu2crp_la_z_m(scale = 0.01, unit = "m"),
 
   // Sensor Fusion Odometer CFG-SFODO-*
   /// Use odometer
@@ -420,9 +708,9 @@ cfg_val! {
   /// Odometer profile configuration
   OdoProfileSet, 0x20220005, OdoProfile,
   /// Upper speed limit for low-speed course over ground filter
-  CogMaxSpeed, 0x20220021, u8,
+  CogMaxSpeed, 0x20220021, u8 as cog_max_speed_mps(scale = 1.0, unit = "m/s"),
   /// Maximum acceptable position accuracy for computing low-speed filtered course over ground
-  CogMaxPosAcc, 0x20220022, u8,
+  CogMaxPosAcc, 0x20220022, u8 as cog_max_pos_acc_m(scale = 1.0, unit = "m"),
   /// Velocity low-pass filter level -- Range is from 0 to 255.
   VelLpGain, 0x20220031, u8,
   /// Course over ground low-pass filter level (at speed < 8 m/s) -- Range is from 0 to 255.
@@ -436,7 +724,7 @@ cfg_val! {
   /// Nominal gyroscope sensor data sampling frequency
   GyroFreq, 0x20060009, u8,
   /// Gyroscope sensor data latency due to e.g. CANbus
-  GyroLatency, 0x3006000a, u16,
+  GyroLatency, 0x3006000a, u16 as gyro_latency_ms(scale = 1.0, unit = "ms"),
   /// Gyroscope sensor data accuracy
   GyroAcc, 0x3006000b, u16,
   /// Accelerometer RMS threshold
@@ -444,7 +732,7 @@ cfg_val! {
   ///Nominal accelerometer sensor data sampling frequency
   AccelFreq, 0x20060016, u8,
   /// Accelerometer sensor data latency due to e.g. CAN bus
-  AccelLatency, 0x30060017, u16,
+  AccelLatency, 0x30060017, u16 as accel_latency_ms(scale = 1.0, unit = "ms"),
   /// Accelerometer sensor data accuracy
   AccelAcc, 0x30060018, u16,
   /// IMU enabled
@@ -456,11 +744,51 @@ cfg_val! {
   /// Enable automatic IMU-mount alignment
   ImuAutoMntAlg, 0x10060027, bool,
   /// User-defined IMU-mount yaw angle [0, 36000]
-  ImuMntAlgYaw, 0x4006002d, u32,
+  ImuMntAlgYaw, 0x4006002d, u32 as imu_mnt_alg_yaw_deg(scale = 1e-2, unit = "deg", min = 0.0, max = 360.0),
   /// User-defined IMU-mount pitch angle [-9000, 9000]
-  ImuMntAlgPitch, 0x3006002e, i16,
+  ImuMntAlgPitch, 0x3006002e, i16 as imu_mnt_alg_pitch_deg(scale = 1e-2, unit = "deg", min = -90.0, max = 90.0),
   /// User-defined IMU-mount roll angle [-18000, 18000]
-  ImuMntAlgRoll, 0x3006002f, i16,
+  ImuMntAlgRoll, 0x3006002f, i16 as imu_mnt_alg_roll_deg(scale = 1e-2, unit = "deg", min = -180.0, max = 180.0),
+
+  // Wheel tick sensor CFG-SFODO-*
+  /// Use combined rear wheel ticks instead of the single tick
+  SfodoCombineTicks, 0x10070001, bool,
+  /// Use speed measurements instead of wheel ticks
+  SfodoUseSpeed, 0x10070003, bool,
+  /// Disable automatic estimation of the maximum absolute wheel tick counter
+  SfodoDisAutoCountMax, 0x10070004, bool,
+  /// Disable automatic wheel tick direction pin polarity detection
+  SfodoDisAutoDirPinPol, 0x10070005, bool,
+  /// Disable automatic receiver reconfiguration for processing speed data
+  SfodoDisAutoSpeed, 0x10070006, bool,
+  /// Wheel tick scale factor to obtain distance [m] from wheel ticks, in 1e-6
+  SfodoFactor, 0x40070007, u32 as sfodo_factor(scale = 1e-6, unit = "m"),
+  /// Wheel tick quantization, in 1e-6 m or m/s
+  SfodoQuantError, 0x40070008, u32,
+  /// Wheel tick counter maximum value
+  SfodoCountMax, 0x40070009, u32,
+  /// Wheel tick data latency due to e.g. CAN bus
+  SfodoLatency, 0x3007000a, u16 as sfodo_latency_ms(scale = 1.0, unit = "ms"),
+  /// Nominal wheel tick data frequency (0 = not set)
+  SfodoFrequency, 0x2007000b, u8 as sfodo_frequency_hz(scale = 1.0, unit = "Hz"),
+  /// Count both rising and falling edges on wheel tick signal
+  SfodoCntBothEdges, 0x1007000d, bool,
+  /// Speed sensor dead band in cm/s (0 = not set)
+  SfodoSpeedBand, 0x3007000e, u16 as sfodo_speed_band_mps(scale = 0.01, unit = "m/s"),
+  /// Wheel tick signal enabled
+  SfodoUseWtPin, 0x1007000f, bool,
+  /// Wheel tick direction pin polarity: false for high means forward, true for low means forward
+  SfodoDirPinPol, 0x10070010, bool,
+  /// Disable automatic use of wheel tick or speed data received over the software interface
+  SfodoDisAutoSw, 0x10070011, bool,
+  /// X coordinate of the IMU-frame to VRP-frame lever arm in cm
+  SfodoImu2VrpLaX, 0x30070012, i16 as sfodo_imu2vrp_la_x_m(scale = 0.01, unit = "m"),
+  /// Y coordinate of the IMU-frame to VRP-frame lever arm in cm
+  SfodoImu2VrpLaY, 0x30070013, i16 as sfodo_imu2vrp_la_y_m(scale = 0.01, unit = "m"),
+  /// Z coordinate of the IMU-frame to VRP-frame lever arm in cm
+  SfodoImu2VrpLaZ, 0x30070014, i16 as sfodo_imu2vrp_la_z_m(scale = 0.01, unit = "m"),
+  /// Ignore the direction information of the wheel ticks
+  SfodoDisDirInfo, 0x1007001c, bool,
 
   
 
@@ -1173,26 +1501,532 @@ cfg_val! {
   SignalGloEna,          0x10310025, bool,
   SignalGloL1Ena,        0x10310018, bool,
   SignalGLoL2Ena,        0x1031001a, bool,
+  SignalGpsL5Ena,        0x10310004, bool,
+  SignalSbasEna,         0x10310020, bool,
+  SignalSbasL1caEna,     0x10310005, bool,
+  SignalGalE5aEna,       0x10310009, bool,
+  SignalBdsB1cEna,       0x1031000f, bool,
+  SignalBdsB2aEna,       0x10310028, bool,
+  SignalQzssL1sEna,      0x10310014, bool,
+  SignalQzssL5Ena,       0x10310017, bool,
+  SignalNavicEna,        0x10310026, bool,
+  SignalNavicL5Ena,      0x1031001d, bool,
 
   // CFG-TP-*
   TpPulseDef,            0x20050023, TpPulse,
   TpPulseLengthDef,      0x20050030, TpPulseLength,
-  TpAntCableDelay,       0x30050001, i16,
-  TpPeriodTp1,           0x40050002, u32,
-  TpPeriodLockTp1,       0x40050003, u32,
-  TpFreqTp1,             0x40050024, u32,
-  TpFreqLockTp1,         0x40050025, u32,
-  TpLenTp1,              0x40050004, u32,
-  TpLenLockTp1,          0x40050005, u32,
+  TpAntCableDelay,       0x30050001, i16 as tp_ant_cable_delay_ns(scale = 1.0, unit = "ns"),
+  TpPeriodTp1,           0x40050002, u32 as tp_period_tp1_us(scale = 1.0, unit = "us"),
+  TpPeriodLockTp1,       0x40050003, u32 as tp_period_lock_tp1_us(scale = 1.0, unit = "us"),
+  TpFreqTp1,             0x40050024, u32 as tp_freq_tp1_hz(scale = 1.0, unit = "Hz"),
+  TpFreqLockTp1,         0x40050025, u32 as tp_freq_lock_tp1_hz(scale = 1.0, unit = "Hz"),
+  TpLenTp1,              0x40050004, u32 as tp_len_tp1_us(scale = 1.0, unit = "us"),
+  TpLenLockTp1,          0x40050005, u32 as tp_len_lock_tp1_us(scale = 1.0, unit = "us"),
   TpTp1Ena,              0x10050007, bool,
   TpSyncGnssTp1,         0x10050008, bool,
   TpUseLockedTp1,        0x10050009, bool,
   TpAlignToTowTp1,       0x1005000a, bool,
   TpPolTp1,              0x1005000b, bool,
   TpTimegridTp1,         0x2005000c, AlignmentToReferenceTime,
+  /// Duty cycle of time pulse 1 in %, if `TpPulseLengthDef` is `Ratio`
+  TpDutyTp1,             0x5005002a, f64,
+  /// Duty cycle of time pulse 1 in % when locked to GNSS time
+  TpDutyLockTp1,         0x5005002b, f64,
+  /// User configurable time pulse 1 delay in ns
+  TpUserDelayTp1,        0x40050006, i32 as tp_user_delay_tp1_ns(scale = 1.0, unit = "ns"),
+  TpPeriodTp2,           0x4005000d, u32 as tp_period_tp2_us(scale = 1.0, unit = "us"),
+  TpPeriodLockTp2,       0x4005000e, u32 as tp_period_lock_tp2_us(scale = 1.0, unit = "us"),
+  TpFreqTp2,             0x40050026, u32 as tp_freq_tp2_hz(scale = 1.0, unit = "Hz"),
+  TpFreqLockTp2,         0x40050027, u32 as tp_freq_lock_tp2_hz(scale = 1.0, unit = "Hz"),
+  TpLenTp2,              0x4005000f, u32 as tp_len_tp2_us(scale = 1.0, unit = "us"),
+  TpLenLockTp2,          0x40050010, u32 as tp_len_lock_tp2_us(scale = 1.0, unit = "us"),
+  /// Duty cycle of time pulse 2 in %, if `TpPulseLengthDef` is `Ratio`
+  TpDutyTp2,             0x5005002c, f64,
+  /// Duty cycle of time pulse 2 in % when locked to GNSS time
+  TpDutyLockTp2,         0x5005002d, f64,
+  /// User configurable time pulse 2 delay in ns
+  TpUserDelayTp2,        0x40050011, i32 as tp_user_delay_tp2_ns(scale = 1.0, unit = "ns"),
+  TpTp2Ena,              0x10050012, bool,
+  TpSyncGnssTp2,         0x10050013, bool,
+  TpUseLockedTp2,        0x10050014, bool,
+  TpAlignToTowTp2,       0x10050015, bool,
+  TpPolTp2,              0x10050016, bool,
+  TpTimegridTp2,         0x20050017, AlignmentToReferenceTime,
+
+  // CFG-NAVSPG-*
+  /// Position fix mode
+  NavSpgFixMode,         0x20110011, CfgNav5FixMode,
+  /// Initial fix must be a 3D fix
+  NavSpgIniFix3d,        0x10110013, bool,
+  /// GPS week rollover number, GPS week numbers are set to be at or after this week number
+  NavSpgWknRollover,     0x30110017, u16,
+  /// Use precise point positioning
+  NavSpgUsePpp,          0x10110019, bool,
+  /// UTC standard to be used
+  NavSpgUtcStandard,     0x2011001c, CfgNav5UtcStandard,
+  /// Dynamic platform model
+  NavSpgDynModel,        0x20110021, CfgNav5DynModel,
+  /// Acknowledge assistance input messages
+  NavSpgAckAiding,       0x10110025, bool,
+  /// Use user geodetic datum parameters
+  NavSpgUseUsrDat,       0x10110061, bool,
+  /// Geodetic datum semi-major axis in m
+  NavSpgUsrDatMajA,      0x50110062, f64,
+  /// Geodetic datum 1.0 / flattening
+  NavSpgUsrDatFlat,      0x50110063, f64,
+  /// Geodetic datum X axis shift at the origin in m
+  NavSpgUsrDatDx,        0x40110064, f32,
+  /// Geodetic datum Y axis shift at the origin in m
+  NavSpgUsrDatDy,        0x40110065, f32,
+  /// Geodetic datum Z axis shift at the origin in m
+  NavSpgUsrDatDz,        0x40110066, f32,
+  /// Geodetic datum rotation about the X axis in arcsec
+  NavSpgUsrDatRotX,      0x40110067, f32,
+  /// Geodetic datum rotation about the Y axis in arcsec
+  NavSpgUsrDatRotY,      0x40110068, f32,
+  /// Geodetic datum rotation about the Z axis in arcsec
+  NavSpgUsrDatRotZ,      0x40110069, f32,
+  /// Geodetic datum scale factor in ppm
+  NavSpgUsrDatScale,     0x4011006a, f32,
+  /// Minimum number of satellites for navigation
+  NavSpgInfilMinSvs,     0x201100a1, u8,
+  /// Maximum number of satellites for navigation
+  NavSpgInfilMaxSvs,     0x201100a2, u8,
+  /// Minimum satellite signal level for navigation in dBHz
+  NavSpgInfilMinCno,     0x201100a3, u8 as nav_spg_infil_min_cno_dbhz(scale = 1.0, unit = "dBHz"),
+  /// Minimum elevation for a GNSS satellite to be used in navigation in deg
+  NavSpgInfilMinElev,    0x201100a4, i8 as nav_spg_infil_min_elev_deg(scale = 1.0, unit = "deg"),
+  /// Number of satellites required to have C/N0 above `NavSpgInfilCnoThrs` for a fix to be attempted
+  NavSpgInfilNcnoThrs,   0x201100aa, u8,
+  /// C/N0 threshold for deciding whether to attempt a fix in dBHz
+  NavSpgInfilCnoThrs,    0x201100ab, u8 as nav_spg_infil_cno_thrs_dbhz(scale = 1.0, unit = "dBHz"),
+  /// Output filter position DOP mask (threshold), scaled by 0.1
+  NavSpgOutfilPdop,      0x301100b1, u16 as nav_spg_outfil_pdop(scale = 0.1, unit = ""),
+  /// Output filter time DOP mask (threshold), scaled by 0.1
+  NavSpgOutfilTdop,      0x301100b2, u16 as nav_spg_outfil_tdop(scale = 0.1, unit = ""),
+  /// Output filter position accuracy mask (threshold) in m
+  NavSpgOutfilPacc,      0x301100b3, u16 as nav_spg_outfil_pacc_m(scale = 1.0, unit = "m"),
+  /// Output filter time accuracy mask (threshold) in m
+  NavSpgOutfilTacc,      0x301100b4, u16 as nav_spg_outfil_tacc_m(scale = 1.0, unit = "m"),
+  /// Output filter frequency accuracy mask (threshold) in 0.01 m/s
+  NavSpgOutfilFacc,      0x301100b5, u16 as nav_spg_outfil_facc_mps(scale = 0.01, unit = "m/s"),
+  /// Fixed altitude (mean sea level) for 2D fix mode in 0.01 m
+  NavSpgConstrAlt,       0x401100c1, i32 as nav_spg_constr_alt_m(scale = 0.01, unit = "m"),
+  /// Fixed altitude variance for 2D mode in 0.0001 m^2
+  NavSpgConstrAltVar,    0x401100c2, u32 as nav_spg_constr_alt_var_m2(scale = 1e-4, unit = "m^2"),
+  /// DGNSS timeout in s
+  NavSpgConstrDgnssTo,   0x201100c4, u8 as nav_spg_constr_dgnss_to_s(scale = 1.0, unit = "s"),
+  /// Signal attenuation compensation: 0 disabled, 255 automatic, or the maximum C/N0 in dBHz
+  NavSpgSigAttComp,      0x201100d6, u8 as nav_spg_sig_att_comp_dbhz(scale = 1.0, unit = "dBHz"),
+
+  // CFG-NAVHPG-*
+  /// Differential corrections mode
+  NavHpgDgnssMode,       0x20140011, CfgDgnssMode,
+
+  // CFG-TMODE-*
+  /// Receiver mode
+  TmodeMode,             0x20030001, TmodeReceiverMode,
+  /// Determines whether the ARP position is given in ECEF or LAT/LON/HEIGHT
+  TmodePosType,          0x20030002, TmodePositionType,
+  /// ECEF X coordinate of the ARP position in cm
+  TmodeEcefX,            0x40030003, i32 as tmode_ecef_x_m(scale = 0.01, unit = "m"),
+  /// ECEF Y coordinate of the ARP position in cm
+  TmodeEcefY,            0x40030004, i32 as tmode_ecef_y_m(scale = 0.01, unit = "m"),
+  /// ECEF Z coordinate of the ARP position in cm
+  TmodeEcefZ,            0x40030005, i32 as tmode_ecef_z_m(scale = 0.01, unit = "m"),
+  /// High-precision ECEF X coordinate of the ARP position in 0.1 mm
+  TmodeEcefXHp,          0x20030006, i8 as tmode_ecef_xhp_m(scale = 1e-4, unit = "m", min = -0.0099, max = 0.0099),
+  /// High-precision ECEF Y coordinate of the ARP position in 0.1 mm
+  TmodeEcefYHp,          0x20030007, i8 as tmode_ecef_yhp_m(scale = 1e-4, unit = "m", min = -0.0099, max = 0.0099),
+  /// High-precision ECEF Z coordinate of the ARP position in 0.1 mm
+  TmodeEcefZHp,          0x20030008, i8 as tmode_ecef_zhp_m(scale = 1e-4, unit = "m", min = -0.0099, max = 0.0099),
+  /// Latitude of the ARP position in 1e-7 deg
+  TmodeLat,              0x40030009, i32 as tmode_lat_deg(scale = 1e-7, unit = "deg", min = -90.0, max = 90.0),
+  /// Longitude of the ARP position in 1e-7 deg
+  TmodeLon,              0x4003000a, i32 as tmode_lon_deg(scale = 1e-7, unit = "deg", min = -180.0, max = 180.0),
+  /// Height of the ARP position in cm
+  TmodeHeight,           0x4003000b, i32 as tmode_height_m(scale = 0.01, unit = "m"),
+  /// High-precision latitude of the ARP position in 1e-9 deg
+  TmodeLatHp,            0x2003000c, i8 as tmode_lat_hp_deg(scale = 1e-9, unit = "deg", min = -9.9e-8, max = 9.9e-8),
+  /// High-precision longitude of the ARP position in 1e-9 deg
+  TmodeLonHp,            0x2003000d, i8 as tmode_lon_hp_deg(scale = 1e-9, unit = "deg", min = -9.9e-8, max = 9.9e-8),
+  /// High-precision height of the ARP position in 0.1 mm
+  TmodeHeightHp,         0x2003000e, i8 as tmode_height_hp_m(scale = 1e-4, unit = "m", min = -0.0099, max = 0.0099),
+  /// Fixed position 3D accuracy in 0.1 mm
+  TmodeFixedPosAcc,      0x4003000f, u32 as tmode_fixed_pos_acc_m(scale = 1e-4, unit = "m"),
+  /// Survey-in minimum duration in s
+  TmodeSvinMinDur,       0x40030010, u32 as tmode_svin_min_dur_s(scale = 1.0, unit = "s"),
+  /// Survey-in position accuracy limit in 0.1 mm
+  TmodeSvinAccLimit,     0x40030011, u32 as tmode_svin_acc_limit_m(scale = 1e-4, unit = "m"),
+
+  // CFG-ITFM-*
+  /// Broadband jamming detection threshold in dB
+  ItfmBbThreshold,       0x20410001, u8 as itfm_bb_threshold_db(scale = 1.0, unit = "dB"),
+  /// CW jamming detection threshold in dB
+  ItfmCwThreshold,       0x20410002, u8 as itfm_cw_threshold_db(scale = 1.0, unit = "dB"),
+  /// Enable interference detection
+  ItfmEnable,            0x1041000d, bool,
+  /// Antenna setting
+  ItfmAntSetting,        0x20410010, ItfmAntennaSetting,
+  /// Scan auxiliary bands
+  ItfmEnableAux,         0x10410013, bool,
+
+  // CFG-PM-*
+  /// Power saving mode
+  PmOperateMode,         0x20d00001, PowerMode,
+  /// Position update period for PSMOO in s
+  PmPosUpdatePeriod,     0x40d00002, u32 as pm_pos_update_period_s(scale = 1.0, unit = "s"),
+  /// Acquisition period if previously failed to achieve a position fix in s
+  PmAcqPeriod,           0x40d00003, u32 as pm_acq_period_s(scale = 1.0, unit = "s"),
+  /// Position update period grid offset relative to GPS start of week in s
+  PmGridOffset,          0x40d00004, u32 as pm_grid_offset_s(scale = 1.0, unit = "s"),
+  /// Time to stay in tracking state in s
+  PmOnTime,              0x30d00005, u16 as pm_on_time_s(scale = 1.0, unit = "s"),
+  /// Minimal search time in s
+  PmMinAcqTime,          0x20d00006, u8 as pm_min_acq_time_s(scale = 1.0, unit = "s"),
+  /// Maximal search time in s
+  PmMaxAcqTime,          0x20d00007, u8 as pm_max_acq_time_s(scale = 1.0, unit = "s"),
+  /// Behavior of receiver in case of no fix
+  PmDoNotEnterOff,       0x10d00008, bool,
+  /// Wait for time fix
+  PmWaitTimeFix,         0x10d00009, bool,
+  /// Update ephemeris regularly
+  PmUpdateEph,           0x10d0000a, bool,
+  /// EXTINT pin select, 0 for EXTINT0 and 1 for EXTINT1
+  PmExtIntSel,           0x20d0000b, u8,
+  /// EXTINT pin control (wake)
+  PmExtIntWake,          0x10d0000c, bool,
+  /// EXTINT pin control (backup)
+  PmExtIntBackup,        0x10d0000d, bool,
+  /// EXTINT pin control (inactive)
+  PmExtIntInactive,      0x10d0000e, bool,
+  /// Inactivity time out on EXTINT pin if enabled in ms
+  PmExtIntInactivity,    0x40d0000f, u32 as pm_ext_int_inactivity_ms(scale = 1.0, unit = "ms"),
+  /// Limit peak current
+  PmLimitPeakCurr,       0x10d00010, bool,
+
+  // CFG-SBAS-*
+  /// Use SBAS data when it is in test mode
+  SbasUseTestMode,       0x10360002, bool,
+  /// Use SBAS GEOs as a ranging source (for navigation)
+  SbasUseRanging,        0x10360003, bool,
+  /// Use SBAS differential corrections
+  SbasUseDiffCorr,       0x10360004, bool,
+  /// Use SBAS integrity information
+  SbasUseIntegrity,      0x10360005, bool,
+  /// SBAS PRN search configuration, bit 0 for PRN 120 up to bit 38 for PRN 158
+  SbasPrnScanMask,       0x50360006, u64,
+
+  // CFG-GEOFENCE-*
+  /// Required confidence level for state evaluation
+  GeofenceConfLvl,       0x20240011, GeofenceConfidence,
+  /// Use PIO combined fence state output
+  GeofenceUsePio,        0x10240012, bool,
+  /// PIO pin polarity
+  GeofencePinPol,        0x20240013, GeofencePinPolarity,
+  /// PIO pin number
+  GeofencePin,           0x20240014, u8,
+  /// Use first geofence
+  GeofenceUseFence1,     0x10240020, bool,
+  /// Latitude of the first geofence circle center in 1e-7 deg
+  GeofenceFence1Lat,     0x40240021, i32 as geofence_fence1_lat_deg(scale = 1e-7, unit = "deg", min = -90.0, max = 90.0),
+  /// Longitude of the first geofence circle center in 1e-7 deg
+  GeofenceFence1Lon,     0x40240022, i32 as geofence_fence1_lon_deg(scale = 1e-7, unit = "deg", min = -180.0, max = 180.0),
+  /// Radius of the first geofence circle in 0.01 m
+  GeofenceFence1Rad,     0x40240023, u32 as geofence_fence1_rad_m(scale = 0.01, unit = "m"),
+  /// Use second geofence
+  GeofenceUseFence2,     0x10240030, bool,
+  /// Latitude of the second geofence circle center in 1e-7 deg
+  GeofenceFence2Lat,     0x40240031, i32 as geofence_fence2_lat_deg(scale = 1e-7, unit = "deg", min = -90.0, max = 90.0),
+  /// Longitude of the second geofence circle center in 1e-7 deg
+  GeofenceFence2Lon,     0x40240032, i32 as geofence_fence2_lon_deg(scale = 1e-7, unit = "deg", min = -180.0, max = 180.0),
+  /// Radius of the second geofence circle in 0.01 m
+  GeofenceFence2Rad,     0x40240033, u32 as geofence_fence2_rad_m(scale = 0.01, unit = "m"),
+  /// Use third geofence
+  GeofenceUseFence3,     0x10240040, bool,
+  /// Latitude of the third geofence circle center in 1e-7 deg
+  GeofenceFence3Lat,     0x40240041, i32 as geofence_fence3_lat_deg(scale = 1e-7, unit = "deg", min = -90.0, max = 90.0),
+  /// Longitude of the third geofence circle center in 1e-7 deg
+  GeofenceFence3Lon,     0x40240042, i32 as geofence_fence3_lon_deg(scale = 1e-7, unit = "deg", min = -180.0, max = 180.0),
+  /// Radius of the third geofence circle in 0.01 m
+  GeofenceFence3Rad,     0x40240043, u32 as geofence_fence3_rad_m(scale = 0.01, unit = "m"),
+  /// Use fourth geofence
+  GeofenceUseFence4,     0x10240050, bool,
+  /// Latitude of the fourth geofence circle center in 1e-7 deg
+  GeofenceFence4Lat,     0x40240051, i32 as geofence_fence4_lat_deg(scale = 1e-7, unit = "deg", min = -90.0, max = 90.0),
+  /// Longitude of the fourth geofence circle center in 1e-7 deg
+  GeofenceFence4Lon,     0x40240052, i32 as geofence_fence4_lon_deg(scale = 1e-7, unit = "deg", min = -180.0, max = 180.0),
+  /// Radius of the fourth geofence circle in 0.01 m
+  GeofenceFence4Rad,     0x40240053, u32 as geofence_fence4_rad_m(scale = 0.01, unit = "m"),
+
+  // CFG-HW-*
+  /// Active antenna voltage control enable
+  HwAntCfgVoltCtrl,      0x10a3002e, bool,
+  /// Short antenna detection enable
+  HwAntCfgShortDet,      0x10a3002f, bool,
+  /// Short antenna detection polarity
+  HwAntCfgShortDetPol,   0x10a30030, bool,
+  /// Open antenna detection enable
+  HwAntCfgOpenDet,       0x10a30031, bool,
+  /// Open antenna detection polarity
+  HwAntCfgOpenDetPol,    0x10a30032, bool,
+  /// Power down antenna supply if short or open is detected
+  HwAntCfgPwrDown,       0x10a30033, bool,
+  /// Power down antenna logic polarity
+  HwAntCfgPwrDownPol,    0x10a30034, bool,
+  /// Automatic recovery from short state
+  HwAntCfgRecover,       0x10a30035, bool,
+  /// ANT1 PIO number
+  HwAntSupSwitchPin,     0x20a30036, u8,
+  /// ANT0 PIO number
+  HwAntSupShortPin,      0x20a30037, u8,
+  /// ANT2 PIO number
+  HwAntSupOpenPin,       0x20a30038, u8,
+
+  // CFG-I2C-*
+  /// I2C slave address of the receiver (7 bits)
+  I2cAddress,            0x20510001, u8,
+  /// Disable timeouting the interface after 1.5 s
+  I2cExtendedTimeout,    0x10510002, bool,
+  /// Flag to indicate if the I2C interface should be enabled
+  I2cEnabled,            0x10510003, bool,
+
+  // CFG-I2CINPROT-*
+  I2cInProtUbx,          0x10710001, bool,
+  I2cInProtNmea,         0x10710002, bool,
+  I2cInProtRtcm3x,       0x10710004, bool,
+
+  // CFG-I2COUTPROT-*
+  I2cOutProtUbx,         0x10720001, bool,
+  I2cOutProtNmea,        0x10720002, bool,
+  I2cOutProtRtcm3x,      0x10720004, bool,
+
+  // CFG-SPI-*
+  /// Number of bytes containing 0xFF to receive before switching off reception
+  SpiMaxFf,              0x20640001, u8,
+  /// Clock polarity select: false for active high clock, true for active low clock
+  SpiCPolarity,          0x10640002, bool,
+  /// Clock phase select: false for data captured on first edge, true on second edge
+  SpiCPhase,             0x10640003, bool,
+  /// Flag to disable timeouting the interface after 1.5 s
+  SpiExtendedTimeout,    0x10640005, bool,
+  /// Flag to indicate if the SPI interface should be enabled
+  SpiEnabled,            0x10640006, bool,
+
+  // CFG-SPIINPROT-*
+  SpiInProtUbx,          0x10790001, bool,
+  SpiInProtNmea,         0x10790002, bool,
+  SpiInProtRtcm3x,       0x10790004, bool,
+
+  // CFG-SPIOUTPROT-*
+  SpiOutProtUbx,         0x107a0001, bool,
+  SpiOutProtNmea,        0x107a0002, bool,
+  SpiOutProtRtcm3x,      0x107a0004, bool,
+
+  // CFG-NMEA-*
+  /// NMEA protocol version
+  NmeaProtVer,           0x20930001, NmeaVersion,
+  /// Maximum number of SVs to report per Talker ID, 0 for unlimited
+  NmeaMaxSvs,            0x20930002, u8,
+  /// Enable compatibility mode
+  NmeaCompat,            0x10930003, bool,
+  /// Enable considering mode
+  NmeaConsider,          0x10930004, bool,
+  /// Enable strict limit to 82 characters maximum NMEA message length
+  NmeaLimit82,           0x10930005, bool,
+  /// Enable high precision mode
+  NmeaHighPrec,          0x10930006, bool,
+  /// Display configuration for SVs that do not have value defined in NMEA
+  NmeaSvNumbering,       0x20930007, NmeaSvNumberingScheme,
+  /// Disable reporting of GPS satellites
+  NmeaFiltGps,           0x10930011, bool,
+  /// Disable reporting of SBAS satellites
+  NmeaFiltSbas,          0x10930012, bool,
+  /// Disable reporting of Galileo satellites
+  NmeaFiltGal,           0x10930013, bool,
+  /// Disable reporting of QZSS satellites
+  NmeaFiltQzss,          0x10930015, bool,
+  /// Disable reporting of GLONASS satellites
+  NmeaFiltGlo,           0x10930016, bool,
+  /// Disable reporting of BeiDou satellites
+  NmeaFiltBds,           0x10930017, bool,
+  /// Enable position output for failed or invalid fixes
+  NmeaOutInvFix,         0x10930021, bool,
+  /// Enable position output for invalid fixes
+  NmeaOutMskFix,         0x10930022, bool,
+  /// Enable time output for invalid times
+  NmeaOutInvTime,        0x10930023, bool,
+  /// Enable date output for invalid dates
+  NmeaOutInvDate,        0x10930024, bool,
+  /// Restrict output to GPS satellites only
+  NmeaOutOnlyGps,        0x10930025, bool,
+  /// Enable course over ground output even if it is frozen
+  NmeaOutFrozenCog,      0x10930026, bool,
+  /// Main Talker ID
+  NmeaMainTalkerId,      0x20930031, NmeaTalkerId,
+  /// Talker ID for GSV NMEA messages
+  NmeaGsvTalkerId,       0x20930032, NmeaGsvTalker,
+  /// BeiDou Talker ID, two ASCII characters, 0 for the default
+  NmeaBdsTalkerId,       0x30930033, u16,
+
+  // CFG-QZSS-*
+  /// Apply QZSS SLAS DGNSS corrections
+  QzssUseSlasDgnss,      0x10370005, bool,
+  /// Use QZSS SLAS data when it is in test mode (SLAS msg 0)
+  QzssUseSlasTestMode,   0x10370006, bool,
+  /// Raim out measurements that are not corrected by QZSS SLAS, if at least 5 measurements are corrected
+  QzssUseSlasRaimUncorr, 0x10370007, bool,
+  /// Maximum baseline distance to closest Ground Monitoring Station in km
+  QzssSlasMaxBaseline,   0x30370008, u16 as qzss_slas_max_baseline_km(scale = 1.0, unit = "km"),
+
+  // CFG-RINV-*
+  /// Dump data at startup
+  RinvDump,              0x10c70001, bool,
+  /// Data is binary
+  RinvBinary,            0x10c70002, bool,
+  /// Size of data, in bytes
+  RinvDataSize,          0x20c70003, u8 as rinv_data_size_bytes(scale = 1.0, unit = "bytes"),
+  /// Data bytes 1-8 (LSB)
+  RinvChunk0,            0x50c70004, u64,
+  /// Data bytes 9-16 (LSB)
+  RinvChunk1,            0x50c70005, u64,
+  /// Data bytes 17-24 (LSB)
+  RinvChunk2,            0x50c70006, u64,
+  /// Data bytes 25-30 (LSB)
+  RinvChunk3,            0x50c70007, u64,
+
+  // CFG-SEC-*
+  /// Configuration lockdown
+  SecCfgLock,            0x10f60009, bool,
+  /// Configuration lockdown exempted group 1
+  SecCfgLockUnlockGrp1,  0x30f6000a, u16,
+  /// Configuration lockdown exempted group 2
+  SecCfgLockUnlockGrp2,  0x30f6000b, u16,
+
+  // CFG-TXREADY-*
+  /// Flag to indicate if TX ready pin mechanism should be enabled
+  TxReadyEnabled,        0x10a20001, bool,
+  /// The polarity of the TX ready pin: false for high-active, true for low-active
+  TxReadyPolarity,       0x10a20002, bool,
+  /// PIO to be used (must not be in use by another function)
+  TxReadyPin,            0x20a20003, u8,
+  /// Amount of data that should be ready on the interface before triggering the TX ready pin, in 8 bytes
+  TxReadyThreshold,      0x30a20004, u16 as tx_ready_threshold_bytes(scale = 8.0, unit = "bytes"),
+  /// Interface where the TX ready feature should be linked to, 0 for I2C and 1 for SPI
+  TxReadyInterface,      0x20a20005, u8,
+
+  // CFG-LOGFILTER-*
+  /// Recording enabled
+  LogFilterRecordEna,    0x10de0002, bool,
+  /// Once per wake up
+  LogFilterOncePerWakeUpEna, 0x10de0003, bool,
+  /// Apply all filter settings
+  LogFilterApplyAllFilters, 0x10de0004, bool,
+  /// Minimum time interval between logged positions in s
+  LogFilterMinInterval,  0x30de0005, u16 as log_filter_min_interval_s(scale = 1.0, unit = "s"),
+  /// Time threshold in s
+  LogFilterTimeThrs,     0x30de0006, u16 as log_filter_time_thrs_s(scale = 1.0, unit = "s"),
+  /// Speed threshold in m/s
+  LogFilterSpeedThrs,    0x30de0007, u16 as log_filter_speed_thrs_mps(scale = 1.0, unit = "m/s"),
+  /// Position threshold in m
+  LogFilterPositionThrs, 0x40de0008, u32 as log_filter_position_thrs_m(scale = 1.0, unit = "m"),
+
+  // CFG-ANA-*
+  /// Use AssistNow Autonomous
+  AnaUseAna,             0x10230001, bool,
+  /// Maximum acceptable (modeled) orbit error in m
+  AnaOrbMaxErr,          0x30230002, u16 as ana_orb_max_err_m(scale = 1.0, unit = "m", min = 5.0, max = 1000.0),
+
+  // CFG-BATCH-*
+  /// Enable data batching
+  BatchEnable,           0x10260013, bool,
+  /// Enable PIO notification when the buffer fill level exceeds `BatchWarnThrs`
+  BatchPioEnable,        0x10260014, bool,
+  /// Size of the buffer (number of epochs)
+  BatchMaxEntries,       0x30260015, u16,
+  /// Buffer fill level that triggers the PIO notification
+  BatchWarnThrs,         0x30260016, u16,
+  /// The PIO is driven low when the buffer fill level exceeds the threshold
+  BatchPioActiveLow,     0x10260018, bool,
+  /// PIO used for the buffer fill level notification
+  BatchPioId,            0x20260019, u8,
+  /// Include additional PVT information in UBX-LOG-BATCH messages
+  BatchExtraPvt,         0x1026001a, bool,
+  /// Include odometer data in UBX-LOG-BATCH messages
+  BatchExtraOdo,         0x1026001b, bool,
+
+  // CFG-BDS-*
+  /// Use BeiDou geostationary satellites (PRN 1-5 and 59-63)
+  BdsUseGeoPrn,          0x10340014, bool,
+
+  // CFG-MOT-*
+  /// GNSS speed threshold below which the platform is considered as stationary, in cm/s
+  MotGnssSpeedThrs,      0x20250038, u8 as mot_gnss_speed_thrs_mps(scale = 0.01, unit = "m/s"),
+  /// Distance above which the GNSS-based stationary motion is exited
+  MotGnssDistThrs,       0x3025003b, u16 as mot_gnss_dist_thrs_m(scale = 1.0, unit = "m"),
+
+  // CFG-NAV2-*
+  /// Enable the secondary output (UBX-NAV2 messages)
+  Nav2OutEnabled,        0x10170001, bool,
+  /// Use SBAS integrity information in the secondary output
+  Nav2SbasUseIntegrity,  0x10170002, bool,
+
+  // CFG-RTCM-*
+  /// Reference station ID (DF003) of the RTCM 3.X output
+  RtcmDf003Out,          0x30090001, u16,
+  /// Reference station ID (DF003) accepted in the RTCM 3.X input
+  RtcmDf003In,           0x30090008, u16,
+  /// Filtering of the RTCM 3.X input by reference station ID
+  RtcmDf003InFilter,     0x20090009, RtcmDf003Filter,
+
+  // CFG-SPARTN-*
+  /// Source of the SPARTN corrections
+  SpartnUseSource,       0x20a70001, SpartnSource,
+
+  // CFG-PMP-*
+  /// Center frequency of the L-band channel in Hz
+  PmpCenterFrequency,    0x40b10011, u32 as pmp_center_frequency_hz(scale = 1.0, unit = "Hz"),
+  /// Search window around the center frequency in Hz
+  PmpSearchWindow,       0x30b10012, u16 as pmp_search_window_hz(scale = 1.0, unit = "Hz"),
+  /// Data rate of the L-band channel
+  PmpDataRate,           0x30b10013, PmpRate,
+  /// Use the descrambler
+  PmpUseDescrambler,     0x10b10014, bool,
+  /// Descrambler initialization value
+  PmpDescramblerInit,    0x30b10015, u16,
+  /// Only accept frames with `PmpServiceId`
+  PmpUseServiceId,       0x10b10016, bool,
+  /// Expected service ID
+  PmpServiceId,          0x30b10017, u16,
+  /// Use prescrambling
+  PmpUsePrescrambling,   0x10b10019, bool,
+  /// Unique word of the frames
+  PmpUniqueWord,         0x50b1001a, u64,
+}
+
+impl CfgVal {
+    /// `value` should be `key_id.value_size()` bytes long
+    fn unknown(key_id: KeyId, value: &[u8]) -> Self {
+        let mut raw = [0; 8];
+        raw[..value.len()].copy_from_slice(value);
+        Self::Unknown(key_id, raw)
+    }
+
+    /// Like `parse`, but values which can't be decoded, like out of range
+    /// enum values, are returned as `CfgVal::Unknown` too
+    pub(crate) fn parse_or_unknown(buf: &[u8]) -> Result<Self, ParserError> {
+        Self::parse(buf).or_else(|err| match err {
+            ParserError::InvalidField { .. } => {
+                let (key_id, value) = split_key_value(buf)?;
+                Ok(Self::unknown(key_id, value))
+            }
+            err => Err(err),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TpPulse {
     /// Time pulse period
     Period = 0,
@@ -1201,9 +2035,156 @@ pub enum TpPulse {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TpPulseLength {
     /// Time pulse ratio
     Ratio = 0,
     /// Time pulse length
     Length = 1,
 }
+
+/// Data rate of the L-band channel, for `PmpDataRate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PmpRate {
+    B600 = 600,
+    B1200 = 1200,
+    B2400 = 2400,
+    B4800 = 4800,
+}
+
+cfg_enum! {
+    /// Receiver mode, for `TmodeMode`
+    pub enum TmodeReceiverMode {
+        Disabled = 0,
+        SurveyIn = 1,
+        /// True Antenna Reference Point (ARP) position information required
+        Fixed = 2,
+    }
+}
+
+cfg_enum! {
+    /// How the ARP position is given, for `TmodePosType`
+    pub enum TmodePositionType {
+        /// ECEF coordinates, `TmodeEcef*`
+        Ecef = 0,
+        /// Latitude, longitude and height, `TmodeLat`, `TmodeLon` and `TmodeHeight`
+        Llh = 1,
+    }
+}
+
+cfg_enum! {
+    /// Antenna setting, for `ItfmAntSetting`
+    pub enum ItfmAntennaSetting {
+        Unknown = 0,
+        Passive = 1,
+        Active = 2,
+    }
+}
+
+cfg_enum! {
+    /// Power saving mode, for `PmOperateMode`
+    pub enum PowerMode {
+        /// Normal operation, no power save mode active
+        Full = 0,
+        /// PSM On/Off operation
+        Psmoo = 1,
+        /// PSM cyclic tracking operation
+        Psmct = 2,
+    }
+}
+
+cfg_enum! {
+    /// Confidence level for geofence state evaluation, for `GeofenceConfLvl`
+    pub enum GeofenceConfidence {
+        /// No confidence required
+        L000 = 0,
+        /// 68%
+        L680 = 1,
+        /// 95%
+        L950 = 2,
+        /// 99.7%
+        L997 = 3,
+        /// 99.99%
+        L9999 = 4,
+        /// 99.9999%
+        L999999 = 5,
+    }
+}
+
+cfg_enum! {
+    /// PIO pin polarity, for `GeofencePinPol`
+    pub enum GeofencePinPolarity {
+        /// Low means inside
+        LowIn = 0,
+        /// Low means outside
+        LowOut = 1,
+    }
+}
+
+cfg_enum! {
+    /// NMEA protocol version, for `NmeaProtVer`
+    pub enum NmeaVersion {
+        V21 = 21,
+        V23 = 23,
+        V40 = 40,
+        V41 = 41,
+        V411 = 42,
+    }
+}
+
+cfg_enum! {
+    /// Numbering of SVs which have no number defined in NMEA, for `NmeaSvNumbering`
+    pub enum NmeaSvNumberingScheme {
+        /// Strict - SVs are not output
+        Strict = 0,
+        /// Extended - Use proprietary numbering
+        Extended = 1,
+    }
+}
+
+cfg_enum! {
+    /// Main NMEA Talker ID, for `NmeaMainTalkerId`
+    pub enum NmeaTalkerId {
+        /// Main Talker ID is not overridden
+        Auto = 0,
+        Gp = 1,
+        Gl = 2,
+        Gn = 3,
+        Ga = 4,
+        Gb = 5,
+        Gq = 7,
+    }
+}
+
+cfg_enum! {
+    /// Talker ID of GSV messages, for `NmeaGsvTalkerId`
+    pub enum NmeaGsvTalker {
+        /// Use GNSS-specific Talker ID (as defined by NMEA)
+        Gnss = 0,
+        /// Use the main Talker ID
+        Main = 1,
+    }
+}
+
+cfg_enum! {
+    /// Filtering of the RTCM 3.X input by reference station ID, for `RtcmDf003InFilter`
+    pub enum RtcmDf003Filter {
+        /// Accept all reference stations
+        None = 0,
+        /// Accept the reference station `RtcmDf003In`, and messages without DF003
+        Relaxed = 1,
+        /// Only accept the reference station `RtcmDf003In`
+        Strict = 2,
+    }
+}
+
+cfg_enum! {
+    /// Source of the SPARTN corrections, for `SpartnUseSource`
+    pub enum SpartnSource {
+        /// UBX-RXM-SPARTN or SPARTN messages over IP
+        Ip = 0,
+        /// UBX-RXM-PMP messages from an L-band receiver
+        LBand = 1,
+    }
+}
@@ -1,4 +1,6 @@
-use crate::cfg_val::CfgVal;
+use crate::cfg_val::{CfgVal, KeyId};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::convert::TryInto;
 use core::fmt;
 
@@ -12,11 +14,13 @@ use ublox_derive::{
     ubx_packet_send,
 };
 
-use crate::error::{MemWriterError, ParserError};
+use crate::error::{BuilderError, CfgGnssError, MemWriterError, ParserError};
 #[cfg(feature = "serde")]
 use crate::serde::ser::SerializeMap;
 use crate::ubx_packets::packets::mon_ver::is_cstr_valid;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use super::UbxUnknownPacketOwned;
 use super::{
     ubx_checksum, MemWriter, Position, UbxChecksumCalc, UbxPacketCreator, UbxPacketMeta,
     UbxUnknownPacketRef, SYNC_CHAR_1, SYNC_CHAR_2,
@@ -94,6 +98,104 @@ struct NavVelNed {
     course_heading_accuracy_estimate: u32,
 }
 
+/// Position Solution in ECEF
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x01, fixed_payload_len = 20)]
+struct NavPosECEF {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+
+    /// ECEF X coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    ecef_x: i32,
+
+    /// ECEF Y coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    ecef_y: i32,
+
+    /// ECEF Z coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    ecef_z: i32,
+
+    /// Position Accuracy Estimate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    position_accuracy: u32,
+}
+
+/// High Precision Position Solution in ECEF
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x13, fixed_payload_len = 28)]
+struct NavHpPosECEF {
+    /// Message version (0 for protocol version 27)
+    version: u8,
+
+    reserved1: [u8; 3],
+
+    /// GPS Millisecond Time of Week
+    itow: u32,
+
+    /// ECEF X coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    ecef_x: i32,
+
+    /// ECEF Y coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    ecef_y: i32,
+
+    /// ECEF Z coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    ecef_z: i32,
+
+    /// High precision component of ECEF X coordinate (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    ecef_x_hp: i8,
+
+    /// High precision component of ECEF Y coordinate (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    ecef_y_hp: i8,
+
+    /// High precision component of ECEF Z coordinate (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    ecef_z_hp: i8,
+
+    #[ubx(map_type = NavHpPosECEFFlags)]
+    flags: u8,
+
+    /// Position Accuracy Estimate (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    position_accuracy: u32,
+}
+
+impl<'a> NavHpPosECEFRef<'a> {
+    /// ECEF X coordinate, including the high precision part (m)
+    pub fn ecef_x_meters(&self) -> f64 {
+        self.ecef_x() + self.ecef_x_hp()
+    }
+
+    /// ECEF Y coordinate, including the high precision part (m)
+    pub fn ecef_y_meters(&self) -> f64 {
+        self.ecef_y() + self.ecef_y_hp()
+    }
+
+    /// ECEF Z coordinate, including the high precision part (m)
+    pub fn ecef_z_meters(&self) -> f64 {
+        self.ecef_z() + self.ecef_z_hp()
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Flags for `NavHpPosECEF`
+    pub struct NavHpPosECEFFlags: u8 {
+        /// ECEF coordinates are not valid
+        const INVALID_ECEF = 1;
+    }
+}
+
 /// High Precision Geodetic Position Solution
 #[ubx_packet_recv]
 #[ubx(class = 0x01, id = 0x14, fixed_payload_len = 36)]
@@ -155,6 +257,291 @@ struct NavHpPosLlh {
     vertical_accuracy: u32,
 }
 
+/// Relative Positioning Information in NED frame, version 0 (40 bytes)
+///
+/// Sent by protocol versions before 20, see `NavRelPosNedV1` for newer receivers.
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x3c, fixed_payload_len = 40)]
+struct NavRelPosNedV0 {
+    /// Message version (0x00 for this layout)
+    version: u8,
+
+    reserved1: u8,
+
+    /// Reference station ID, must be in the range 0..4095
+    ref_station_id: u16,
+
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// North component of relative position vector (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    rel_pos_n: i32,
+
+    /// East component of relative position vector (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    rel_pos_e: i32,
+
+    /// Down component of relative position vector (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    rel_pos_d: i32,
+
+    /// High precision component of North (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    rel_pos_hp_n: i8,
+
+    /// High precision component of East (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    rel_pos_hp_e: i8,
+
+    /// High precision component of Down (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    rel_pos_hp_d: i8,
+
+    reserved2: u8,
+
+    /// Accuracy of relative position North component (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    acc_n: u32,
+
+    /// Accuracy of relative position East component (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    acc_e: u32,
+
+    /// Accuracy of relative position Down component (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    acc_d: u32,
+
+    #[ubx(map_type = NavRelPosNedFlags)]
+    flags: u32,
+}
+
+impl<'a> NavRelPosNedV0Ref<'a> {
+    /// North component of relative position vector,
+    /// including the high precision part (m)
+    pub fn rel_pos_n_meters(&self) -> f64 {
+        self.rel_pos_n() + self.rel_pos_hp_n()
+    }
+
+    /// East component of relative position vector,
+    /// including the high precision part (m)
+    pub fn rel_pos_e_meters(&self) -> f64 {
+        self.rel_pos_e() + self.rel_pos_hp_e()
+    }
+
+    /// Down component of relative position vector,
+    /// including the high precision part (m)
+    pub fn rel_pos_d_meters(&self) -> f64 {
+        self.rel_pos_d() + self.rel_pos_hp_d()
+    }
+}
+
+/// Relative Positioning Information in NED frame, version 1 (64 bytes)
+///
+/// Adds the length and heading of the relative position vector,
+/// as needed for moving base setups.
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x3c, fixed_payload_len = 64)]
+struct NavRelPosNedV1 {
+    /// Message version (0x01 for this layout)
+    version: u8,
+
+    reserved0: u8,
+
+    /// Reference station ID, must be in the range 0..4095
+    ref_station_id: u16,
+
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// North component of relative position vector (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    rel_pos_n: i32,
+
+    /// East component of relative position vector (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    rel_pos_e: i32,
+
+    /// Down component of relative position vector (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    rel_pos_d: i32,
+
+    /// Length of the relative position vector (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    rel_pos_length: i32,
+
+    /// Heading of the relative position vector (deg)
+    #[ubx(map_type = f64, scale = 1e-5, alias = rel_pos_heading_degrees)]
+    rel_pos_heading: i32,
+
+    reserved1: [u8; 4],
+
+    /// High precision component of North (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    rel_pos_hp_n: i8,
+
+    /// High precision component of East (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    rel_pos_hp_e: i8,
+
+    /// High precision component of Down (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    rel_pos_hp_d: i8,
+
+    /// High precision component of the length (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    rel_pos_hp_length: i8,
+
+    /// Accuracy of relative position North component (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    acc_n: u32,
+
+    /// Accuracy of relative position East component (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    acc_e: u32,
+
+    /// Accuracy of relative position Down component (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    acc_d: u32,
+
+    /// Accuracy of length of the relative position vector (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    acc_length: u32,
+
+    /// Accuracy of heading of the relative position vector (deg)
+    #[ubx(map_type = f64, scale = 1e-5, alias = acc_heading_degrees)]
+    acc_heading: u32,
+
+    reserved2: [u8; 4],
+
+    #[ubx(map_type = NavRelPosNedFlags)]
+    flags: u32,
+}
+
+impl<'a> NavRelPosNedV1Ref<'a> {
+    /// North component of relative position vector,
+    /// including the high precision part (m)
+    pub fn rel_pos_n_meters(&self) -> f64 {
+        self.rel_pos_n() + self.rel_pos_hp_n()
+    }
+
+    /// East component of relative position vector,
+    /// including the high precision part (m)
+    pub fn rel_pos_e_meters(&self) -> f64 {
+        self.rel_pos_e() + self.rel_pos_hp_e()
+    }
+
+    /// Down component of relative position vector,
+    /// including the high precision part (m)
+    pub fn rel_pos_d_meters(&self) -> f64 {
+        self.rel_pos_d() + self.rel_pos_hp_d()
+    }
+
+    /// Length of the relative position vector,
+    /// including the high precision part (m)
+    pub fn rel_pos_length_meters(&self) -> f64 {
+        self.rel_pos_length() + self.rel_pos_hp_length()
+    }
+}
+
+/// Flags of `NavRelPosNedV0` and `NavRelPosNedV1`
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NavRelPosNedFlags(u32);
+
+impl NavRelPosNedFlags {
+    /// A valid fix (i.e within DOP & accuracy masks)
+    pub fn gnss_fix_ok(self) -> bool {
+        self.0 & 0x1 != 0
+    }
+
+    /// Differential corrections were applied
+    pub fn diff_soln(self) -> bool {
+        (self.0 >> 1) & 0x1 != 0
+    }
+
+    /// Relative position components and accuracies are valid
+    pub fn rel_pos_valid(self) -> bool {
+        (self.0 >> 2) & 0x1 != 0
+    }
+
+    pub fn carr_soln(self) -> CarrierPhaseSolution {
+        let bits = (self.0 >> 3) & 0x3;
+        match bits {
+            1 => CarrierPhaseSolution::Float,
+            2 => CarrierPhaseSolution::Fixed,
+            _ => CarrierPhaseSolution::NoSolution,
+        }
+    }
+
+    /// The receiver is operating in moving base mode
+    pub fn is_moving(self) -> bool {
+        (self.0 >> 5) & 0x1 != 0
+    }
+
+    /// Extrapolated reference position was used to compute moving base
+    /// solution this epoch
+    pub fn ref_pos_miss(self) -> bool {
+        (self.0 >> 6) & 0x1 != 0
+    }
+
+    /// Extrapolated reference observations were used to compute moving base
+    /// solution this epoch
+    pub fn ref_obs_miss(self) -> bool {
+        (self.0 >> 7) & 0x1 != 0
+    }
+
+    /// Heading of the relative position vector is valid, version 1 only
+    pub fn rel_pos_heading_valid(self) -> bool {
+        (self.0 >> 8) & 0x1 != 0
+    }
+
+    /// Components of the relative position vector (including the high
+    /// precision parts) are normalized, version 1 only
+    pub fn rel_pos_normalized(self) -> bool {
+        (self.0 >> 9) & 0x1 != 0
+    }
+
+    pub const fn from(x: u32) -> Self {
+        Self(x)
+    }
+}
+
+impl fmt::Debug for NavRelPosNedFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NavRelPosNedFlags")
+            .field("gnss_fix_ok", &self.gnss_fix_ok())
+            .field("diff_soln", &self.diff_soln())
+            .field("rel_pos_valid", &self.rel_pos_valid())
+            .field("carr_soln", &self.carr_soln())
+            .field("is_moving", &self.is_moving())
+            .field("ref_pos_miss", &self.ref_pos_miss())
+            .field("ref_obs_miss", &self.ref_obs_miss())
+            .field("rel_pos_heading_valid", &self.rel_pos_heading_valid())
+            .field("rel_pos_normalized", &self.rel_pos_normalized())
+            .finish()
+    }
+}
+
+/// Carrier phase range solution status
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CarrierPhaseSolution {
+    NoSolution,
+    /// Carrier phase range solution with floating ambiguities
+    Float,
+    /// Carrier phase range solution with fixed ambiguities
+    Fixed,
+}
+
 /// Navigation Position Velocity Time Solution
 #[ubx_packet_recv]
 #[ubx(class = 1, id = 0x07, fixed_payload_len = 92)]
@@ -491,18 +878,7 @@ pub struct NavSatSvFlags(u32);
 
 impl NavSatSvFlags {
     pub fn quality_ind(self) -> NavSatQualityIndicator {
-        let bits = self.0 & 0x7;
-        match bits {
-            0 => NavSatQualityIndicator::NoSignal,
-            1 => NavSatQualityIndicator::Searching,
-            2 => NavSatQualityIndicator::SignalAcquired,
-            3 => NavSatQualityIndicator::SignalDetected,
-            4 => NavSatQualityIndicator::CodeLock,
-            5 | 6 | 7 => NavSatQualityIndicator::CarrierLock,
-            _ => {
-                panic!("Unexpected 3-bit bitfield value {}!", bits);
-            }
-        }
+        NavSatQualityIndicator::from((self.0 & 0x7) as u8)
     }
 
     pub fn sv_used(self) -> bool {
@@ -510,12 +886,7 @@ impl NavSatSvFlags {
     }
 
     pub fn health(self) -> NavSatSvHealth {
-        let bits = (self.0 >> 4) & 0x3;
-        match bits {
-            1 => NavSatSvHealth::Healthy,
-            2 => NavSatSvHealth::Unhealthy,
-            x => NavSatSvHealth::Unknown(x as u8),
-        }
+        NavSatSvHealth::from(((self.0 >> 4) & 0x3) as u8)
     }
 
     pub fn differential_correction_available(self) -> bool {
@@ -625,6 +996,19 @@ pub enum NavSatQualityIndicator {
     CarrierLock,
 }
 
+impl From<u8> for NavSatQualityIndicator {
+    fn from(x: u8) -> Self {
+        match x {
+            1 => NavSatQualityIndicator::Searching,
+            2 => NavSatQualityIndicator::SignalAcquired,
+            3 => NavSatQualityIndicator::SignalDetected,
+            4 => NavSatQualityIndicator::CodeLock,
+            5..=7 => NavSatQualityIndicator::CarrierLock,
+            _ => NavSatQualityIndicator::NoSignal,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NavSatSvHealth {
@@ -633,6 +1017,16 @@ pub enum NavSatSvHealth {
     Unknown(u8),
 }
 
+impl From<u8> for NavSatSvHealth {
+    fn from(x: u8) -> Self {
+        match x {
+            1 => NavSatSvHealth::Healthy,
+            2 => NavSatSvHealth::Unhealthy,
+            x => NavSatSvHealth::Unknown(x),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NavSatOrbitSource {
@@ -711,46 +1105,545 @@ struct NavSat {
     svs: [u8; 0],
 }
 
-/// Odometer solution
-#[ubx_packet_recv]
-#[ubx(class = 0x01, id = 0x09, fixed_payload_len = 20)]
-struct NavOdo {
-    version: u8,
-    reserved: [u8; 3],
-    i_tow: u32,
-    distance: u32,
-    total_distance: u32,
-    distance_std: u32,
+/// SBAS mode of `NavSbas`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NavSbasMode {
+    Disabled,
+    EnabledIntegrity,
+    EnabledTestMode,
+    Unknown(u8),
 }
 
-/// Reset odometer
-#[ubx_packet_send]
-#[ubx(class = 0x01, id = 0x10, fixed_payload_len = 0)]
-struct NavResetOdo {}
+impl From<u8> for NavSbasMode {
+    fn from(x: u8) -> Self {
+        match x {
+            0 => NavSbasMode::Disabled,
+            1 => NavSbasMode::EnabledIntegrity,
+            3 => NavSbasMode::EnabledTestMode,
+            x => NavSbasMode::Unknown(x),
+        }
+    }
+}
 
-/// Configure odometer
-#[ubx_packet_recv_send]
-#[ubx(
-    class = 0x06,
-    id = 0x1E,
-    fixed_payload_len = 20,
-    flags = "default_for_builder"
-)]
-struct CfgOdo {
-    version: u8,
-    reserved: [u8; 3],
-    /// Odometer COG filter flags. See [OdoCogFilterFlags] for details.
-    #[ubx(map_type = OdoCogFilterFlags)]
-    flags: u8,
-    #[ubx(map_type = OdoProfile, may_fail)]
-    odo_cfg: u8,
-    reserved2: [u8; 6],
-    cog_max_speed: u8,
-    cog_max_pos_acc: u8,
-    reserved3: [u8; 2],
-    vel_lp_gain: u8,
-    cog_lp_gain: u8,
-    reserved4: [u8; 2],
+/// SBAS system of `NavSbas`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SbasSystem {
+    Unknown,
+    Waas,
+    Egnos,
+    Msas,
+    Gagan,
+    Gps,
+    Other(i8),
+}
+
+impl From<i8> for SbasSystem {
+    fn from(x: i8) -> Self {
+        match x {
+            -1 => SbasSystem::Unknown,
+            0 => SbasSystem::Waas,
+            1 => SbasSystem::Egnos,
+            2 => SbasSystem::Msas,
+            3 => SbasSystem::Gagan,
+            16 => SbasSystem::Gps,
+            x => SbasSystem::Other(x),
+        }
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Services provided by an SBAS satellite or system
+    pub struct SbasService: u8 {
+        const RANGING = 0x01;
+        const CORRECTIONS = 0x02;
+        const INTEGRITY = 0x04;
+        const TEST_MODE = 0x08;
+        const BAD = 0x10;
+    }
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x32, fixed_payload_len = 12)]
+struct NavSbasSvInfo {
+    sv_id: u8,
+    reserved1: u8,
+    /// Monitoring status
+    udre: u8,
+    #[ubx(map_type = SbasSystem)]
+    sv_sys: i8,
+    #[ubx(map_type = SbasService)]
+    sv_service: u8,
+    reserved2: u8,
+    /// Pseudorange correction (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    prc: i16,
+    reserved3: [u8; 2],
+    /// Ionospheric correction (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    ic: i16,
+}
+
+#[derive(Debug, Clone)]
+pub struct NavSbasIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> NavSbasIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len() % 12 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for NavSbasIter<'a> {
+    type Item = NavSbasSvInfoRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + 12];
+            self.offset += 12;
+            Some(NavSbasSvInfoRef(data))
+        } else {
+            None
+        }
+    }
+}
+
+/// SBAS status, with the corrections of each satellite
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x32, max_payload_len = 3072)] // 12 + 12 * 255
+struct NavSbas {
+    /// GPS time of week in ms
+    itow: u32,
+    /// PRN of the GEO satellite used, 0 if none
+    geo: u8,
+    #[ubx(map_type = NavSbasMode)]
+    mode: u8,
+    #[ubx(map_type = SbasSystem)]
+    sys: i8,
+    /// Services provided by the GEO satellite used
+    #[ubx(map_type = SbasService)]
+    service: u8,
+    /// Number of satellites with corrections
+    cnt: u8,
+    /// Whether the SBAS integrity information is used, `None` if unknown
+    #[ubx(map_type = Option<bool>, from = NavSbas::integrity_used, alias = integrity_used)]
+    status_flags: u8,
+    reserved1: [u8; 2],
+    #[ubx(
+        map_type = NavSbasIter,
+        from = NavSbasIter::new,
+        is_valid = NavSbasIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    svs: [u8; 0],
+}
+
+impl NavSbas {
+    fn integrity_used(flags: u8) -> Option<bool> {
+        match flags & 0x3 {
+            1 => Some(false),
+            2 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+/// GNSS identifier, as used by `NavSig` and other multi-GNSS messages
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GnssId {
+    Gps,
+    Sbas,
+    Galileo,
+    BeiDou,
+    Imes,
+    Qzss,
+    Glonass,
+    NavIc,
+    Unknown(u8),
+}
+
+impl From<u8> for GnssId {
+    fn from(x: u8) -> Self {
+        match x {
+            0 => GnssId::Gps,
+            1 => GnssId::Sbas,
+            2 => GnssId::Galileo,
+            3 => GnssId::BeiDou,
+            4 => GnssId::Imes,
+            5 => GnssId::Qzss,
+            6 => GnssId::Glonass,
+            7 => GnssId::NavIc,
+            x => GnssId::Unknown(x),
+        }
+    }
+}
+
+impl GnssId {
+    pub const fn into_raw(self) -> u8 {
+        match self {
+            GnssId::Gps => 0,
+            GnssId::Sbas => 1,
+            GnssId::Galileo => 2,
+            GnssId::BeiDou => 3,
+            GnssId::Imes => 4,
+            GnssId::Qzss => 5,
+            GnssId::Glonass => 6,
+            GnssId::NavIc => 7,
+            GnssId::Unknown(x) => x,
+        }
+    }
+
+    /// Flag of the major constellation in `MonGnss`, if any
+    fn major_constellation(self) -> Option<MonGnssConstellMask> {
+        match self {
+            GnssId::Gps => Some(MonGnssConstellMask::GPS),
+            GnssId::Glonass => Some(MonGnssConstellMask::GLO),
+            GnssId::BeiDou => Some(MonGnssConstellMask::BDC),
+            GnssId::Galileo => Some(MonGnssConstellMask::GAL),
+            _ => None,
+        }
+    }
+}
+
+/// Signal identifier, the meaning of `sig_id` depends on `gnss_id`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignalId {
+    GpsL1CA,
+    GpsL2CL,
+    GpsL2CM,
+    GpsL5I,
+    GpsL5Q,
+    SbasL1CA,
+    GalileoE1C,
+    GalileoE1B,
+    GalileoE5aI,
+    GalileoE5aQ,
+    GalileoE5bI,
+    GalileoE5bQ,
+    BeiDouB1ID1,
+    BeiDouB1ID2,
+    BeiDouB2ID1,
+    BeiDouB2ID2,
+    BeiDouB1C,
+    BeiDouB2a,
+    QzssL1CA,
+    QzssL1S,
+    QzssL2CM,
+    QzssL2CL,
+    QzssL5I,
+    QzssL5Q,
+    GlonassL1OF,
+    GlonassL2OF,
+    NavIcL5A,
+    Unknown { gnss_id: u8, sig_id: u8 },
+}
+
+impl SignalId {
+    pub fn new(gnss_id: u8, sig_id: u8) -> Self {
+        match (gnss_id, sig_id) {
+            (0, 0) => SignalId::GpsL1CA,
+            (0, 3) => SignalId::GpsL2CL,
+            (0, 4) => SignalId::GpsL2CM,
+            (0, 6) => SignalId::GpsL5I,
+            (0, 7) => SignalId::GpsL5Q,
+            (1, 0) => SignalId::SbasL1CA,
+            (2, 0) => SignalId::GalileoE1C,
+            (2, 1) => SignalId::GalileoE1B,
+            (2, 3) => SignalId::GalileoE5aI,
+            (2, 4) => SignalId::GalileoE5aQ,
+            (2, 5) => SignalId::GalileoE5bI,
+            (2, 6) => SignalId::GalileoE5bQ,
+            (3, 0) => SignalId::BeiDouB1ID1,
+            (3, 1) => SignalId::BeiDouB1ID2,
+            (3, 2) => SignalId::BeiDouB2ID1,
+            (3, 3) => SignalId::BeiDouB2ID2,
+            (3, 5) => SignalId::BeiDouB1C,
+            (3, 7) => SignalId::BeiDouB2a,
+            (5, 0) => SignalId::QzssL1CA,
+            (5, 1) => SignalId::QzssL1S,
+            (5, 4) => SignalId::QzssL2CM,
+            (5, 5) => SignalId::QzssL2CL,
+            (5, 8) => SignalId::QzssL5I,
+            (5, 9) => SignalId::QzssL5Q,
+            (6, 0) => SignalId::GlonassL1OF,
+            (6, 2) => SignalId::GlonassL2OF,
+            (7, 0) => SignalId::NavIcL5A,
+            (gnss_id, sig_id) => SignalId::Unknown { gnss_id, sig_id },
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NavSigCorrectionSource {
+    NoCorrections,
+    Sbas,
+    BeiDou,
+    Rtcm2,
+    Rtcm3Osr,
+    Rtcm3Ssr,
+    QzssSlas,
+    Spartn,
+    Clas,
+    Unknown(u8),
+}
+
+impl From<u8> for NavSigCorrectionSource {
+    fn from(x: u8) -> Self {
+        match x {
+            0 => NavSigCorrectionSource::NoCorrections,
+            1 => NavSigCorrectionSource::Sbas,
+            2 => NavSigCorrectionSource::BeiDou,
+            3 => NavSigCorrectionSource::Rtcm2,
+            4 => NavSigCorrectionSource::Rtcm3Osr,
+            5 => NavSigCorrectionSource::Rtcm3Ssr,
+            6 => NavSigCorrectionSource::QzssSlas,
+            7 => NavSigCorrectionSource::Spartn,
+            8 => NavSigCorrectionSource::Clas,
+            x => NavSigCorrectionSource::Unknown(x),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NavSigIonoModel {
+    NoModel,
+    KlobucharGps,
+    Sbas,
+    KlobucharBeiDou,
+    /// Iono delay derived from dual frequency observations
+    DualFrequency,
+    Unknown(u8),
+}
+
+impl From<u8> for NavSigIonoModel {
+    fn from(x: u8) -> Self {
+        match x {
+            0 => NavSigIonoModel::NoModel,
+            1 => NavSigIonoModel::KlobucharGps,
+            2 => NavSigIonoModel::Sbas,
+            3 => NavSigIonoModel::KlobucharBeiDou,
+            8 => NavSigIonoModel::DualFrequency,
+            x => NavSigIonoModel::Unknown(x),
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NavSigFlags(u16);
+
+impl NavSigFlags {
+    pub fn health(self) -> NavSatSvHealth {
+        NavSatSvHealth::from((self.0 & 0x3) as u8)
+    }
+
+    /// Pseudorange has been smoothed
+    pub fn pr_smoothed(self) -> bool {
+        (self.0 >> 2) & 0x1 != 0
+    }
+
+    /// Pseudorange has been used for this signal
+    pub fn pr_used(self) -> bool {
+        (self.0 >> 3) & 0x1 != 0
+    }
+
+    /// Carrier range has been used for this signal
+    pub fn cr_used(self) -> bool {
+        (self.0 >> 4) & 0x1 != 0
+    }
+
+    /// Range rate (Doppler) has been used for this signal
+    pub fn do_used(self) -> bool {
+        (self.0 >> 5) & 0x1 != 0
+    }
+
+    /// Pseudorange corrections have been used for this signal
+    pub fn pr_corr_used(self) -> bool {
+        (self.0 >> 6) & 0x1 != 0
+    }
+
+    /// Carrier range corrections have been used for this signal
+    pub fn cr_corr_used(self) -> bool {
+        (self.0 >> 7) & 0x1 != 0
+    }
+
+    /// Range rate (Doppler) corrections have been used for this signal
+    pub fn do_corr_used(self) -> bool {
+        (self.0 >> 8) & 0x1 != 0
+    }
+
+    pub const fn from(x: u16) -> Self {
+        Self(x)
+    }
+}
+
+impl fmt::Debug for NavSigFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NavSigFlags")
+            .field("health", &self.health())
+            .field("pr_smoothed", &self.pr_smoothed())
+            .field("pr_used", &self.pr_used())
+            .field("cr_used", &self.cr_used())
+            .field("do_used", &self.do_used())
+            .field("pr_corr_used", &self.pr_corr_used())
+            .field("cr_corr_used", &self.cr_corr_used())
+            .field("do_corr_used", &self.do_corr_used())
+            .finish()
+    }
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x43, fixed_payload_len = 16)]
+struct NavSigInfo {
+    #[ubx(map_type = GnssId)]
+    gnss_id: u8,
+
+    /// Satellite identifier
+    sv_id: u8,
+
+    /// Signal identifier, see `signal_id()`
+    sig_id: u8,
+
+    /// GLONASS frequency slot + 7 (range from 0 to 13)
+    freq_id: u8,
+
+    /// Pseudorange residual (m)
+    #[ubx(map_type = f64, scale = 1e-1)]
+    pr_res: i16,
+
+    /// Carrier-to-noise density ratio (dBHz)
+    cno: u8,
+
+    #[ubx(map_type = NavSatQualityIndicator)]
+    quality_ind: u8,
+
+    #[ubx(map_type = NavSigCorrectionSource)]
+    corr_source: u8,
+
+    #[ubx(map_type = NavSigIonoModel)]
+    iono_model: u8,
+
+    #[ubx(map_type = NavSigFlags)]
+    sig_flags: u16,
+
+    reserved1: [u8; 4],
+}
+
+impl<'a> NavSigInfoRef<'a> {
+    pub fn signal_id(&self) -> SignalId {
+        SignalId::new(self.gnss_id_raw(), self.sig_id())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NavSigIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> NavSigIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len() % 16 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for NavSigIter<'a> {
+    type Item = NavSigInfoRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + 16];
+            self.offset += 16;
+            Some(NavSigInfoRef(data))
+        } else {
+            None
+        }
+    }
+}
+
+/// Signal Information
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x43, max_payload_len = 1928)] // 8 + 16 * 120
+struct NavSig {
+    /// GPS time of week in ms
+    itow: u32,
+
+    /// Message version, should be 0
+    version: u8,
+
+    num_sigs: u8,
+
+    reserved0: [u8; 2],
+
+    #[ubx(
+        map_type = NavSigIter,
+        from = NavSigIter::new,
+        is_valid = NavSigIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    sigs: [u8; 0],
+}
+
+/// Odometer solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x09, fixed_payload_len = 20)]
+struct NavOdo {
+    version: u8,
+    reserved: [u8; 3],
+    i_tow: u32,
+    distance: u32,
+    total_distance: u32,
+    distance_std: u32,
+}
+
+/// Reset odometer
+#[ubx_packet_send]
+#[ubx(class = 0x01, id = 0x10, fixed_payload_len = 0)]
+struct NavResetOdo {}
+
+/// Configure odometer
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x1E,
+    fixed_payload_len = 20,
+    flags = "default_for_builder"
+)]
+struct CfgOdo {
+    version: u8,
+    reserved: [u8; 3],
+    /// Odometer COG filter flags. See [OdoCogFilterFlags] for details.
+    #[ubx(map_type = OdoCogFilterFlags)]
+    flags: u8,
+    #[ubx(map_type = OdoProfile, may_fail)]
+    odo_cfg: u8,
+    reserved2: [u8; 6],
+    cog_max_speed: u8,
+    cog_max_pos_acc: u8,
+    reserved3: [u8; 2],
+    vel_lp_gain: u8,
+    cog_lp_gain: u8,
+    reserved4: [u8; 2],
 }
 
 #[ubx_extend_bitflags]
@@ -1462,35 +2355,120 @@ struct TimSvin {
     reserved: [u8; 2],
 }
 
-/// Leap second event information
+/// Survey-in data, used by the F9 generation instead of `TimSvin`
 #[ubx_packet_recv]
-#[ubx(class = 0x01, id = 0x26, fixed_payload_len = 24)]
-struct NavTimeLs {
-    /// GPS time of week of the navigation epoch in ms.
-    i_tow: u32,
-    ///Message version (0x00 for this version)
+#[ubx(class = 0x01, id = 0x3b, fixed_payload_len = 40)]
+struct NavSvin {
+    /// Message version (0x00 for this version)
     version: u8,
-    reserved_1: [u8; 3],
-    /// Information source for the current number of leap seconds.
-    /// 0: Default (hardcoded in the firmware, can be outdated)
-    /// 1: Derived from time difference between GPS and GLONASS time
-    /// 2: GPS
-    /// 3: SBAS
-    /// 4: BeiDou
-    /// 5: Galileo
-    /// 6: Aided data 7: Configured 8: NavIC
-    /// 255: Unknown
-    src_of_curr_ls: u8,
-    /// Current number of leap seconds since start of GPS time (Jan 6, 1980). It reflects how much
-    /// GPS time is ahead of UTC time. Galileo number of leap seconds is the same as GPS. BeiDou
-    /// number of leap seconds is 14 less than GPS. GLONASS follows UTC time, so no leap seconds.
-    current_ls: i8,
-    /// Information source for the future leap second event.
-    /// 0: No source
-    /// 2: GPS
-    /// 3: SBAS
-    /// 4: BeiDou
-    /// 5: Galileo
+
+    reserved0: [u8; 3],
+
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// Passed survey-in observation time (s)
+    dur: u32,
+
+    /// Current survey-in mean position ECEF X coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    mean_x: i32,
+
+    /// Current survey-in mean position ECEF Y coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    mean_y: i32,
+
+    /// Current survey-in mean position ECEF Z coordinate (m)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    mean_z: i32,
+
+    /// High precision component of the mean ECEF X coordinate (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    mean_x_hp: i8,
+
+    /// High precision component of the mean ECEF Y coordinate (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    mean_y_hp: i8,
+
+    /// High precision component of the mean ECEF Z coordinate (m)
+    /// Must be in the range -99..+99 (0.1 mm)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    mean_z_hp: i8,
+
+    reserved1: u8,
+
+    /// Current survey-in mean position accuracy (m)
+    #[ubx(map_type = f64, scale = 1e-4)]
+    mean_acc: u32,
+
+    /// Number of position observations used during survey-in
+    obs: u32,
+
+    /// Survey-in position is valid
+    #[ubx(map_type = bool, from = NavSvin::flag)]
+    valid: u8,
+
+    /// Survey-in is in progress
+    #[ubx(map_type = bool, from = NavSvin::flag)]
+    active: u8,
+
+    reserved2: [u8; 2],
+}
+
+impl NavSvin {
+    fn flag(x: u8) -> bool {
+        x != 0
+    }
+}
+
+impl<'a> NavSvinRef<'a> {
+    /// Mean ECEF X coordinate, including the high precision part (m)
+    pub fn mean_x_meters(&self) -> f64 {
+        self.mean_x() + self.mean_x_hp()
+    }
+
+    /// Mean ECEF Y coordinate, including the high precision part (m)
+    pub fn mean_y_meters(&self) -> f64 {
+        self.mean_y() + self.mean_y_hp()
+    }
+
+    /// Mean ECEF Z coordinate, including the high precision part (m)
+    pub fn mean_z_meters(&self) -> f64 {
+        self.mean_z() + self.mean_z_hp()
+    }
+}
+
+/// Leap second event information
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x26, fixed_payload_len = 24)]
+struct NavTimeLs {
+    /// GPS time of week of the navigation epoch in ms.
+    i_tow: u32,
+    ///Message version (0x00 for this version)
+    version: u8,
+    reserved_1: [u8; 3],
+    /// Information source for the current number of leap seconds.
+    /// 0: Default (hardcoded in the firmware, can be outdated)
+    /// 1: Derived from time difference between GPS and GLONASS time
+    /// 2: GPS
+    /// 3: SBAS
+    /// 4: BeiDou
+    /// 5: Galileo
+    /// 6: Aided data 7: Configured 8: NavIC
+    /// 255: Unknown
+    src_of_curr_ls: u8,
+    /// Current number of leap seconds since start of GPS time (Jan 6, 1980). It reflects how much
+    /// GPS time is ahead of UTC time. Galileo number of leap seconds is the same as GPS. BeiDou
+    /// number of leap seconds is 14 less than GPS. GLONASS follows UTC time, so no leap seconds.
+    current_ls: i8,
+    /// Information source for the future leap second event.
+    /// 0: No source
+    /// 2: GPS
+    /// 3: SBAS
+    /// 4: BeiDou
+    /// 5: Galileo
     /// 6: GLONASS 7: NavIC
     src_of_ls_change: u8,
     /// Future leap second change if one is scheduled. +1 = positive leap second, -1 = negative
@@ -1715,55 +2693,57 @@ impl ResetMode {
     }
 }
 
+/// Clear, save and load configurations, to persist the current configuration
+/// in non-volatile memory or to revert to the default one
 #[ubx_packet_send]
 #[ubx(
-  class = 0x06,
-  id = 0x8a,
-  max_payload_len = 772, // 4 + (4 + 8) * 64
+    class = 0x06,
+    id = 0x09,
+    fixed_payload_len = 13,
+    flags = "default_for_builder"
 )]
-struct CfgValSet<'a> {
-    /// Message version
-    version: u8,
-    /// The layers from which the configuration items should be retrieved
-    #[ubx(map_type = CfgLayer)]
-    layers: u8,
-    reserved1: u16,
-    cfg_data: &'a [CfgVal],
-}
-
-#[derive(Debug, Clone)]
-pub struct CfgValIter<'a> {
-    pub(crate) data: &'a [u8],
-    pub(crate) offset: usize,
-}
-
-impl<'a> CfgValIter<'a> {
-    pub fn new(data: &'a mut [u8], values: &[CfgVal]) -> Self {
-        let mut offset = 0;
-
-        for value in values {
-            offset += value.write_to(&mut data[offset..]);
+struct CfgCfg {
+    /// Configuration sections to reset to their default in non-volatile memory
+    #[ubx(map_type = CfgCfgMask)]
+    clear_mask: u32,
+    /// Configuration sections to save from the current configuration to non-volatile memory
+    #[ubx(map_type = CfgCfgMask)]
+    save_mask: u32,
+    /// Configuration sections to load from non-volatile memory to the current configuration
+    #[ubx(map_type = CfgCfgMask)]
+    load_mask: u32,
+    /// Devices for the save, load and clear operations
+    #[ubx(map_type = CfgCfgDevices)]
+    device_mask: u8,
+}
+
+impl CfgCfgBuilder {
+    /// Save the current configuration of `mask` to `devices`
+    pub fn save(mask: CfgCfgMask, devices: CfgCfgDevices) -> Self {
+        Self {
+            save_mask: mask,
+            device_mask: devices,
+            ..Self::default()
         }
+    }
 
+    /// Load the configuration of `mask` from `devices`
+    pub fn load(mask: CfgCfgMask, devices: CfgCfgDevices) -> Self {
         Self {
-            data: &data[..offset],
-            offset: 0,
+            load_mask: mask,
+            device_mask: devices,
+            ..Self::default()
         }
     }
-}
-
-impl<'a> core::iter::Iterator for CfgValIter<'a> {
-    type Item = CfgVal;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.offset < self.data.len() {
-            let cfg_val = CfgVal::parse(&self.data[self.offset..]);
 
-            self.offset += cfg_val.len();
-
-            Some(cfg_val)
-        } else {
-            None
+    /// Revert the configuration of `mask` to the default one, both in
+    /// `devices` and in the current configuration
+    pub fn reset_to_default(mask: CfgCfgMask, devices: CfgCfgDevices) -> Self {
+        Self {
+            clear_mask: mask,
+            load_mask: mask,
+            device_mask: devices,
+            ..Self::default()
         }
     }
 }
@@ -1771,1254 +2751,3115 @@ impl<'a> core::iter::Iterator for CfgValIter<'a> {
 #[ubx_extend_bitflags]
 #[ubx(from, into_raw, rest_reserved)]
 bitflags! {
-    /// A mask describing where configuration is applied.
-    pub struct CfgLayer: u8 {
-        const RAM = 0b001;
-        const BBR = 0b010;
-        const FLASH = 0b100;
+    /// Configuration sections of `CfgCfg`
+    #[derive(Default)]
+    pub struct CfgCfgMask: u32 {
+        /// Port settings, `CfgPrtUart`, `CfgPrtSpi`, `CfgPrtI2c`, ...
+        const IO_PORT = 0x1;
+        /// Message rates, `CfgMsgAllPorts` and `CfgMsgSinglePort`
+        const MSG_CONF = 0x2;
+        /// INF message settings
+        const INF_MSG = 0x4;
+        /// Navigation settings, `CfgNav5`, `CfgRate`, ...
+        const NAV_CONF = 0x8;
+        /// Receiver manager settings, power management, ...
+        const RXM_CONF = 0x10;
+        /// Sensor interface settings
+        const SEN_CONF = 0x100;
+        /// Remote inventory settings
+        const RINV_CONF = 0x200;
+        /// Antenna settings, `CfgAnt`
+        const ANT_CONF = 0x400;
+        /// Logging settings
+        const LOG_CONF = 0x800;
+        /// FTS settings, only for FTS products
+        const FTS_CONF = 0x1000;
     }
 }
 
-impl Default for CfgLayer {
-    fn default() -> Self {
-        Self::RAM | Self::BBR | Self::FLASH
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// Non-volatile memories of `CfgCfg`
+    #[derive(Default)]
+    pub struct CfgCfgDevices: u8 {
+        /// Battery backed RAM
+        const BBR = 0x1;
+        const FLASH = 0x2;
+        const EEPROM = 0x4;
+        const SPI_FLASH = 0x10;
     }
 }
 
-/// Port Configuration for I2C
-#[ubx_packet_recv_send]
-#[ubx(
-    class = 0x06,
-    id = 0x00,
-    fixed_payload_len = 20,
-    flags = "default_for_builder"
-)]
-struct CfgPrtI2c {
-    #[ubx(map_type = I2cPortId, may_fail)]
-    portid: u8,
-    reserved1: u8,
-    /// TX ready PIN configuration
-    tx_ready: u16,
-    /// I2C Mode Flags
-    mode: u32,
-    reserved2: u32,
-    #[ubx(map_type = InProtoMask)]
-    in_proto_mask: u16,
-    #[ubx(map_type = OutProtoMask)]
-    out_proto_mask: u16,
-    flags: u16,
-    reserved3: u16,
+/// GNSS system configuration: enabled constellations, their signals and
+/// the tracking channels reserved for them.
+///
+/// Received as the response to a poll, see `CfgGnssBuilder` to change the configuration.
+#[ubx_packet_recv]
+#[ubx(class = 0x06, id = 0x3e, max_payload_len = 2044)] // 4 + 8 * 255
+struct CfgGnss {
+    /// Message version, should be 0
+    msg_ver: u8,
+    /// Number of tracking channels available in hardware
+    num_trk_ch_hw: u8,
+    /// Number of tracking channels to use, 0xff to use all of them
+    num_trk_ch_use: u8,
+    num_config_blocks: u8,
+    #[ubx(
+        map_type = CfgGnssIter,
+        from = CfgGnssIter::new,
+        is_valid = CfgGnssIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    blocks: [u8; 0],
 }
 
-/// Port Identifier Number (= 0 for I2C ports)
-#[ubx_extend]
-#[ubx(from_unchecked, into_raw, rest_error)]
-#[repr(u8)]
-#[derive(Debug, Copy, Clone)]
-pub enum I2cPortId {
-    I2c = 0,
+/// Configuration of a single GNSS in `CfgGnss`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CfgGnssBlock {
+    /// Number of reserved (minimum) tracking channels
+    pub res_trk_ch: u8,
+    /// Maximum number of tracking channels
+    pub max_trk_ch: u8,
+    pub enable: bool,
+    /// GNSS of the block and its signals to track
+    pub signals: CfgGnssSignals,
+}
+
+impl CfgGnssBlock {
+    const SIZE: usize = 8;
+
+    pub fn gnss_id(&self) -> GnssId {
+        self.signals.gnss_id()
+    }
+
+    fn extend_to<T>(&self, buf: &mut T)
+    where
+        T: core::iter::Extend<u8>,
+    {
+        let (gnss_id, mask) = self.signals.into_raw();
+        let flags = u32::from(self.enable) | (u32::from(mask) << 16);
+        let mut block = [0; Self::SIZE];
+        block[0] = gnss_id;
+        block[1] = self.res_trk_ch;
+        block[2] = self.max_trk_ch;
+        block[4..8].copy_from_slice(&flags.to_le_bytes());
+        for b in block.iter() {
+            buf.extend(core::iter::once(*b));
+        }
+    }
 }
 
-impl Default for I2cPortId {
-    fn default() -> Self {
-        Self::I2c
+/// GNSS of a `CfgGnssBlock`, with the mask of its signals
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CfgGnssSignals {
+    Gps(CfgGnssGpsSignals),
+    Sbas(CfgGnssSbasSignals),
+    Galileo(CfgGnssGalileoSignals),
+    BeiDou(CfgGnssBeiDouSignals),
+    Imes(CfgGnssImesSignals),
+    Qzss(CfgGnssQzssSignals),
+    Glonass(CfgGnssGlonassSignals),
+    NavIc(CfgGnssNavIcSignals),
+    Unknown { gnss_id: u8, mask: u8 },
+}
+
+impl CfgGnssSignals {
+    fn from_raw(gnss_id: u8, mask: u8) -> Self {
+        match GnssId::from(gnss_id) {
+            GnssId::Gps => Self::Gps(CfgGnssGpsSignals::from(mask)),
+            GnssId::Sbas => Self::Sbas(CfgGnssSbasSignals::from(mask)),
+            GnssId::Galileo => Self::Galileo(CfgGnssGalileoSignals::from(mask)),
+            GnssId::BeiDou => Self::BeiDou(CfgGnssBeiDouSignals::from(mask)),
+            GnssId::Imes => Self::Imes(CfgGnssImesSignals::from(mask)),
+            GnssId::Qzss => Self::Qzss(CfgGnssQzssSignals::from(mask)),
+            GnssId::Glonass => Self::Glonass(CfgGnssGlonassSignals::from(mask)),
+            GnssId::NavIc => Self::NavIc(CfgGnssNavIcSignals::from(mask)),
+            GnssId::Unknown(gnss_id) => Self::Unknown { gnss_id, mask },
+        }
+    }
+
+    /// `gnssId` and `sigCfgMask`
+    fn into_raw(self) -> (u8, u8) {
+        let mask = match self {
+            Self::Gps(mask) => mask.into_raw(),
+            Self::Sbas(mask) => mask.into_raw(),
+            Self::Galileo(mask) => mask.into_raw(),
+            Self::BeiDou(mask) => mask.into_raw(),
+            Self::Imes(mask) => mask.into_raw(),
+            Self::Qzss(mask) => mask.into_raw(),
+            Self::Glonass(mask) => mask.into_raw(),
+            Self::NavIc(mask) => mask.into_raw(),
+            Self::Unknown { mask, .. } => mask,
+        };
+        (self.gnss_id().into_raw(), mask)
+    }
+
+    pub fn gnss_id(&self) -> GnssId {
+        match self {
+            Self::Gps(_) => GnssId::Gps,
+            Self::Sbas(_) => GnssId::Sbas,
+            Self::Galileo(_) => GnssId::Galileo,
+            Self::BeiDou(_) => GnssId::BeiDou,
+            Self::Imes(_) => GnssId::Imes,
+            Self::Qzss(_) => GnssId::Qzss,
+            Self::Glonass(_) => GnssId::Glonass,
+            Self::NavIc(_) => GnssId::NavIc,
+            Self::Unknown { gnss_id, .. } => GnssId::Unknown(*gnss_id),
+        }
     }
 }
 
-/// Port Configuration for UART
-#[ubx_packet_recv_send]
-#[ubx(class = 0x06, id = 0x00, fixed_payload_len = 20)]
-struct CfgPrtUart {
-    #[ubx(map_type = UartPortId, may_fail)]
-    portid: u8,
-    reserved0: u8,
-    tx_ready: u16,
-    #[ubx(map_type = UartMode)]
-    mode: u32,
-    baud_rate: u32,
-    #[ubx(map_type = InProtoMask)]
-    in_proto_mask: u16,
-    #[ubx(map_type = OutProtoMask)]
-    out_proto_mask: u16,
-    flags: u16,
-    reserved5: u16,
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// GPS signals of `CfgGnssSignals`
+    pub struct CfgGnssGpsSignals: u8 {
+        const L1CA = 0x01;
+        const L2C = 0x10;
+        const L5 = 0x20;
+    }
 }
 
-/// Port Identifier Number (= 1 or 2 for UART ports)
-#[ubx_extend]
-#[ubx(from_unchecked, into_raw, rest_error)]
-#[repr(u8)]
-#[derive(Debug, Copy, Clone)]
-pub enum UartPortId {
-    Uart1 = 1,
-    Uart2 = 2,
-    Usb = 3,
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// SBAS signals of `CfgGnssSignals`
+    pub struct CfgGnssSbasSignals: u8 {
+        const L1CA = 0x01;
+    }
 }
 
-#[derive(Debug, Copy, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UartMode {
-    data_bits: DataBits,
-    parity: Parity,
-    stop_bits: StopBits,
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// Galileo signals of `CfgGnssSignals`
+    pub struct CfgGnssGalileoSignals: u8 {
+        const E1 = 0x01;
+        const E5A = 0x10;
+        const E5B = 0x20;
+    }
 }
 
-impl UartMode {
-    pub const fn new(data_bits: DataBits, parity: Parity, stop_bits: StopBits) -> Self {
-        Self {
-            data_bits,
-            parity,
-            stop_bits,
-        }
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// BeiDou signals of `CfgGnssSignals`
+    pub struct CfgGnssBeiDouSignals: u8 {
+        const B1I = 0x01;
+        const B2I = 0x10;
+        const B2A = 0x80;
     }
+}
 
-    const fn into_raw(self) -> u32 {
-        self.data_bits.into_raw() | self.parity.into_raw() | self.stop_bits.into_raw()
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// IMES signals of `CfgGnssSignals`
+    pub struct CfgGnssImesSignals: u8 {
+        const L1 = 0x01;
     }
 }
 
-impl From<u32> for UartMode {
-    fn from(mode: u32) -> Self {
-        let data_bits = DataBits::from(mode);
-        let parity = Parity::from(mode);
-        let stop_bits = StopBits::from(mode);
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// QZSS signals of `CfgGnssSignals`
+    pub struct CfgGnssQzssSignals: u8 {
+        const L1CA = 0x01;
+        const L1S = 0x04;
+        const L2C = 0x10;
+        const L5 = 0x20;
+    }
+}
 
-        Self {
-            data_bits,
-            parity,
-            stop_bits,
-        }
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// GLONASS signals of `CfgGnssSignals`
+    pub struct CfgGnssGlonassSignals: u8 {
+        const L1 = 0x01;
+        const L2 = 0x10;
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum DataBits {
-    Seven,
-    Eight,
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// NavIC signals of `CfgGnssSignals`
+    pub struct CfgGnssNavIcSignals: u8 {
+        const L5 = 0x01;
+    }
 }
 
-impl DataBits {
-    const POSITION: u32 = 6;
-    const MASK: u32 = 0b11;
+#[derive(Debug, Clone)]
+pub struct CfgGnssIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
 
-    const fn into_raw(self) -> u32 {
-        (match self {
-            Self::Seven => 0b10,
-            Self::Eight => 0b11,
-        }) << Self::POSITION
+impl<'a> CfgGnssIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len() % CfgGnssBlock::SIZE == 0
     }
 }
 
-impl From<u32> for DataBits {
-    fn from(mode: u32) -> Self {
-        match (mode >> Self::POSITION) & Self::MASK {
-            0b00 => unimplemented!("five data bits"),
-            0b01 => unimplemented!("six data bits"),
-            0b10 => Self::Seven,
-            0b11 => Self::Eight,
-            _ => unreachable!(),
+impl<'a> core::iter::Iterator for CfgGnssIter<'a> {
+    type Item = CfgGnssBlock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + CfgGnssBlock::SIZE];
+            self.offset += CfgGnssBlock::SIZE;
+            Some(CfgGnssBlock {
+                res_trk_ch: data[1],
+                max_trk_ch: data[2],
+                enable: data[4] & 0x1 != 0,
+                signals: CfgGnssSignals::from_raw(data[0], data[6]),
+            })
+        } else {
+            None
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum Parity {
-    Even,
-    Odd,
-    None,
+/// Sets the GNSS system configuration.
+///
+/// Not generated by `ubx_packet_send`, because of the variable number of blocks.
+/// Usually built from the blocks of a polled `CfgGnss`, and checked with
+/// `validate` before being sent.
+pub struct CfgGnssBuilder<'a> {
+    /// Message version, should be 0
+    pub msg_ver: u8,
+    /// Number of tracking channels to use, 0xff to use all of them
+    pub num_trk_ch_use: u8,
+    /// Up to 255 blocks
+    pub blocks: &'a [CfgGnssBlock],
 }
 
-impl Parity {
-    const POSITION: u32 = 9;
-    const MASK: u32 = 0b111;
+impl<'a> CfgGnssBuilder<'a> {
+    /// Checks the configuration against the receiver capabilities.
+    ///
+    /// The channels to use must be available in hardware, as reported by the
+    /// `current` configuration polled from the receiver, and the reserved channels
+    /// must fit in them. The enabled major GNSS must be supported by the receiver,
+    /// and not more than it can track simultaneously, as reported by `mon_gnss`.
+    pub fn validate(
+        &self,
+        current: &CfgGnssRef,
+        mon_gnss: &MonGnssRef,
+    ) -> Result<(), CfgGnssError> {
+        let hardware = current.num_trk_ch_hw();
+        let channels = if self.num_trk_ch_use == 0xff {
+            hardware
+        } else {
+            self.num_trk_ch_use
+        };
+        if channels > hardware {
+            return Err(CfgGnssError::TooManyChannels { channels, hardware });
+        }
+
+        let mut reserved = 0u16;
+        let mut major = MonGnssConstellMask::empty();
+        for block in self.blocks.iter().filter(|block| block.enable) {
+            if block.max_trk_ch < block.res_trk_ch {
+                return Err(CfgGnssError::MaxBelowReserved {
+                    gnss_id: block.gnss_id(),
+                });
+            }
+            reserved += u16::from(block.res_trk_ch);
+            if let Some(mask) = block.gnss_id().major_constellation() {
+                if !mon_gnss.supported().contains(mask) {
+                    return Err(CfgGnssError::UnsupportedGnss {
+                        gnss_id: block.gnss_id(),
+                    });
+                }
+                major |= mask;
+            }
+        }
+        if reserved > u16::from(channels) {
+            return Err(CfgGnssError::ReservedChannels { reserved, channels });
+        }
+        let enabled = major.bits().count_ones() as u8;
+        if enabled > mon_gnss.simultaneous() {
+            return Err(CfgGnssError::TooManyGnss {
+                enabled,
+                simultaneous: mon_gnss.simultaneous(),
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn into_packet_vec(self) -> Result<Vec<u8>, BuilderError> {
+        let mut vec = Vec::new();
+        self.extend_to(&mut vec)?;
+        Ok(vec)
+    }
+
+    /// Writes the packet to `out`, fails without writing anything if there
+    /// are too many blocks
+    #[inline]
+    pub fn extend_to<T>(self, out: &mut T) -> Result<(), BuilderError>
+    where
+        T: core::iter::Extend<u8> + core::ops::DerefMut<Target = [u8]>,
+    {
+        check_len("blocks", self.blocks.len(), u8::MAX.into())?;
+
+        let start = out.len();
+        let len_bytes = ((4 + self.blocks.len() * CfgGnssBlock::SIZE) as u16).to_le_bytes();
+        // The number of tracking channels in hardware is read only
+        let header = [
+            SYNC_CHAR_1,
+            SYNC_CHAR_2,
+            CfgGnss::CLASS,
+            CfgGnss::ID,
+            len_bytes[0],
+            len_bytes[1],
+            self.msg_ver,
+            0,
+            self.num_trk_ch_use,
+            self.blocks.len() as u8,
+        ];
+        for b in header.iter() {
+            out.extend(core::iter::once(*b));
+        }
+        for block in self.blocks {
+            block.extend_to(out);
+        }
+
+        let (ck_a, ck_b) = ubx_checksum(&out[start + 2..]);
+        out.extend(core::iter::once(ck_a));
+        out.extend(core::iter::once(ck_b));
+        Ok(())
+    }
+}
+
+/// Power management preset
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x86,
+    fixed_payload_len = 8,
+    flags = "default_for_builder"
+)]
+struct CfgPms {
+    /// Message version, should be 0
+    version: u8,
+    #[ubx(map_type = CfgPmsPowerSetup, may_fail)]
+    power_setup_value: u8,
+    /// Position update period (s), only with `CfgPmsPowerSetup::Interval`,
+    /// must be more than 5 s or 0
+    period: u16,
+    /// Time spent in tracking state (s), only with `CfgPmsPowerSetup::Interval`,
+    /// must be less than `period` or 0
+    on_time: u16,
+    reserved1: [u8; 2],
+}
+
+/// Power setup value of `CfgPms`
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgPmsPowerSetup {
+    /// No power saving
+    FullPower = 0,
+    Balanced = 1,
+    /// Power saving with position updates every `period`
+    Interval = 2,
+    /// Aggressive power saving with 1 Hz position updates
+    Aggressive1Hz = 3,
+    Aggressive2Hz = 4,
+    Aggressive4Hz = 5,
+    /// Invalid, only in responses to a poll
+    Invalid = 0xff,
+}
+
+impl Default for CfgPmsPowerSetup {
+    fn default() -> Self {
+        Self::FullPower
+    }
+}
+
+/// Extended power management configuration, used in power save mode.
+///
+/// Version 2 of the message, supported since protocol version 18
+#[ubx_packet_recv_send]
+#[ubx(class = 0x06, id = 0x3b, fixed_payload_len = 48)]
+struct CfgPm2 {
+    /// Message version, should be 2
+    version: u8,
+    reserved1: u8,
+    /// Maximum time to spend in acquisition state (s), 0 for no limit
+    max_startup_state_dur: u8,
+    reserved2: u8,
+    #[ubx(map_type = CfgPm2Flags)]
+    flags: u32,
+    /// Position update period (ms), 0 to never retry a failed fix.
+    /// Must be more than 5 s in ON/OFF mode, 1 s in cyclic tracking mode
+    update_period: u32,
+    /// Acquisition retry period (ms) if the previous one failed, 0 to never retry
+    search_period: u32,
+    /// Offset (ms) of the updates from the start of the GPS week
+    grid_offset: u32,
+    /// Time (s) to stay in tracking state
+    on_time: u16,
+    /// Minimum time (s) to spend in acquisition state
+    min_acq_time: u16,
+    reserved3: [u8; 20],
+    /// Inactivity time out (ms) on EXTINT pin if enabled
+    extint_inactivity_ms: u32,
+}
+
+impl Default for CfgPm2Builder {
+    fn default() -> Self {
+        Self {
+            version: 2,
+            reserved1: 0,
+            max_startup_state_dur: 0,
+            reserved2: 0,
+            flags: CfgPm2Flags::default(),
+            update_period: 0,
+            search_period: 0,
+            grid_offset: 0,
+            on_time: 0,
+            min_acq_time: 0,
+            reserved3: [0; 20],
+            extint_inactivity_ms: 0,
+        }
+    }
+}
+
+/// Flags of `CfgPm2`
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CfgPm2Flags {
+    /// Use EXTINT1 instead of EXTINT0 for the `extint_*` flags
+    pub extint1: bool,
+    /// Keep the receiver awake as long as the EXTINT pin is high
+    pub extint_wake: bool,
+    /// Force the receiver into backup mode when the EXTINT pin is low
+    pub extint_backup: bool,
+    /// Force the receiver into backup mode when the EXTINT pin is inactive
+    /// for more than `extint_inactivity_ms`
+    pub extint_inactive: bool,
+    /// Limit the peak current
+    pub limit_peak_current: bool,
+    /// Wait for the time to be known before entering the inactive states
+    pub wait_time_fix: bool,
+    /// Update the real time clock in the tracking states
+    pub update_rtc: bool,
+    /// Update the ephemeris in the tracking states
+    pub update_eph: bool,
+    /// Stay in tracking state instead of entering the inactive states
+    /// when no fix could be obtained
+    pub do_not_enter_off: bool,
+    pub mode: CfgPm2Mode,
+}
+
+impl CfgPm2Flags {
+    const EXTINT_SEL: u32 = 0x10;
+    const EXTINT_WAKE: u32 = 0x20;
+    const EXTINT_BACKUP: u32 = 0x40;
+    const EXTINT_INACTIVE: u32 = 0x80;
+    const LIMIT_PEAK_CURR: u32 = 0x100;
+    const WAIT_TIME_FIX: u32 = 0x400;
+    const UPDATE_RTC: u32 = 0x800;
+    const UPDATE_EPH: u32 = 0x1000;
+    const DO_NOT_ENTER_OFF: u32 = 0x10000;
 
     const fn into_raw(self) -> u32 {
-        (match self {
-            Self::Even => 0b000,
-            Self::Odd => 0b001,
-            Self::None => 0b100,
-        }) << Self::POSITION
+        let mut raw = self.mode.into_raw();
+        if self.extint1 {
+            raw |= Self::EXTINT_SEL;
+        }
+        if self.extint_wake {
+            raw |= Self::EXTINT_WAKE;
+        }
+        if self.extint_backup {
+            raw |= Self::EXTINT_BACKUP;
+        }
+        if self.extint_inactive {
+            raw |= Self::EXTINT_INACTIVE;
+        }
+        if self.limit_peak_current {
+            raw |= Self::LIMIT_PEAK_CURR;
+        }
+        if self.wait_time_fix {
+            raw |= Self::WAIT_TIME_FIX;
+        }
+        if self.update_rtc {
+            raw |= Self::UPDATE_RTC;
+        }
+        if self.update_eph {
+            raw |= Self::UPDATE_EPH;
+        }
+        if self.do_not_enter_off {
+            raw |= Self::DO_NOT_ENTER_OFF;
+        }
+        raw
     }
 }
 
-impl From<u32> for Parity {
-    fn from(mode: u32) -> Self {
-        match (mode >> Self::POSITION) & Self::MASK {
-            0b000 => Self::Even,
-            0b001 => Self::Odd,
-            0b100 | 0b101 => Self::None,
-            0b010 | 0b011 | 0b110 | 0b111 => unimplemented!("reserved"),
-            _ => unreachable!(),
+impl From<u32> for CfgPm2Flags {
+    fn from(flags: u32) -> Self {
+        Self {
+            extint1: flags & Self::EXTINT_SEL != 0,
+            extint_wake: flags & Self::EXTINT_WAKE != 0,
+            extint_backup: flags & Self::EXTINT_BACKUP != 0,
+            extint_inactive: flags & Self::EXTINT_INACTIVE != 0,
+            limit_peak_current: flags & Self::LIMIT_PEAK_CURR != 0,
+            wait_time_fix: flags & Self::WAIT_TIME_FIX != 0,
+            update_rtc: flags & Self::UPDATE_RTC != 0,
+            update_eph: flags & Self::UPDATE_EPH != 0,
+            do_not_enter_off: flags & Self::DO_NOT_ENTER_OFF != 0,
+            mode: CfgPm2Mode::from(flags),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Power save mode of `CfgPm2`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum StopBits {
-    One,
-    OneHalf,
-    Two,
-    Half,
+pub enum CfgPm2Mode {
+    /// Periodic fixes, switching the receiver off in between
+    OnOff,
+    /// Continuous tracking with a reduced power consumption
+    CyclicTracking,
+    Unknown(u8),
 }
 
-impl StopBits {
-    const POSITION: u32 = 12;
+impl CfgPm2Mode {
+    const POSITION: u32 = 17;
     const MASK: u32 = 0b11;
 
     const fn into_raw(self) -> u32 {
         (match self {
-            Self::One => 0b00,
-            Self::OneHalf => 0b01,
-            Self::Two => 0b10,
-            Self::Half => 0b11,
+            Self::OnOff => 0,
+            Self::CyclicTracking => 1,
+            Self::Unknown(x) => x as u32 & Self::MASK,
         }) << Self::POSITION
     }
 }
 
-impl From<u32> for StopBits {
-    fn from(mode: u32) -> Self {
-        match (mode >> Self::POSITION) & Self::MASK {
-            0b00 => Self::One,
-            0b01 => Self::OneHalf,
-            0b10 => Self::Two,
-            0b11 => Self::Half,
-            _ => unreachable!(),
+impl From<u32> for CfgPm2Mode {
+    fn from(flags: u32) -> Self {
+        match (flags >> Self::POSITION) & Self::MASK {
+            0 => Self::OnOff,
+            1 => Self::CyclicTracking,
+            x => Self::Unknown(x as u8),
         }
     }
 }
 
-/// Port Configuration for SPI Port
+impl Default for CfgPm2Mode {
+    fn default() -> Self {
+        Self::OnOff
+    }
+}
+
+/// SBAS configuration
 #[ubx_packet_recv_send]
 #[ubx(
     class = 0x06,
-    id = 0x00,
-    fixed_payload_len = 20,
+    id = 0x16,
+    fixed_payload_len = 8,
     flags = "default_for_builder"
 )]
-struct CfgPrtSpi {
-    #[ubx(map_type = SpiPortId, may_fail)]
-    portid: u8,
-    reserved0: u8,
-    /// TX ready PIN configuration
-    tx_ready: u16,
-    /// SPI Mode Flags
-    mode: u32,
-    reserved3: u32,
-    #[ubx(map_type = InProtoMask)]
-    in_proto_mask: u16,
-    #[ubx(map_type = OutProtoMask)]
-    out_proto_mask: u16,
-    flags: u16,
-    reserved5: u16,
+struct CfgSbas {
+    #[ubx(map_type = CfgSbasMode)]
+    mode: u8,
+    /// SBAS services to use
+    #[ubx(map_type = CfgSbasUsage)]
+    usage: u8,
+    /// Maximum number of SBAS prioritized tracking channels to use, obsolete
+    max_sbas: u8,
+    /// PRNs 152 to 158 to search for, see `scanmode1`
+    #[ubx(map_type = CfgSbasScanMode2)]
+    scanmode2: u8,
+    /// PRNs 120 to 151 to search for. If no PRN is set in both masks,
+    /// all of them are searched for
+    #[ubx(map_type = CfgSbasScanMode1)]
+    scanmode1: u32,
 }
 
 #[ubx_extend_bitflags]
 #[ubx(from, into_raw, rest_reserved)]
 bitflags! {
-    /// A mask describing which input protocols are active
-    /// Each bit of this mask is used for a protocol.
-    /// Through that, multiple protocols can be defined on a single port
-    /// Used in `CfgPrtSpi` and `CfgPrtI2c`
+    /// SBAS mode of `CfgSbas`
     #[derive(Default)]
-    pub struct InProtoMask: u16 {
-        const UBLOX = 1;
-        const NMEA = 2;
-        const RTCM = 4;
-        /// The bitfield inRtcm3 is not supported in protocol
-        /// versions less than 20
-        const RTCM3 = 0x20;
+    pub struct CfgSbasMode: u8 {
+        const ENABLED = 0x01;
+        /// Use the SBAS data even when the satellites are in test mode
+        const TEST = 0x02;
     }
 }
 
 #[ubx_extend_bitflags]
 #[ubx(from, into_raw, rest_reserved)]
 bitflags! {
-    /// A mask describing which output protocols are active.
-    /// Each bit of this mask is used for a protocol.
-    /// Through that, multiple protocols can be defined on a single port
-    /// Used in `CfgPrtSpi` and `CfgPrtI2c`
+    /// SBAS services used with `CfgSbas`
     #[derive(Default)]
-    pub struct OutProtoMask: u16 {
-        const UBLOX = 1;
-        const NMEA = 2;
-        /// The bitfield outRtcm3 is not supported in protocol
-        /// versions less than 20
-        const RTCM3 = 0x20;
+    pub struct CfgSbasUsage: u8 {
+        /// Use the SBAS satellites for navigation
+        const RANGE = 0x01;
+        /// Use the SBAS differential corrections
+        const DIFF_CORR = 0x02;
+        /// Use the SBAS integrity information
+        const INTEGRITY = 0x04;
     }
 }
 
-/// Port Identifier Number (= 4 for SPI port)
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// SBAS PRNs 120 to 151 searched for with `CfgSbas`
+    #[derive(Default)]
+    pub struct CfgSbasScanMode1: u32 {
+        const PRN120 = 0x1;
+        const PRN121 = 0x2;
+        const PRN122 = 0x4;
+        const PRN123 = 0x8;
+        const PRN124 = 0x10;
+        const PRN125 = 0x20;
+        const PRN126 = 0x40;
+        const PRN127 = 0x80;
+        const PRN128 = 0x100;
+        const PRN129 = 0x200;
+        const PRN130 = 0x400;
+        const PRN131 = 0x800;
+        const PRN132 = 0x1000;
+        const PRN133 = 0x2000;
+        const PRN134 = 0x4000;
+        const PRN135 = 0x8000;
+        const PRN136 = 0x10000;
+        const PRN137 = 0x20000;
+        const PRN138 = 0x40000;
+        const PRN139 = 0x80000;
+        const PRN140 = 0x100000;
+        const PRN141 = 0x200000;
+        const PRN142 = 0x400000;
+        const PRN143 = 0x800000;
+        const PRN144 = 0x1000000;
+        const PRN145 = 0x2000000;
+        const PRN146 = 0x4000000;
+        const PRN147 = 0x8000000;
+        const PRN148 = 0x10000000;
+        const PRN149 = 0x20000000;
+        const PRN150 = 0x40000000;
+        const PRN151 = 0x80000000;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// SBAS PRNs 152 to 158 searched for with `CfgSbas`
+    #[derive(Default)]
+    pub struct CfgSbasScanMode2: u8 {
+        const PRN152 = 0x1;
+        const PRN153 = 0x2;
+        const PRN154 = 0x4;
+        const PRN155 = 0x8;
+        const PRN156 = 0x10;
+        const PRN157 = 0x20;
+        const PRN158 = 0x40;
+    }
+}
+
+/// Differential GNSS (RTK) configuration
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x70,
+    fixed_payload_len = 4,
+    flags = "default_for_builder"
+)]
+struct CfgDgnss {
+    #[ubx(map_type = CfgDgnssMode, may_fail)]
+    dgnss_mode: u8,
+    reserved1: [u8; 3],
+}
+
+/// Carrier phase ambiguity resolution of `CfgDgnss`, and of the
+/// `NavHpgDgnssMode` configuration item
 #[ubx_extend]
 #[ubx(from_unchecked, into_raw, rest_error)]
 #[repr(u8)]
-#[derive(Debug, Copy, Clone)]
-pub enum SpiPortId {
-    Spi = 4,
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgDgnssMode {
+    /// No attempt to fix the ambiguities
+    RtkFloat = 2,
+    /// Fix the ambiguities whenever possible
+    RtkFixed = 3,
 }
 
-impl Default for SpiPortId {
+impl Default for CfgDgnssMode {
     fn default() -> Self {
-        Self::Spi
+        Self::RtkFixed
     }
 }
 
-/// UTC Time Solution
-#[ubx_packet_recv]
-#[ubx(class = 1, id = 0x21, fixed_payload_len = 20)]
-struct NavTimeUTC {
-    /// GPS Millisecond Time of Week
-    itow: u32,
-    time_accuracy_estimate_ns: u32,
+#[ubx_packet_send]
+#[ubx(
+  class = 0x06,
+  id = 0x8a,
+  max_payload_len = 772, // 4 + (4 + 8) * 64
+)]
+struct CfgValSet<'a> {
+    /// Message version
+    version: u8,
+    /// The layers from which the configuration items should be retrieved
+    #[ubx(map_type = CfgLayer)]
+    layers: u8,
+    reserved1: u16,
+    cfg_data: &'a [CfgVal],
+}
 
-    /// Nanoseconds of second, range -1e9 .. 1e9
-    nanos: i32,
+/// Poll configuration items from one layer, the receiver answers with `CfgValGet`
+#[ubx_packet_send]
+#[ubx(
+  class = 0x06,
+  id = 0x8b,
+  max_payload_len = 260, // 4 + 4 * 64
+)]
+struct CfgValGetPoll<'a> {
+    /// Message version, should be 0
+    version: u8,
+    /// The layer from which the configuration items should be retrieved
+    #[ubx(map_type = CfgValGetLayer)]
+    layer: u8,
+    /// Number of matching configuration items to skip, to page through
+    /// results of wildcard keys which don't fit in one response
+    position: u16,
+    keys: &'a [KeyId],
+}
 
-    /// Year, range 1999..2099
-    year: u16,
+/// Configuration items read from the receiver, response to `CfgValGetPollBuilder`
+#[ubx_packet_recv]
+#[ubx(class = 0x06, id = 0x8b, max_payload_len = 772)]
+struct CfgValGet {
+    /// Message version, should be 1
+    version: u8,
+    /// The layer from which the configuration items were retrieved
+    #[ubx(map_type = CfgValGetLayer, may_fail)]
+    layer: u8,
+    /// Number of configuration items skipped before the first one in this response
+    position: u16,
+    #[ubx(
+        map_type = CfgValIter,
+        from = CfgValIter::from_payload,
+        is_valid = CfgValIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    cfg_data: [u8; 0],
+}
 
-    /// Month, range 1..12
-    month: u8,
-
-    /// Day of Month, range 1..31
-    day: u8,
-
-    /// Hour of Day, range 0..23
-    hour: u8,
-
-    /// Minute of Hour, range 0..59
-    min: u8,
-
-    /// Seconds of Minute, range 0..59
-    sec: u8,
+/// Delete configuration items from the BBR and/or Flash layers,
+/// so the values from the lower layers are used again
+#[ubx_packet_send]
+#[ubx(
+  class = 0x06,
+  id = 0x8c,
+  max_payload_len = 260, // 4 + 4 * 64
+)]
+struct CfgValDel<'a> {
+    /// Message version, should be 0
+    version: u8,
+    /// The layers from which the configuration items should be deleted
+    #[ubx(map_type = CfgValDelLayer)]
+    layers: u8,
+    reserved1: u16,
+    keys: &'a [KeyId],
+}
 
-    /// Validity Flags
-    #[ubx(map_type = NavTimeUtcFlags)]
-    valid: u8,
+/// Layers of CFG-VALDEL. Items can't be deleted from the RAM layer, it's
+/// rebuilt from the lower layers on startup.
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CfgValDelLayer {
+    Bbr = 0b010,
+    Flash = 0b100,
+    BbrAndFlash = 0b110,
 }
 
-#[ubx_extend_bitflags]
-#[ubx(from, rest_reserved)]
-bitflags! {
-    /// Validity Flags of `NavTimeUTC`
-    pub struct NavTimeUtcFlags: u8 {
-        /// Valid Time of Week
-        const VALID_TOW = 1;
-        /// Valid Week Number
-        const VALID_WKN = 2;
-        /// Valid UTC (Leap Seconds already known)
-        const VALID_UTC = 4;
-    }
+/// Layer of CFG-VALGET
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CfgValGetLayer {
+    Ram = 0,
+    Bbr = 1,
+    Flash = 2,
+    /// Default values of the receiver
+    Default = 7,
 }
 
-/// Navigation/Measurement Rate Settings
-#[ubx_packet_send]
-#[ubx(class = 6, id = 8, fixed_payload_len = 6)]
-struct CfgRate {
-    /// Measurement Rate, GPS measurements are taken every `measure_rate_ms` milliseconds
-    measure_rate_ms: u16,
+/// Iterator over configuration items. Items with keys not known by this crate,
+/// or with values which can't be decoded, are returned as `CfgVal::Unknown`.
+#[derive(Debug, Clone)]
+pub struct CfgValIter<'a> {
+    pub(crate) data: &'a [u8],
+    pub(crate) offset: usize,
+}
 
-    /// Navigation Rate, in number of measurement cycles.
+impl<'a> CfgValIter<'a> {
+    pub fn new(data: &'a mut [u8], values: &[CfgVal]) -> Self {
+        let mut offset = 0;
 
-    /// On u-blox 5 and u-blox 6, this parametercannot be changed, and is always equals 1.
-    nav_rate: u16,
+        for value in values {
+            offset += value.write_to(&mut data[offset..]);
+        }
 
-    /// Alignment to reference time
-    #[ubx(map_type = AlignmentToReferenceTime)]
-    time_ref: u16,
-}
+        Self {
+            data: &data[..offset],
+            offset: 0,
+        }
+    }
 
-/// Alignment to reference time
-#[repr(u16)]
-#[derive(Clone, Copy, Debug)]
-pub enum AlignmentToReferenceTime {
-    Utc = 0,
-    Gps = 1,
-    Glo = 2,
-    Bds = 3,
-    Gal = 4,
-}
+    fn from_payload(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
 
-impl AlignmentToReferenceTime {
-    const fn into_raw(self) -> u16 {
-        self as u16
+    fn is_valid(bytes: &[u8]) -> bool {
+        let mut offset = 0;
+        while offset < bytes.len() {
+            if bytes.len() - offset < KeyId::SIZE {
+                return false;
+            }
+            let key_id = KeyId::new(u32::from_le_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ]));
+            offset += KeyId::SIZE + key_id.value_size().to_usize();
+        }
+        offset == bytes.len()
     }
 }
 
-/// Set Message Rate the current port
-#[ubx_packet_send]
-#[ubx(class = 6, id = 1, fixed_payload_len = 3)]
-struct CfgMsgSinglePort {
-    msg_class: u8,
-    msg_id: u8,
+impl<'a> core::iter::Iterator for CfgValIter<'a> {
+    type Item = CfgVal;
 
-    /// Send rate on current Target
-    rate: u8,
-}
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            // Only fails on truncated data, which `is_valid` rejects
+            let cfg_val = CfgVal::parse_or_unknown(&self.data[self.offset..]).ok()?;
 
-impl CfgMsgSinglePortBuilder {
-    #[inline]
-    pub fn set_rate_for<T: UbxPacketMeta>(rate: u8) -> Self {
-        Self {
-            msg_class: T::CLASS,
-            msg_id: T::ID,
-            rate,
+            self.offset += cfg_val.len();
+
+            Some(cfg_val)
+        } else {
+            None
         }
     }
 }
 
-/// Set Message rate configuration
-/// Send rate is relative to the event a message is registered on.
-/// For example, if the rate of a navigation message is set to 2,
-/// the message is sent every second navigation solution
-#[ubx_packet_send]
-#[ubx(class = 6, id = 1, fixed_payload_len = 8)]
-struct CfgMsgAllPorts {
-    msg_class: u8,
-    msg_id: u8,
-
-    /// Send rate on I/O Port (6 Ports)
-    rates: [u8; 6],
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// A mask describing where configuration is applied.
+    pub struct CfgLayer: u8 {
+        const RAM = 0b001;
+        const BBR = 0b010;
+        const FLASH = 0b100;
+    }
 }
 
-impl CfgMsgAllPortsBuilder {
-    #[inline]
-    pub fn set_rate_for<T: UbxPacketMeta>(rates: [u8; 6]) -> Self {
-        Self {
-            msg_class: T::CLASS,
-            msg_id: T::ID,
-            rates,
-        }
+impl Default for CfgLayer {
+    fn default() -> Self {
+        Self::RAM | Self::BBR | Self::FLASH
     }
 }
 
-/// Navigation Engine Settings
+/// Port Configuration for I2C
 #[ubx_packet_recv_send]
 #[ubx(
     class = 0x06,
-    id = 0x24,
-    fixed_payload_len = 36,
+    id = 0x00,
+    fixed_payload_len = 20,
     flags = "default_for_builder"
 )]
-struct CfgNav5 {
-    /// Only the masked parameters will be applied
-    #[ubx(map_type = CfgNav5Params)]
-    mask: u16,
-    #[ubx(map_type = CfgNav5DynModel, may_fail)]
-    dyn_model: u8,
-    #[ubx(map_type = CfgNav5FixMode, may_fail)]
-    fix_mode: u8,
-
-    /// Fixed altitude (mean sea level) for 2D fixmode (m)
-    #[ubx(map_type = f64, scale = 0.01)]
-    fixed_alt: i32,
+struct CfgPrtI2c {
+    #[ubx(map_type = I2cPortId, may_fail)]
+    portid: u8,
+    reserved1: u8,
+    /// TX ready PIN configuration
+    tx_ready: u16,
+    /// I2C Mode Flags
+    mode: u32,
+    reserved2: u32,
+    #[ubx(map_type = InProtoMask)]
+    in_proto_mask: u16,
+    #[ubx(map_type = OutProtoMask)]
+    out_proto_mask: u16,
+    flags: u16,
+    reserved3: u16,
+}
 
-    /// Fixed altitude variance for 2D mode (m^2)
-    #[ubx(map_type = f64, scale = 0.0001)]
-    fixed_alt_var: u32,
+/// Port Identifier Number (= 0 for I2C ports)
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone)]
+pub enum I2cPortId {
+    I2c = 0,
+}
 
-    /// Minimum Elevation for a GNSS satellite to be used in NAV (deg)
-    min_elev_degrees: i8,
+impl Default for I2cPortId {
+    fn default() -> Self {
+        Self::I2c
+    }
+}
 
-    /// Reserved
-    dr_limit: u8,
+/// Port Configuration for UART
+#[ubx_packet_recv_send]
+#[ubx(class = 0x06, id = 0x00, fixed_payload_len = 20)]
+struct CfgPrtUart {
+    #[ubx(map_type = UartPortId, may_fail)]
+    portid: u8,
+    reserved0: u8,
+    tx_ready: u16,
+    #[ubx(map_type = UartMode)]
+    mode: u32,
+    baud_rate: u32,
+    #[ubx(map_type = InProtoMask)]
+    in_proto_mask: u16,
+    #[ubx(map_type = OutProtoMask)]
+    out_proto_mask: u16,
+    flags: u16,
+    reserved5: u16,
+}
 
-    /// Position DOP Mask to use
-    #[ubx(map_type = f32, scale = 0.1)]
-    pdop: u16,
+/// Port Identifier Number (= 1 or 2 for UART ports)
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone)]
+pub enum UartPortId {
+    Uart1 = 1,
+    Uart2 = 2,
+    Usb = 3,
+}
 
-    /// Time DOP Mask to use
-    #[ubx(map_type = f32, scale = 0.1)]
-    tdop: u16,
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UartMode {
+    data_bits: DataBits,
+    parity: Parity,
+    stop_bits: StopBits,
+}
 
-    /// Position Accuracy Mask (m)
-    pacc: u16,
+impl UartMode {
+    pub const fn new(data_bits: DataBits, parity: Parity, stop_bits: StopBits) -> Self {
+        Self {
+            data_bits,
+            parity,
+            stop_bits,
+        }
+    }
 
-    /// Time Accuracy Mask
-    /// according to manual unit is "m", but this looks like typo
+    const fn into_raw(self) -> u32 {
+        self.data_bits.into_raw() | self.parity.into_raw() | self.stop_bits.into_raw()
+    }
+}
+
+impl From<u32> for UartMode {
+    fn from(mode: u32) -> Self {
+        let data_bits = DataBits::from(mode);
+        let parity = Parity::from(mode);
+        let stop_bits = StopBits::from(mode);
+
+        Self {
+            data_bits,
+            parity,
+            stop_bits,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataBits {
+    Seven,
+    Eight,
+}
+
+impl DataBits {
+    const POSITION: u32 = 6;
+    const MASK: u32 = 0b11;
+
+    const fn into_raw(self) -> u32 {
+        (match self {
+            Self::Seven => 0b10,
+            Self::Eight => 0b11,
+        }) << Self::POSITION
+    }
+}
+
+impl From<u32> for DataBits {
+    fn from(mode: u32) -> Self {
+        match (mode >> Self::POSITION) & Self::MASK {
+            0b00 => unimplemented!("five data bits"),
+            0b01 => unimplemented!("six data bits"),
+            0b10 => Self::Seven,
+            0b11 => Self::Eight,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Parity {
+    Even,
+    Odd,
+    None,
+}
+
+impl Parity {
+    const POSITION: u32 = 9;
+    const MASK: u32 = 0b111;
+
+    const fn into_raw(self) -> u32 {
+        (match self {
+            Self::Even => 0b000,
+            Self::Odd => 0b001,
+            Self::None => 0b100,
+        }) << Self::POSITION
+    }
+}
+
+impl From<u32> for Parity {
+    fn from(mode: u32) -> Self {
+        match (mode >> Self::POSITION) & Self::MASK {
+            0b000 => Self::Even,
+            0b001 => Self::Odd,
+            0b100 | 0b101 => Self::None,
+            0b010 | 0b011 | 0b110 | 0b111 => unimplemented!("reserved"),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopBits {
+    One,
+    OneHalf,
+    Two,
+    Half,
+}
+
+impl StopBits {
+    const POSITION: u32 = 12;
+    const MASK: u32 = 0b11;
+
+    const fn into_raw(self) -> u32 {
+        (match self {
+            Self::One => 0b00,
+            Self::OneHalf => 0b01,
+            Self::Two => 0b10,
+            Self::Half => 0b11,
+        }) << Self::POSITION
+    }
+}
+
+impl From<u32> for StopBits {
+    fn from(mode: u32) -> Self {
+        match (mode >> Self::POSITION) & Self::MASK {
+            0b00 => Self::One,
+            0b01 => Self::OneHalf,
+            0b10 => Self::Two,
+            0b11 => Self::Half,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Port Configuration for SPI Port
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x00,
+    fixed_payload_len = 20,
+    flags = "default_for_builder"
+)]
+struct CfgPrtSpi {
+    #[ubx(map_type = SpiPortId, may_fail)]
+    portid: u8,
+    reserved0: u8,
+    /// TX ready PIN configuration
+    tx_ready: u16,
+    /// SPI Mode Flags
+    mode: u32,
+    reserved3: u32,
+    #[ubx(map_type = InProtoMask)]
+    in_proto_mask: u16,
+    #[ubx(map_type = OutProtoMask)]
+    out_proto_mask: u16,
+    flags: u16,
+    reserved5: u16,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// A mask describing which input protocols are active
+    /// Each bit of this mask is used for a protocol.
+    /// Through that, multiple protocols can be defined on a single port
+    /// Used in `CfgPrtSpi` and `CfgPrtI2c`
+    #[derive(Default)]
+    pub struct InProtoMask: u16 {
+        const UBLOX = 1;
+        const NMEA = 2;
+        const RTCM = 4;
+        /// The bitfield inRtcm3 is not supported in protocol
+        /// versions less than 20
+        const RTCM3 = 0x20;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// A mask describing which output protocols are active.
+    /// Each bit of this mask is used for a protocol.
+    /// Through that, multiple protocols can be defined on a single port
+    /// Used in `CfgPrtSpi` and `CfgPrtI2c`
+    #[derive(Default)]
+    pub struct OutProtoMask: u16 {
+        const UBLOX = 1;
+        const NMEA = 2;
+        /// The bitfield outRtcm3 is not supported in protocol
+        /// versions less than 20
+        const RTCM3 = 0x20;
+    }
+}
+
+/// Port Identifier Number (= 4 for SPI port)
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone)]
+pub enum SpiPortId {
+    Spi = 4,
+}
+
+impl Default for SpiPortId {
+    fn default() -> Self {
+        Self::Spi
+    }
+}
+
+/// UTC Time Solution
+#[ubx_packet_recv]
+#[ubx(class = 1, id = 0x21, fixed_payload_len = 20)]
+struct NavTimeUTC {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+    time_accuracy_estimate_ns: u32,
+
+    /// Nanoseconds of second, range -1e9 .. 1e9
+    nanos: i32,
+
+    /// Year, range 1999..2099
+    year: u16,
+
+    /// Month, range 1..12
+    month: u8,
+
+    /// Day of Month, range 1..31
+    day: u8,
+
+    /// Hour of Day, range 0..23
+    hour: u8,
+
+    /// Minute of Hour, range 0..59
+    min: u8,
+
+    /// Seconds of Minute, range 0..59
+    sec: u8,
+
+    /// Validity Flags
+    #[ubx(map_type = NavTimeUtcFlags)]
+    valid: u8,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity Flags of `NavTimeUTC`
+    pub struct NavTimeUtcFlags: u8 {
+        /// Valid Time of Week
+        const VALID_TOW = 1;
+        /// Valid Week Number
+        const VALID_WKN = 2;
+        /// Valid UTC (Leap Seconds already known)
+        const VALID_UTC = 4;
+    }
+}
+
+/// GPS Time Solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x20, fixed_payload_len = 16)]
+struct NavTimeGps {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+
+    /// Fractional part of iTOW (ns), range -500000..500000
+    ftow: i32,
+
+    /// GPS week number of the navigation epoch
+    week: i16,
+
+    /// GPS leap seconds (GPS-UTC)
+    leap_s: i8,
+
+    /// Validity Flags
+    #[ubx(map_type = NavTimeGpsFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns)
+    time_accuracy_estimate_ns: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity Flags of `NavTimeGps`
+    pub struct NavTimeGpsFlags: u8 {
+        /// Valid GPS time of week (iTOW & fTOW)
+        const TOW_VALID = 1;
+        /// Valid GPS week number
+        const WEEK_VALID = 2;
+        /// Valid GPS leap seconds
+        const LEAP_S_VALID = 4;
+    }
+}
+
+/// Galileo Time Solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x25, fixed_payload_len = 20)]
+struct NavTimeGal {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+
+    /// Galileo time of week (s)
+    gal_tow: u32,
+
+    /// Fractional part of the Galileo time of week (ns), range -500000000..500000000
+    fgal_tow: i32,
+
+    /// Galileo week number
+    gal_wno: i16,
+
+    /// Galileo leap seconds (Galileo-UTC)
+    leap_s: i8,
+
+    /// Validity Flags
+    #[ubx(map_type = NavTimeGalFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns)
+    time_accuracy_estimate_ns: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity Flags of `NavTimeGal`
+    pub struct NavTimeGalFlags: u8 {
+        /// Valid Galileo time of week (galTow & fGalTow)
+        const GAL_TOW_VALID = 1;
+        /// Valid Galileo week number
+        const GAL_WNO_VALID = 2;
+        /// Valid Galileo leap seconds
+        const LEAP_S_VALID = 4;
+    }
+}
+
+/// GLONASS Time Solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x23, fixed_payload_len = 20)]
+struct NavTimeGlo {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+
+    /// GLONASS time of day (s)
+    tod: u32,
+
+    /// Fractional part of the time of day (ns), range -500000000..500000000
+    ftod: i32,
+
+    /// Current date, range 1..1461, within the four year interval `n4`
+    nt: u16,
+
+    /// Four year interval number starting from 1996 (1 = 1996..1999, 2 = 2000..2003, ...)
+    n4: u8,
+
+    /// Validity Flags
+    #[ubx(map_type = NavTimeGloFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns)
+    time_accuracy_estimate_ns: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity Flags of `NavTimeGlo`
+    pub struct NavTimeGloFlags: u8 {
+        /// Valid GLONASS time of day (tod & ftod)
+        const TOD_VALID = 1;
+        /// Valid GLONASS date (nt & n4)
+        const DATE_VALID = 2;
+    }
+}
+
+/// BeiDou Time Solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x24, fixed_payload_len = 20)]
+struct NavTimeBds {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+
+    /// BeiDou time of week (s)
+    sow: u32,
+
+    /// Fractional part of the BeiDou time of week (ns), range -500000000..500000000
+    fsow: i32,
+
+    /// BeiDou week number
+    week: i16,
+
+    /// BeiDou leap seconds (BeiDou-UTC)
+    leap_s: i8,
+
+    /// Validity Flags
+    #[ubx(map_type = NavTimeBdsFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns)
+    time_accuracy_estimate_ns: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity Flags of `NavTimeBds`
+    pub struct NavTimeBdsFlags: u8 {
+        /// Valid BeiDou time of week (sow & fsow)
+        const SOW_VALID = 1;
+        /// Valid BeiDou week number
+        const WEEK_VALID = 2;
+        /// Valid BeiDou leap seconds
+        const LEAP_S_VALID = 4;
+    }
+}
+
+/// Navigation/Measurement Rate Settings
+#[ubx_packet_send]
+#[ubx(class = 6, id = 8, fixed_payload_len = 6)]
+struct CfgRate {
+    /// Measurement Rate, GPS measurements are taken every `measure_rate_ms` milliseconds
+    measure_rate_ms: u16,
+
+    /// Navigation Rate, in number of measurement cycles.
+
+    /// On u-blox 5 and u-blox 6, this parametercannot be changed, and is always equals 1.
+    nav_rate: u16,
+
+    /// Alignment to reference time
+    #[ubx(map_type = AlignmentToReferenceTime)]
+    time_ref: u16,
+}
+
+/// Alignment to reference time
+#[repr(u16)]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlignmentToReferenceTime {
+    Utc = 0,
+    Gps = 1,
+    Glo = 2,
+    Bds = 3,
+    Gal = 4,
+}
+
+impl AlignmentToReferenceTime {
+    const fn into_raw(self) -> u16 {
+        self as u16
+    }
+}
+
+/// Set Message Rate the current port
+#[ubx_packet_send]
+#[ubx(class = 6, id = 1, fixed_payload_len = 3)]
+struct CfgMsgSinglePort {
+    msg_class: u8,
+    msg_id: u8,
+
+    /// Send rate on current Target
+    rate: u8,
+}
+
+impl CfgMsgSinglePortBuilder {
+    #[inline]
+    pub fn set_rate_for<T: UbxPacketMeta>(rate: u8) -> Self {
+        Self {
+            msg_class: T::CLASS,
+            msg_id: T::ID,
+            rate,
+        }
+    }
+}
+
+/// Set Message rate configuration
+/// Send rate is relative to the event a message is registered on.
+/// For example, if the rate of a navigation message is set to 2,
+/// the message is sent every second navigation solution
+#[ubx_packet_send]
+#[ubx(class = 6, id = 1, fixed_payload_len = 8)]
+struct CfgMsgAllPorts {
+    msg_class: u8,
+    msg_id: u8,
+
+    /// Send rate on I/O Port (6 Ports)
+    rates: [u8; 6],
+}
+
+impl CfgMsgAllPortsBuilder {
+    #[inline]
+    pub fn set_rate_for<T: UbxPacketMeta>(rates: [u8; 6]) -> Self {
+        Self {
+            msg_class: T::CLASS,
+            msg_id: T::ID,
+            rates,
+        }
+    }
+}
+
+/// Navigation Engine Settings
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x24,
+    fixed_payload_len = 36,
+    flags = "default_for_builder"
+)]
+struct CfgNav5 {
+    /// Only the masked parameters will be applied
+    #[ubx(map_type = CfgNav5Params)]
+    mask: u16,
+    #[ubx(map_type = CfgNav5DynModel, may_fail)]
+    dyn_model: u8,
+    #[ubx(map_type = CfgNav5FixMode, may_fail)]
+    fix_mode: u8,
+
+    /// Fixed altitude (mean sea level) for 2D fixmode (m)
+    #[ubx(map_type = f64, scale = 0.01)]
+    fixed_alt: i32,
+
+    /// Fixed altitude variance for 2D mode (m^2)
+    #[ubx(map_type = f64, scale = 0.0001)]
+    fixed_alt_var: u32,
+
+    /// Minimum Elevation for a GNSS satellite to be used in NAV (deg)
+    min_elev_degrees: i8,
+
+    /// Reserved
+    dr_limit: u8,
+
+    /// Position DOP Mask to use
+    #[ubx(map_type = f32, scale = 0.1)]
+    pdop: u16,
+
+    /// Time DOP Mask to use
+    #[ubx(map_type = f32, scale = 0.1)]
+    tdop: u16,
+
+    /// Position Accuracy Mask (m)
+    pacc: u16,
+
+    /// Time Accuracy Mask
+    /// according to manual unit is "m", but this looks like typo
     tacc: u16,
 
-    /// Static hold threshold
-    #[ubx(map_type = f32, scale = 0.01)]
-    static_hold_thresh: u8,
+    /// Static hold threshold
+    #[ubx(map_type = f32, scale = 0.01)]
+    static_hold_thresh: u8,
+
+    /// DGNSS timeout (seconds)
+    dgps_time_out: u8,
+
+    /// Number of satellites required to have
+    /// C/N0 above `cno_thresh` for a fix to be attempted
+    cno_thresh_num_svs: u8,
+
+    /// C/N0 threshold for deciding whether toattempt a fix (dBHz)
+    cno_thresh: u8,
+    reserved1: [u8; 2],
+
+    /// Static hold distance threshold (beforequitting static hold)
+    static_hold_max_dist: u16,
+
+    /// UTC standard to be used
+    #[ubx(map_type = CfgNav5UtcStandard, may_fail)]
+    utc_standard: u8,
+    reserved2: [u8; 5],
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// `CfgNav5` parameters bitmask
+    #[derive(Default)]
+    pub struct CfgNav5Params: u16 {
+        /// Apply dynamic model settings
+        const DYN = 1;
+        /// Apply minimum elevation settings
+        const MIN_EL = 2;
+        /// Apply fix mode settings
+       const POS_FIX_MODE = 4;
+        /// Reserved
+        const DR_LIM = 8;
+        /// position mask settings
+       const POS_MASK_APPLY = 0x10;
+        /// Apply time mask settings
+        const TIME_MASK = 0x20;
+        /// Apply static hold settings
+        const STATIC_HOLD_MASK = 0x40;
+        /// Apply DGPS settings
+        const DGPS_MASK = 0x80;
+        /// Apply CNO threshold settings (cnoThresh, cnoThreshNumSVs)
+        const CNO_THRESHOLD = 0x100;
+        /// Apply UTC settings (not supported in protocol versions less than 16)
+        const UTC = 0x400;
+    }
+}
+
+/// Dynamic platform model
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgNav5DynModel {
+    Portable = 0,
+    Stationary = 2,
+    Pedestrian = 3,
+    Automotive = 4,
+    Sea = 5,
+    AirborneWithLess1gAcceleration = 6,
+    AirborneWithLess2gAcceleration = 7,
+    AirborneWith4gAcceleration = 8,
+    /// not supported in protocol versions less than 18
+    WristWornWatch = 9,
+    /// supported in protocol versions 19.2
+    Bike = 10,
+    /// Robotic lawn mower, only supported by some Gen9 receivers
+    Mower = 11,
+    /// Electric kick scooter, only supported by some Gen9 receivers
+    EScooter = 12,
+}
+
+impl Default for CfgNav5DynModel {
+    fn default() -> Self {
+        Self::AirborneWith4gAcceleration
+    }
+}
+
+/// Position Fixing Mode
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgNav5FixMode {
+    Only2D = 1,
+    Only3D = 2,
+    Auto2D3D = 3,
+}
+
+impl Default for CfgNav5FixMode {
+    fn default() -> Self {
+        CfgNav5FixMode::Auto2D3D
+    }
+}
+
+/// UTC standard to be used
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgNav5UtcStandard {
+    /// receiver selects based on GNSS configuration (see GNSS timebases)
+    Automatic = 0,
+    /// UTC as operated by the U.S. NavalObservatory (USNO);
+    /// derived from GPStime
+    Usno = 3,
+    /// UTC as combined from multiple European laboratories;
+    /// derived from Galileo time
+    Eu = 5,
+    /// UTC as operated by the former Soviet Union; derived from GLONASS time
+    UtcSu = 6,
+    /// UTC as operated by the National TimeService Center, China;
+    /// derived from BeiDou time
+    UtcChina = 7,
+    /// UTC as operated by the National Physical Laboratory, India;
+    /// derived from NavIC time
+    UtcIndia = 8,
+}
+
+impl Default for CfgNav5UtcStandard {
+    fn default() -> Self {
+        Self::Automatic
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+struct ScaleBack<T: FloatCore + FromPrimitive + ToPrimitive>(T);
+
+impl<T: FloatCore + FromPrimitive + ToPrimitive> ScaleBack<T> {
+    fn as_i8(self, x: T) -> i8 {
+        let x = (x * self.0).round();
+        if x < T::from_i8(i8::min_value()).unwrap() {
+            i8::min_value()
+        } else if x > T::from_i8(i8::max_value()).unwrap() {
+            i8::max_value()
+        } else {
+            x.to_i8().unwrap()
+        }
+    }
+
+    fn as_i16(self, x: T) -> i16 {
+        let x = (x * self.0).round();
+        if x < T::from_i16(i16::min_value()).unwrap() {
+            i16::min_value()
+        } else if x > T::from_i16(i16::max_value()).unwrap() {
+            i16::max_value()
+        } else {
+            x.to_i16().unwrap()
+        }
+    }
+
+    fn as_i32(self, x: T) -> i32 {
+        let x = (x * self.0).round();
+        if x < T::from_i32(i32::MIN).unwrap() {
+            i32::MIN
+        } else if x > T::from_i32(i32::MAX).unwrap() {
+            i32::MAX
+        } else {
+            x.to_i32().unwrap()
+        }
+    }
+
+    fn as_u32(self, x: T) -> u32 {
+        let x = (x * self.0).round();
+        if !x.is_sign_negative() {
+            if x <= T::from_u32(u32::MAX).unwrap() {
+                x.to_u32().unwrap()
+            } else {
+                u32::MAX
+            }
+        } else {
+            0
+        }
+    }
+
+    fn as_u16(self, x: T) -> u16 {
+        let x = (x * self.0).round();
+        if !x.is_sign_negative() {
+            if x <= T::from_u16(u16::MAX).unwrap() {
+                x.to_u16().unwrap()
+            } else {
+                u16::MAX
+            }
+        } else {
+            0
+        }
+    }
+
+    fn as_u8(self, x: T) -> u8 {
+        let x = (x * self.0).round();
+        if !x.is_sign_negative() {
+            if x <= T::from_u8(u8::MAX).unwrap() {
+                x.to_u8().unwrap()
+            } else {
+                u8::MAX
+            }
+        } else {
+            0
+        }
+    }
+}
+
+/// Navigation Engine Expert Settings
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x23,
+    fixed_payload_len = 40,
+    flags = "default_for_builder"
+)]
+struct CfgNavX5 {
+    /// Only version 2 supported
+    version: u16,
+
+    /// Only the masked parameters will be applied
+    #[ubx(map_type = CfgNavX5Params1)]
+    mask1: u16,
+
+    #[ubx(map_type = CfgNavX5Params2)]
+    mask2: u32,
+
+    /// Reserved
+    reserved1: [u8; 2],
+
+    /// Minimum number of satellites for navigation
+    min_svs: u8,
+
+    ///Maximum number of satellites for navigation
+    max_svs: u8,
+
+    /// Minimum satellite signal level for navigation
+    min_cno_dbhz: u8,
+
+    /// Reserved
+    reserved2: u8,
+
+    /// initial fix must be 3D
+    ini_fix_3d: u8,
+
+    /// Reserved
+    reserved3: [u8; 2],
+
+    /// issue acknowledgements for assistance message input
+    ack_aiding: u8,
+
+    /// GPS week rollover number
+    wkn_rollover: u16,
+
+    /// Permanently attenuated signal compensation
+    sig_atten_comp_mode: u8,
+
+    /// Reserved
+    reserved4: u8,
+    reserved5: [u8; 2],
+    reserved6: [u8; 2],
+
+    /// Use Precise Point Positioning (only available with the PPP product variant)
+    use_ppp: u8,
+
+    /// AssistNow Autonomous configuration
+    aop_cfg: u8,
+
+    /// Reserved
+    reserved7: [u8; 2],
+
+    /// Maximum acceptable (modeled) AssistNow Autonomous orbit error
+    aop_orb_max_err: u16,
+
+    /// Reserved
+    reserved8: [u8; 4],
+    reserved9: [u8; 3],
+
+    /// Enable/disable ADR/UDR sensor fusion
+    use_adr: u8,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// `CfgNavX51` parameters bitmask
+    #[derive(Default)]
+    pub struct CfgNavX5Params1: u16 {
+        /// apply min/max SVs settings
+        const MIN_MAX = 0x4;
+        /// apply minimum C/N0 setting
+        const MIN_CNO = 0x8;
+        /// apply initial 3D fix settings
+        const INITIAL_3D_FIX = 0x40;
+        /// apply GPS weeknumber rollover settings
+        const WKN_ROLL = 0x200;
+        /// apply assistance acknowledgement settings
+        const AID_ACK = 0x400;
+        /// apply usePPP flag
+        const USE_PPP = 0x2000;
+        /// apply aopCfg (useAOP flag) and aopOrbMaxErr settings (AssistNow Autonomous)
+        const AOP_CFG = 0x4000;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// `CfgNavX5Params2` parameters bitmask
+    #[derive(Default)]
+    pub struct CfgNavX5Params2: u32 {
+        ///  apply ADR/UDR sensor fusion on/off setting
+        const USE_ADR = 0x40;
+        ///  apply signal attenuation compensation feature settings
+        const USE_SIG_ATTEN_COMP = 0x80;
+    }
+}
+
+/// GNSS Assistance ACK UBX-MGA-ACK
+#[ubx_packet_recv]
+#[ubx(class = 0x13, id = 0x60, fixed_payload_len = 8)]
+struct MgaAck {
+    /// Type of acknowledgment: 0 -> not used, 1 -> accepted
+    ack_type: u8,
+
+    /// Version 0
+    version: u8,
+
+    /// Provides greater information on what the receiver chose to do with the message contents.
+    /// See [MsgAckInfoCode].
+    #[ubx(map_type = MsgAckInfoCode)]
+    info_code: u8,
+
+    /// UBX message ID of the acknowledged message
+    msg_id: u8,
+
+    /// The first 4 bytes of the acknowledged message's payload
+    msg_payload_start: [u8; 4],
+}
+
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MsgAckInfoCode {
+    Accepted = 0,
+    RejectedNoTime = 1,
+    RejectedBadVersion = 2,
+    RejectedBadSize = 3,
+    RejectedDBStoreFailed = 4,
+    RejectedNotReady = 5,
+    RejectedUnknownType = 6,
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x13, id = 0x06, fixed_payload_len = 48)]
+struct MgaGloEph {
+    msg_type: u8,
+    version: u8,
+    sv_id: u8,
+    reserved1: u8,
+    ft: u8,
+    b: u8,
+    m: u8,
+    h: i8,
+    x: i32,
+    y: i32,
+    z: i32,
+    dx: i32,
+    dy: i32,
+    dz: i32,
+    ddx: i8,
+    ddy: i8,
+    ddz: i8,
+    tb: u8,
+    gamma: u16,
+    e: u8,
+    delta_tau: u8,
+    tau: i32,
+    reserved2: [u8; 4],
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x13, id = 0x00, fixed_payload_len = 16)]
+struct MgaGpsIono {
+    /// Message type: 0x06 for this type
+    msg_type: u8,
+    /// Message version: 0x00 for this version
+    version: u8,
+    reserved1: [u8; 2],
+    /// Ionospheric parameter alpha0 [s]
+    #[ubx(map_type = f64, scale = 1.0)] // 2^-30
+    alpha0: i8,
+    /// Ionospheric parameter alpha1 [s/semi-circle]
+    #[ubx(map_type = f64, scale = 1.0)] // 2^-27
+    alpha1: i8,
+    /// Ionospheric parameter alpha1 [s/semi-circle^2]
+    #[ubx(map_type = f64, scale = 1.0)] // 2^-24
+    alpha2: i8,
+    /// Ionospheric parameter alpha1 [s/semi-circle^3]
+    #[ubx(map_type = f64, scale = 1.0)] // 2^-24
+    alpha3: i8,
+    /// Ionospheric parameter beta0 [s]
+    #[ubx(map_type = f64, scale = 1.0)] // 2^-11
+    beta0: i8,
+    /// Ionospheric parameter beta0 [s/semi-circle]
+    #[ubx(map_type = f64, scale = 1.0)] // 2^-14
+    beta1: i8,
+    /// Ionospheric parameter beta0 [s/semi-circle^2]
+    #[ubx(map_type = f64, scale = 1.0)] // 2^-16
+    beta2: i8,
+    /// Ionospheric parameter beta0 [s/semi-circle^3]
+    #[ubx(map_type = f64, scale = 1.0)] // 2^-16
+    beta3: i8,
+    reserved2: [u8; 4],
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x13, id = 0x00, fixed_payload_len = 68)]
+struct MgaGpsEph {
+    msg_type: u8,
+    version: u8,
+    sv_id: u8,
+    reserved1: u8,
+    fit_interval: u8,
+    ura_index: u8,
+    sv_health: u8,
+    tgd: i8,
+    iodc: u16,
+    toc: u16,
+    reserved2: u8,
+    af2: i8,
+    af1: i16,
+    af0: i32,
+    crs: i16,
+    delta_n: i16,
+    m0: i32,
+    cuc: i16,
+    cus: i16,
+    e: u32,
+    sqrt_a: u32,
+    toe: u16,
+    cic: i16,
+    omega0: i32,
+    cis: i16,
+    crc: i16,
+    i0: i32,
+    omega: i32,
+    omega_dot: i32,
+    idot: i16,
+    reserved3: [u8; 2],
+}
+
+/// Time pulse time data
+#[ubx_packet_recv]
+#[ubx(class = 0x0d, id = 0x01, fixed_payload_len = 16)]
+struct TimTp {
+    /// Time pulse time of week according to time base
+    tow_ms: u32,
+    /// Submillisecond part of towMS (scaling: 2^(-32))
+    tow_sub_ms: u32,
+    /// Quantization error of time pulse
+    q_err: i32,
+    /// Time pulse week number according to time base
+    week: u16,
+    /// Flags
+    #[ubx(map_type = TimTpFlags, from = TimTpFlags)]
+    flags: u8,
+    /// Time reference information
+    #[ubx(map_type = TimTpRefInfo, from = TimTpRefInfo)]
+    ref_info: u8,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TimTpFlags(u8);
+
+impl TimTpFlags {
+    /// Time base
+    pub fn time_base(&self) -> TimTpTimeBase {
+        if self.0 & 0b1 == 0 {
+            TimTpTimeBase::Gnss
+        } else {
+            TimTpTimeBase::Utc
+        }
+    }
+
+    /// UTC availability
+    pub fn utc_available(&self) -> bool {
+        self.0 & 0b10 != 0
+    }
+
+    /// (T)RAIM state
+    ///
+    /// Returns `None` if unavailale.
+    pub fn raim_active(&self) -> Option<bool> {
+        match (self.0 >> 2) & 0b11 {
+            // Inactive.
+            0b01 => Some(false),
+            // Active.
+            0b10 => Some(true),
+            // Unavailable.
+            _ => None,
+        }
+    }
+
+    /// Quantization error validity
+    pub fn q_err_valid(&self) -> bool {
+        self.0 & 0b10000 == 0
+    }
+}
 
-    /// DGNSS timeout (seconds)
-    dgps_time_out: u8,
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimTpTimeBase {
+    Gnss,
+    Utc,
+}
 
-    /// Number of satellites required to have
-    /// C/N0 above `cno_thresh` for a fix to be attempted
-    cno_thresh_num_svs: u8,
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TimTpRefInfo(u8);
 
-    /// C/N0 threshold for deciding whether toattempt a fix (dBHz)
-    cno_thresh: u8,
+impl TimTpRefInfo {
+    /// GNSS reference information. Only valid if time base is GNSS.
+    pub fn time_ref_gnss(&self) -> Option<TimTpRefInfoTimeRefGnss> {
+        Some(match self.0 & 0b1111 {
+            0 => TimTpRefInfoTimeRefGnss::Gps,
+            1 => TimTpRefInfoTimeRefGnss::Glo,
+            2 => TimTpRefInfoTimeRefGnss::Bds,
+            3 => TimTpRefInfoTimeRefGnss::Gal,
+            4 => TimTpRefInfoTimeRefGnss::NavIc,
+            _ => return None,
+        })
+    }
+
+    /// UTC standard identifier. Only valid if time base is UTC.
+    pub fn utc_standard(&self) -> Option<TimTpRefInfoUtcStandard> {
+        Some(match self.0 >> 4 {
+            1 => TimTpRefInfoUtcStandard::Crl,
+            2 => TimTpRefInfoUtcStandard::Nist,
+            3 => TimTpRefInfoUtcStandard::Usno,
+            4 => TimTpRefInfoUtcStandard::Bipm,
+            5 => TimTpRefInfoUtcStandard::Eu,
+            6 => TimTpRefInfoUtcStandard::Su,
+            7 => TimTpRefInfoUtcStandard::Ntsc,
+            8 => TimTpRefInfoUtcStandard::Npli,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TimTpRefInfoTimeRefGnss {
+    Gps,
+    Glo,
+    Bds,
+    Gal,
+    NavIc,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TimTpRefInfoUtcStandard {
+    Crl,
+    Nist,
+    Usno,
+    Bipm,
+    Eu,
+    Su,
+    Ntsc,
+    Npli,
+}
+
+/// Time mark data
+#[ubx_packet_recv]
+#[ubx(class = 0x0d, id = 0x03, fixed_payload_len = 28)]
+struct TimTm2 {
+    /// Channel (i.e. EXTINT) upon which the pulse was measured
+    ch: u8,
+    /// Flags
+    #[ubx(map_type = TimTm2Flags, from = TimTm2Flags)]
+    flags: u8,
+    /// Rising edge counter
+    count: u16,
+    /// Week number of last rising edge
+    wn_r: u16,
+    /// Week number of last falling edge
+    wn_f: u16,
+    /// Tow of rising edge
+    tow_ms_r: u32,
+    /// Millisecond fraction of tow of rising edge in nanoseconds
+    tow_sub_ms_r: u32,
+    /// Tow of falling edge
+    tow_ms_f: u32,
+    /// Millisecond fraction of tow of falling edge in nanoseconds
+    tow_sub_ms_f: u32,
+    /// Accuracy estimate
+    acc_est: u32,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TimTm2Flags(u8);
+
+impl TimTm2Flags {
+    pub fn mode(&self) -> TimTm2Mode {
+        if self.0 & 0b1 == 0 {
+            TimTm2Mode::Single
+        } else {
+            TimTm2Mode::Running
+        }
+    }
+
+    pub fn run(&self) -> TimTm2Run {
+        if self.0 & 0b10 == 0 {
+            TimTm2Run::Armed
+        } else {
+            TimTm2Run::Stopped
+        }
+    }
+
+    pub fn new_falling_edge(&self) -> bool {
+        self.0 & 0b100 != 0
+    }
+
+    pub fn new_rising_edge(&self) -> bool {
+        self.0 & 0b10000000 != 0
+    }
+
+    pub fn time_base(&self) -> TimTm2TimeBase {
+        match self.0 & 0b11000 {
+            0 => TimTm2TimeBase::Receiver,
+            1 => TimTm2TimeBase::Gnss,
+            2 => TimTm2TimeBase::Utc,
+            _ => unreachable!(),
+        }
+    }
+
+    /// UTC availability
+    pub fn utc_available(&self) -> bool {
+        self.0 & 0b100000 != 0
+    }
+
+    pub fn time_valid(&self) -> bool {
+        self.0 & 0b1000000 != 0
+    }
+}
+
+pub enum TimTm2Mode {
+    Single,
+    Running,
+}
+
+pub enum TimTm2Run {
+    Armed,
+    Stopped,
+}
+
+pub enum TimTm2TimeBase {
+    Receiver,
+    Gnss,
+    Utc,
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x15, max_payload_len = 8176)] // 16 + 255 * 32
+struct RxmRawx {
+    /// Measurement time of week in receiver local time approximately aligned to the GPS time system.
+    rcv_tow: f64,
+    /// GPS week number in receiver local time.
+    week: u16,
+    /// GPS leap seconds (GPS-UTC)
+    leap_s: i8,
+    /// Number of measurements to follow
+    num_meas: u8,
+    /// Receiver tracking status bitfield
+    #[ubx(map_type = RecStatFlags)]
+    rec_stat: u8,
+    /// Message version
+    version: u8,
     reserved1: [u8; 2],
+    /// Extended software information strings
+    #[ubx(
+        map_type = RxmRawxInfoIter,
+        from = RxmRawxInfoIter::new,
+        may_fail,
+        is_valid = RxmRawxInfoIter::is_valid,
+    )]
+    measurements: [u8; 0],
+}
 
-    /// Static hold distance threshold (beforequitting static hold)
-    static_hold_max_dist: u16,
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// `CfgNavX5Params2` parameters bitmask
+    #[derive(Default)]
+    pub struct RecStatFlags: u8 {
+        /// Leap seconds have been determined
+        const LEAP_SEC = 0x1;
+        /// Clock reset applied.
+        const CLK_RESET = 0x2;
+    }
+}
 
-    /// UTC standard to be used
-    #[ubx(map_type = CfgNav5UtcStandard, may_fail)]
-    utc_standard: u8,
-    reserved2: [u8; 5],
+/// Hardware status
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x09, fixed_payload_len = 60)]
+struct MonHw {
+    pin_sel: u32,
+    pin_bank: u32,
+    pin_dir: u32,
+    pin_val: u32,
+    noise_per_ms: u16,
+    agc_cnt: u16,
+    #[ubx(map_type = AntennaStatus)]
+    a_status: u8,
+    #[ubx(map_type = AntennaPower)]
+    a_power: u8,
+    flags: u8,
+    reserved1: u8,
+    used_mask: u32,
+    vp: [u8; 17],
+    jam_ind: u8,
+    reserved2: [u8; 2],
+    pin_irq: u32,
+    pull_h: u32,
+    pull_l: u32,
+}
+
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AntennaStatus {
+    Init = 0,
+    DontKnow = 1,
+    Ok = 2,
+    Short = 3,
+    Open = 4,
+}
+
+/// GNSS status monitoring,
+/// gives currently selected constellations
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x28, fixed_payload_len = 8)]
+struct MonGnss {
+    /// Message version: 0x00
+    version: u8,
+    /// Supported major constellations bit mask
+    #[ubx(map_type = MonGnssConstellMask)]
+    supported: u8,
+    /// Default major GNSS constellations bit mask
+    #[ubx(map_type = MonGnssConstellMask)]
+    default: u8,
+    /// Currently enabled major constellations bit mask
+    #[ubx(map_type = MonGnssConstellMask)]
+    enabled: u8,
+    /// Maximum number of concurent Major GNSS
+    /// that can be supported by this receiver
+    simultaneous: u8,
+    reserved1: [u8; 3],
 }
 
 #[ubx_extend_bitflags]
 #[ubx(from, into_raw, rest_reserved)]
 bitflags! {
-    /// `CfgNav5` parameters bitmask
+    /// Selected / available Constellation Mask
     #[derive(Default)]
-    pub struct CfgNav5Params: u16 {
-        /// Apply dynamic model settings
-        const DYN = 1;
-        /// Apply minimum elevation settings
-        const MIN_EL = 2;
-        /// Apply fix mode settings
-       const POS_FIX_MODE = 4;
-        /// Reserved
-        const DR_LIM = 8;
-        /// position mask settings
-       const POS_MASK_APPLY = 0x10;
-        /// Apply time mask settings
-        const TIME_MASK = 0x20;
-        /// Apply static hold settings
-        const STATIC_HOLD_MASK = 0x40;
-        /// Apply DGPS settings
-        const DGPS_MASK = 0x80;
-        /// Apply CNO threshold settings (cnoThresh, cnoThreshNumSVs)
-        const CNO_THRESHOLD = 0x100;
-        /// Apply UTC settings (not supported in protocol versions less than 16)
-        const UTC = 0x400;
+    pub struct MonGnssConstellMask: u8 {
+        /// GPS constellation
+        const GPS = 0x01;
+        /// GLO constellation
+        const GLO = 0x02;
+        /// BDC constellation
+        const BDC = 0x04;
+        /// GAL constellation
+        const GAL = 0x08;
     }
 }
 
-/// Dynamic platform model
 #[ubx_extend]
-#[ubx(from_unchecked, into_raw, rest_error)]
+#[ubx(from, rest_reserved)]
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum CfgNav5DynModel {
-    Portable = 0,
-    Stationary = 2,
-    Pedestrian = 3,
-    Automotive = 4,
-    Sea = 5,
-    AirborneWithLess1gAcceleration = 6,
-    AirborneWithLess2gAcceleration = 7,
-    AirborneWith4gAcceleration = 8,
-    /// not supported in protocol versions less than 18
-    WristWornWatch = 9,
-    /// supported in protocol versions 19.2
-    Bike = 10,
+pub enum AntennaPower {
+    Off = 0,
+    On = 1,
+    DontKnow = 2,
 }
 
-impl Default for CfgNav5DynModel {
-    fn default() -> Self {
-        Self::AirborneWith4gAcceleration
+/// Jamming/interference monitor status of a `MonRfBlock`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JammingState {
+    /// Unknown or feature disabled
+    Unknown,
+    /// Ok, no significant jamming
+    Ok,
+    /// Interference visible but fix OK
+    Warning,
+    /// Interference visible and no fix
+    Critical,
+}
+
+impl JammingState {
+    fn from_flags(flags: u8) -> Self {
+        match flags & 0x3 {
+            1 => JammingState::Ok,
+            2 => JammingState::Warning,
+            3 => JammingState::Critical,
+            _ => JammingState::Unknown,
+        }
     }
 }
 
-/// Position Fixing Mode
-#[ubx_extend]
-#[ubx(from_unchecked, into_raw, rest_error)]
-#[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum CfgNav5FixMode {
-    Only2D = 1,
-    Only3D = 2,
-    Auto2D3D = 3,
-}
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x38, fixed_payload_len = 24)]
+struct MonRfBlock {
+    /// RF block ID (0 = L1 band, 1 = L2 or L5 band depending on product configuration)
+    block_id: u8,
 
-impl Default for CfgNav5FixMode {
-    fn default() -> Self {
-        CfgNav5FixMode::Auto2D3D
-    }
-}
+    #[ubx(map_type = JammingState, from = JammingState::from_flags, alias = jamming_state)]
+    flags: u8,
 
-/// UTC standard to be used
-#[ubx_extend]
-#[ubx(from_unchecked, into_raw, rest_error)]
-#[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum CfgNav5UtcStandard {
-    /// receiver selects based on GNSS configuration (see GNSS timebases)
-    Automatic = 0,
-    /// UTC as operated by the U.S. NavalObservatory (USNO);
-    /// derived from GPStime
-    Usno = 3,
-    /// UTC as operated by the former Soviet Union; derived from GLONASS time
-    UtcSu = 6,
-    /// UTC as operated by the National TimeService Center, China;
-    /// derived from BeiDou time
-    UtcChina = 7,
-}
+    #[ubx(map_type = AntennaStatus)]
+    ant_status: u8,
 
-impl Default for CfgNav5UtcStandard {
-    fn default() -> Self {
-        Self::Automatic
-    }
-}
+    #[ubx(map_type = AntennaPower)]
+    ant_power: u8,
 
-#[derive(Clone, Copy)]
-#[repr(transparent)]
-struct ScaleBack<T: FloatCore + FromPrimitive + ToPrimitive>(T);
+    /// POST status word
+    post_status: u32,
 
-impl<T: FloatCore + FromPrimitive + ToPrimitive> ScaleBack<T> {
-    fn as_i8(self, x: T) -> i8 {
-        let x = (x * self.0).round();
-        if x < T::from_i8(i8::min_value()).unwrap() {
-            i8::min_value()
-        } else if x > T::from_i8(i8::max_value()).unwrap() {
-            i8::max_value()
-        } else {
-            x.to_i8().unwrap()
-        }
-    }
+    reserved1: [u8; 4],
 
-    fn as_i16(self, x: T) -> i16 {
-        let x = (x * self.0).round();
-        if x < T::from_i16(i16::min_value()).unwrap() {
-            i16::min_value()
-        } else if x > T::from_i16(i16::max_value()).unwrap() {
-            i16::max_value()
-        } else {
-            x.to_i16().unwrap()
-        }
-    }
+    /// Noise level as measured by the GPS core
+    noise_per_ms: u16,
 
-    fn as_i32(self, x: T) -> i32 {
-        let x = (x * self.0).round();
-        if x < T::from_i32(i32::MIN).unwrap() {
-            i32::MIN
-        } else if x > T::from_i32(i32::MAX).unwrap() {
-            i32::MAX
-        } else {
-            x.to_i32().unwrap()
-        }
-    }
+    /// AGC Monitor (counts SIGHI xor SIGLO, range 0 to 8191)
+    agc_cnt: u16,
 
-    fn as_u32(self, x: T) -> u32 {
-        let x = (x * self.0).round();
-        if !x.is_sign_negative() {
-            if x <= T::from_u32(u32::MAX).unwrap() {
-                x.to_u32().unwrap()
-            } else {
-                u32::MAX
-            }
-        } else {
-            0
-        }
+    /// CW interference suppression level, scaled (0 = no CW jamming, 255 = strong CW jamming)
+    jam_ind: u8,
+
+    /// Imbalance of I-part of complex signal, scaled (-128 = max. negative imbalance, 127 = max. positive imbalance)
+    ofs_i: i8,
+
+    /// Magnitude of I-part of complex signal, scaled (0 = no signal, 255 = max. magnitude)
+    mag_i: u8,
+
+    /// Imbalance of Q-part of complex signal, scaled (-128 = max. negative imbalance, 127 = max. positive imbalance)
+    ofs_q: i8,
+
+    /// Magnitude of Q-part of complex signal, scaled (0 = no signal, 255 = max. magnitude)
+    mag_q: u8,
+
+    reserved2: [u8; 3],
+}
+
+#[derive(Debug, Clone)]
+pub struct MonRfIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> MonRfIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
     }
 
-    fn as_u16(self, x: T) -> u16 {
-        let x = (x * self.0).round();
-        if !x.is_sign_negative() {
-            if x <= T::from_u16(u16::MAX).unwrap() {
-                x.to_u16().unwrap()
-            } else {
-                u16::MAX
-            }
-        } else {
-            0
-        }
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len() % 24 == 0
     }
+}
 
-    fn as_u8(self, x: T) -> u8 {
-        let x = (x * self.0).round();
-        if !x.is_sign_negative() {
-            if x <= T::from_u8(u8::MAX).unwrap() {
-                x.to_u8().unwrap()
-            } else {
-                u8::MAX
-            }
+impl<'a> core::iter::Iterator for MonRfIter<'a> {
+    type Item = MonRfBlockRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + 24];
+            self.offset += 24;
+            Some(MonRfBlockRef(data))
         } else {
-            0
+            None
         }
     }
 }
 
-/// Navigation Engine Expert Settings
-#[ubx_packet_recv_send]
-#[ubx(
-    class = 0x06,
-    id = 0x23,
-    fixed_payload_len = 40,
-    flags = "default_for_builder"
-)]
-struct CfgNavX5 {
-    /// Only version 2 supported
-    version: u16,
-
-    /// Only the masked parameters will be applied
-    #[ubx(map_type = CfgNavX5Params1)]
-    mask1: u16,
+/// RF information, one block per RF path
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x38, max_payload_len = 52)] // 4 + 24 * 2
+struct MonRf {
+    /// Message version (0x00 for this version)
+    version: u8,
 
-    #[ubx(map_type = CfgNavX5Params2)]
-    mask2: u32,
+    n_blocks: u8,
 
-    /// Reserved
-    reserved1: [u8; 2],
+    reserved0: [u8; 2],
 
-    /// Minimum number of satellites for navigation
-    min_svs: u8,
+    #[ubx(
+        map_type = MonRfIter,
+        from = MonRfIter::new,
+        is_valid = MonRfIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    blocks: [u8; 0],
+}
 
-    ///Maximum number of satellites for navigation
-    max_svs: u8,
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x36, fixed_payload_len = 40)]
+struct MonCommsPort {
+    /// Unique identifier for the port, the high byte is the port number
+    /// (0 = I2C, 1 = UART1, 2 = UART2, 3 = USB, 4 = SPI)
+    port_id: u16,
 
-    /// Minimum satellite signal level for navigation
-    min_cno_dbhz: u8,
+    /// Number of bytes pending in transmitter buffer
+    tx_pending: u16,
 
-    /// Reserved
-    reserved2: u8,
+    /// Number of bytes ever sent
+    tx_bytes: u32,
 
-    /// initial fix must be 3D
-    ini_fix_3d: u8,
+    /// Maximum usage transmitter buffer during the last sysmon period (%)
+    tx_usage: u8,
 
-    /// Reserved
-    reserved3: [u8; 2],
+    /// Maximum usage transmitter buffer (%)
+    tx_peak_usage: u8,
 
-    /// issue acknowledgements for assistance message input
-    ack_aiding: u8,
+    /// Number of bytes in receiver buffer
+    rx_pending: u16,
 
-    /// GPS week rollover number
-    wkn_rollover: u16,
+    /// Number of bytes ever received
+    rx_bytes: u32,
 
-    /// Permanently attenuated signal compensation
-    sig_atten_comp_mode: u8,
+    /// Maximum usage receiver buffer during the last sysmon period (%)
+    rx_usage: u8,
 
-    /// Reserved
-    reserved4: u8,
-    reserved5: [u8; 2],
-    reserved6: [u8; 2],
+    /// Maximum usage receiver buffer (%)
+    rx_peak_usage: u8,
 
-    /// Use Precise Point Positioning (only available with the PPP product variant)
-    use_ppp: u8,
+    /// Number of 100 ms timeslots with overrun errors
+    overrun_errs: u16,
 
-    /// AssistNow Autonomous configuration
-    aop_cfg: u8,
+    /// Number of successfully parsed messages for each protocol,
+    /// in the order given by `MonComms::prot_ids`
+    #[ubx(map_type = [u16; 4], from = le_u16_array, get_as_ref)]
+    msgs: [u8; 8],
 
-    /// Reserved
-    reserved7: [u8; 2],
+    reserved1: [u8; 8],
 
-    /// Maximum acceptable (modeled) AssistNow Autonomous orbit error
-    aop_orb_max_err: u16,
+    /// Number of skipped bytes
+    skipped: u32,
+}
 
-    /// Reserved
-    reserved8: [u8; 4],
-    reserved9: [u8; 3],
+impl<'a> MonCommsPortRef<'a> {
+    /// Port number, as used by `I2cPortId`, `UartPortId` and `SpiPortId`
+    pub fn port_number(&self) -> u8 {
+        (self.port_id() >> 8) as u8
+    }
+}
 
-    /// Enable/disable ADR/UDR sensor fusion
-    use_adr: u8,
+#[derive(Debug, Clone)]
+pub struct MonCommsIter<'a> {
+    data: &'a [u8],
+    offset: usize,
 }
 
-#[ubx_extend_bitflags]
-#[ubx(from, into_raw, rest_reserved)]
-bitflags! {
-    /// `CfgNavX51` parameters bitmask
-    #[derive(Default)]
-    pub struct CfgNavX5Params1: u16 {
-        /// apply min/max SVs settings
-        const MIN_MAX = 0x4;
-        /// apply minimum C/N0 setting
-        const MIN_CNO = 0x8;
-        /// apply initial 3D fix settings
-        const INITIAL_3D_FIX = 0x40;
-        /// apply GPS weeknumber rollover settings
-        const WKN_ROLL = 0x200;
-        /// apply assistance acknowledgement settings
-        const AID_ACK = 0x400;
-        /// apply usePPP flag
-        const USE_PPP = 0x2000;
-        /// apply aopCfg (useAOP flag) and aopOrbMaxErr settings (AssistNow Autonomous)
-        const AOP_CFG = 0x4000;
+impl<'a> MonCommsIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len() % 40 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for MonCommsIter<'a> {
+    type Item = MonCommsPortRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + 40];
+            self.offset += 40;
+            Some(MonCommsPortRef(data))
+        } else {
+            None
+        }
     }
 }
 
 #[ubx_extend_bitflags]
-#[ubx(from, into_raw, rest_reserved)]
+#[ubx(from, rest_reserved)]
 bitflags! {
-    /// `CfgNavX5Params2` parameters bitmask
-    #[derive(Default)]
-    pub struct CfgNavX5Params2: u32 {
-        ///  apply ADR/UDR sensor fusion on/off setting
-        const USE_ADR = 0x40;
-        ///  apply signal attenuation compensation feature settings
-        const USE_SIG_ATTEN_COMP = 0x80;
+    /// TX error bitmask of `MonComms`
+    pub struct MonCommsTxErrors: u8 {
+        /// Memory Allocation error
+        const MEM = 0x01;
+        /// Messages for the TX buffer could not be allocated
+        const ALLOC = 0x02;
     }
 }
 
-/// GNSS Assistance ACK UBX-MGA-ACK
+/// Communication port information
 #[ubx_packet_recv]
-#[ubx(class = 0x13, id = 0x60, fixed_payload_len = 8)]
-struct MgaAck {
-    /// Type of acknowledgment: 0 -> not used, 1 -> accepted
-    ack_type: u8,
-
-    /// Version 0
+#[ubx(class = 0x0a, id = 0x36, max_payload_len = 248)] // 8 + 40 * 6
+struct MonComms {
+    /// Message version (0x00 for this version)
     version: u8,
 
-    /// Provides greater information on what the receiver chose to do with the message contents.
-    /// See [MsgAckInfoCode].
-    #[ubx(map_type = MsgAckInfoCode)]
-    info_code: u8,
+    n_ports: u8,
 
-    /// UBX message ID of the acknowledged message
-    msg_id: u8,
+    #[ubx(map_type = MonCommsTxErrors)]
+    tx_errors: u8,
 
-    /// The first 4 bytes of the acknowledged message's payload
-    msg_payload_start: [u8; 4],
-}
+    reserved0: u8,
 
-#[ubx_extend]
-#[ubx(from, rest_reserved)]
-#[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum MsgAckInfoCode {
-    Accepted = 0,
-    RejectedNoTime = 1,
-    RejectedBadVersion = 2,
-    RejectedBadSize = 3,
-    RejectedDBStoreFailed = 4,
-    RejectedNotReady = 5,
-    RejectedUnknownType = 6,
+    /// Protocol identifiers of the `msgs` counters of each port
+    /// (0 = UBX, 1 = NMEA, 2 = RTCM2, 5 = RTCM3, 6 = SPARTN, 0xff = not used)
+    prot_ids: [u8; 4],
+
+    #[ubx(
+        map_type = MonCommsIter,
+        from = MonCommsIter::new,
+        is_valid = MonCommsIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    ports: [u8; 0],
 }
 
 #[ubx_packet_recv]
-#[ubx(class = 0x13, id = 0x06, fixed_payload_len = 48)]
-struct MgaGloEph {
-    msg_type: u8,
-    version: u8,
-    sv_id: u8,
-    reserved1: u8,
-    ft: u8,
-    b: u8,
-    m: u8,
-    h: i8,
-    x: i32,
-    y: i32,
-    z: i32,
-    dx: i32,
-    dy: i32,
-    dz: i32,
-    ddx: i8,
-    ddy: i8,
-    ddz: i8,
-    tb: u8,
-    gamma: u16,
-    e: u8,
-    delta_tau: u8,
-    tau: i32,
-    reserved2: [u8; 4],
+#[ubx(class = 0x0a, id = 0x31, fixed_payload_len = 272)]
+struct MonSpanBlock {
+    /// Spectrum data (0.25 dB per unit), 256 bins from `center - span / 2` to `center + span / 2`
+    #[ubx(map_type = &[u8], from = core::convert::identity, get_as_ref)]
+    spectrum: [u8; 256],
+
+    /// Spectrum span (Hz)
+    span: u32,
+
+    /// Resolution of the spectrum (Hz)
+    res: u32,
+
+    /// Center of spectrum span (Hz)
+    center: u32,
+
+    /// Programmable gain amplifier (dB)
+    pga: u8,
+
+    reserved1: [u8; 3],
 }
 
-#[ubx_packet_recv]
-#[ubx(class = 0x13, id = 0x00, fixed_payload_len = 16)]
-struct MgaGpsIono {
-    /// Message type: 0x06 for this type
-    msg_type: u8,
-    /// Message version: 0x00 for this version
-    version: u8,
-    reserved1: [u8; 2],
-    /// Ionospheric parameter alpha0 [s]
-    #[ubx(map_type = f64, scale = 1.0)] // 2^-30
-    alpha0: i8,
-    /// Ionospheric parameter alpha1 [s/semi-circle]
-    #[ubx(map_type = f64, scale = 1.0)] // 2^-27
-    alpha1: i8,
-    /// Ionospheric parameter alpha1 [s/semi-circle^2]
-    #[ubx(map_type = f64, scale = 1.0)] // 2^-24
-    alpha2: i8,
-    /// Ionospheric parameter alpha1 [s/semi-circle^3]
-    #[ubx(map_type = f64, scale = 1.0)] // 2^-24
-    alpha3: i8,
-    /// Ionospheric parameter beta0 [s]
-    #[ubx(map_type = f64, scale = 1.0)] // 2^-11
-    beta0: i8,
-    /// Ionospheric parameter beta0 [s/semi-circle]
-    #[ubx(map_type = f64, scale = 1.0)] // 2^-14
-    beta1: i8,
-    /// Ionospheric parameter beta0 [s/semi-circle^2]
-    #[ubx(map_type = f64, scale = 1.0)] // 2^-16
-    beta2: i8,
-    /// Ionospheric parameter beta0 [s/semi-circle^3]
-    #[ubx(map_type = f64, scale = 1.0)] // 2^-16
-    beta3: i8,
-    reserved2: [u8; 4],
+impl<'a> MonSpanBlockRef<'a> {
+    /// Spectrum data in dB, with the frequency (Hz) of each bin
+    pub fn spectrum_db(&self) -> impl Iterator<Item = (f64, f32)> + 'a {
+        let span = f64::from(self.span());
+        let center = f64::from(self.center());
+        let spectrum: &'a [u8] = &self.0[..256];
+        let len = spectrum.len() as f64;
+        spectrum.iter().enumerate().map(move |(i, x)| {
+            let frequency = center + span * (i as f64 - len / 2.) / len;
+            (frequency, f32::from(*x) * 0.25)
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MonSpanIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> MonSpanIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len() % 272 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for MonSpanIter<'a> {
+    type Item = MonSpanBlockRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + 272];
+            self.offset += 272;
+            Some(MonSpanBlockRef(data))
+        } else {
+            None
+        }
+    }
 }
 
+/// Signal characteristics, a basic spectrum analyzer for each RF path
 #[ubx_packet_recv]
-#[ubx(class = 0x13, id = 0x00, fixed_payload_len = 68)]
-struct MgaGpsEph {
-    msg_type: u8,
+#[ubx(class = 0x0a, id = 0x31, max_payload_len = 548)] // 4 + 272 * 2
+struct MonSpan {
+    /// Message version (0x00 for this version)
     version: u8,
-    sv_id: u8,
-    reserved1: u8,
-    fit_interval: u8,
-    ura_index: u8,
-    sv_health: u8,
-    tgd: i8,
-    iodc: u16,
-    toc: u16,
-    reserved2: u8,
-    af2: i8,
-    af1: i16,
-    af0: i32,
-    crs: i16,
-    delta_n: i16,
-    m0: i32,
-    cuc: i16,
-    cus: i16,
-    e: u32,
-    sqrt_a: u32,
-    toe: u16,
-    cic: i16,
-    omega0: i32,
-    cis: i16,
-    crc: i16,
-    i0: i32,
-    omega: i32,
-    omega_dot: i32,
-    idot: i16,
-    reserved3: [u8; 2],
+
+    num_rf_blocks: u8,
+
+    reserved0: [u8; 2],
+
+    #[ubx(
+        map_type = MonSpanIter,
+        from = MonSpanIter::new,
+        is_valid = MonSpanIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    rf_blocks: [u8; 0],
 }
 
-/// Time pulse time data
+/// Current system performance information
 #[ubx_packet_recv]
-#[ubx(class = 0x0d, id = 0x01, fixed_payload_len = 16)]
-struct TimTp {
-    /// Time pulse time of week according to time base
-    tow_ms: u32,
-    /// Submillisecond part of towMS (scaling: 2^(-32))
-    tow_sub_ms: u32,
-    /// Quantization error of time pulse
-    q_err: i32,
-    /// Time pulse week number according to time base
-    week: u16,
-    /// Flags
-    #[ubx(map_type = TimTpFlags, from = TimTpFlags)]
-    flags: u8,
-    /// Time reference information
-    #[ubx(map_type = TimTpRefInfo, from = TimTpRefInfo)]
-    ref_info: u8,
-}
+#[ubx(class = 0x0a, id = 0x39, fixed_payload_len = 24)]
+struct MonSys {
+    /// Message version (0x01 for this version)
+    msg_ver: u8,
 
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
-pub struct TimTpFlags(u8);
+    #[ubx(map_type = MonSysBootType)]
+    boot_type: u8,
+
+    /// CPU load (%)
+    cpu_load: u8,
+
+    /// Maximum CPU load (%)
+    cpu_load_max: u8,
+
+    /// Memory usage (%)
+    mem_usage: u8,
+
+    /// Maximum memory usage (%)
+    mem_usage_max: u8,
+
+    /// I/O usage (%)
+    io_usage: u8,
+
+    /// Maximum I/O usage (%)
+    io_usage_max: u8,
+
+    /// Time since startup (s)
+    run_time: u32,
+
+    /// Number of notices since startup
+    notice_count: u16,
+
+    /// Number of warnings since startup
+    warn_count: u16,
+
+    /// Number of errors since startup
+    error_count: u16,
+
+    /// Temperature (°C)
+    temp_value: i8,
+
+    reserved0: [u8; 5],
+}
 
-impl TimTpFlags {
-    /// Time base
-    pub fn time_base(&self) -> TimTpTimeBase {
-        if self.0 & 0b1 == 0 {
-            TimTpTimeBase::Gnss
-        } else {
-            TimTpTimeBase::Utc
-        }
-    }
+/// Boot type of `MonSys`
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MonSysBootType {
+    Unknown = 0,
+    ColdStart = 1,
+    Watchdog = 2,
+    HardwareReset = 3,
+    HardwareBackup = 4,
+    SoftwareBackup = 5,
+    SoftwareReset = 6,
+    VioFail = 7,
+    VddXFail = 8,
+    VddRfFail = 9,
+    VCoreHighFail = 10,
+}
 
-    /// UTC availability
-    pub fn utc_available(&self) -> bool {
-        self.0 & 0b10 != 0
+/// Decodes little endian u16 values
+fn le_u16_array<const N: usize>(bytes: &[u8]) -> [u16; N] {
+    let mut values = [0; N];
+    for (value, chunk) in values.iter_mut().zip(bytes.chunks_exact(2)) {
+        *value = u16::from_le_bytes([chunk[0], chunk[1]]);
     }
+    values
+}
 
-    /// (T)RAIM state
-    ///
-    /// Returns `None` if unavailale.
-    pub fn raim_active(&self) -> Option<bool> {
-        match (self.0 >> 2) & 0b11 {
-            // Inactive.
-            0b01 => Some(false),
-            // Active.
-            0b10 => Some(true),
-            // Unavailable.
-            _ => None,
-        }
+/// Decodes little endian u32 values
+fn le_u32_array<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    let mut values = [0; N];
+    for (value, chunk) in values.iter_mut().zip(bytes.chunks_exact(4)) {
+        *value = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
     }
+    values
+}
 
-    /// Quantization error validity
-    pub fn q_err_valid(&self) -> bool {
-        self.0 & 0b10000 == 0
-    }
+/// Port Identifier Number (= 3 for USB port)
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone)]
+pub enum UsbPortId {
+    Usb = 3,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum TimTpTimeBase {
-    Gnss,
-    Utc,
+/// Port of a receiver, used to index the per-port statistics of
+/// `MonIo`, `MonRxBuf`, `MonTxBuf`, `MonMsgpp` and `MonComms`
+pub trait MonPort: Copy {
+    /// Port number: 0 for I2C, 1 and 2 for UART1/UART2, 3 for USB and 4 for SPI
+    fn port_number(self) -> u8;
 }
 
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
-pub struct TimTpRefInfo(u8);
+impl MonPort for I2cPortId {
+    fn port_number(self) -> u8 {
+        self.into_raw()
+    }
+}
 
-impl TimTpRefInfo {
-    /// GNSS reference information. Only valid if time base is GNSS.
-    pub fn time_ref_gnss(&self) -> Option<TimTpRefInfoTimeRefGnss> {
-        Some(match self.0 & 0b1111 {
-            0 => TimTpRefInfoTimeRefGnss::Gps,
-            1 => TimTpRefInfoTimeRefGnss::Glo,
-            2 => TimTpRefInfoTimeRefGnss::Bds,
-            3 => TimTpRefInfoTimeRefGnss::Gal,
-            4 => TimTpRefInfoTimeRefGnss::NavIc,
-            _ => return None,
-        })
+impl MonPort for UartPortId {
+    fn port_number(self) -> u8 {
+        self.into_raw()
     }
+}
 
-    /// UTC standard identifier. Only valid if time base is UTC.
-    pub fn utc_standard(&self) -> Option<TimTpRefInfoUtcStandard> {
-        Some(match self.0 >> 4 {
-            1 => TimTpRefInfoUtcStandard::Crl,
-            2 => TimTpRefInfoUtcStandard::Nist,
-            3 => TimTpRefInfoUtcStandard::Usno,
-            4 => TimTpRefInfoUtcStandard::Bipm,
-            5 => TimTpRefInfoUtcStandard::Eu,
-            6 => TimTpRefInfoUtcStandard::Su,
-            7 => TimTpRefInfoUtcStandard::Ntsc,
-            8 => TimTpRefInfoUtcStandard::Npli,
-            _ => return None,
-        })
+impl MonPort for SpiPortId {
+    fn port_number(self) -> u8 {
+        self.into_raw()
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum TimTpRefInfoTimeRefGnss {
-    Gps,
-    Glo,
-    Bds,
-    Gal,
-    NavIc,
+impl MonPort for UsbPortId {
+    fn port_number(self) -> u8 {
+        self.into_raw()
+    }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum TimTpRefInfoUtcStandard {
-    Crl,
-    Nist,
-    Usno,
-    Bipm,
-    Eu,
-    Su,
-    Ntsc,
-    Npli,
+impl<'a> MonCommsRef<'a> {
+    /// Statistics of `port`, if the receiver reports it
+    pub fn port<P: MonPort>(&self, port: P) -> Option<MonCommsPortRef<'a>> {
+        let port_number = port.port_number();
+        MonCommsIter::new(&self.0[8..]).find(|info| info.port_number() == port_number)
+    }
 }
 
-/// Time mark data
 #[ubx_packet_recv]
-#[ubx(class = 0x0d, id = 0x03, fixed_payload_len = 28)]
-struct TimTm2 {
-    /// Channel (i.e. EXTINT) upon which the pulse was measured
-    ch: u8,
-    /// Flags
-    #[ubx(map_type = TimTm2Flags, from = TimTm2Flags)]
-    flags: u8,
-    /// Rising edge counter
-    count: u16,
-    /// Week number of last rising edge
-    wn_r: u16,
-    /// Week number of last falling edge
-    wn_f: u16,
-    /// Tow of rising edge
-    tow_ms_r: u32,
-    /// Millisecond fraction of tow of rising edge in nanoseconds
-    tow_sub_ms_r: u32,
-    /// Tow of falling edge
-    tow_ms_f: u32,
-    /// Millisecond fraction of tow of falling edge in nanoseconds
-    tow_sub_ms_f: u32,
-    /// Accuracy estimate
-    acc_est: u32,
+#[ubx(class = 0x0a, id = 0x02, fixed_payload_len = 20)]
+struct MonIoPort {
+    /// Number of bytes ever received
+    rx_bytes: u32,
+
+    /// Number of bytes ever sent
+    tx_bytes: u32,
+
+    /// Number of 100 ms timeslots with parity errors
+    parity_errs: u16,
+
+    /// Number of 100 ms timeslots with framing errors
+    framing_errs: u16,
+
+    /// Number of 100 ms timeslots with overrun errors
+    overrun_errs: u16,
+
+    /// Number of 100 ms timeslots with break conditions
+    break_cond: u16,
+
+    reserved0: [u8; 4],
 }
 
 #[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
-pub struct TimTm2Flags(u8);
+pub struct MonIoIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
 
-impl TimTm2Flags {
-    pub fn mode(&self) -> TimTm2Mode {
-        if self.0 & 0b1 == 0 {
-            TimTm2Mode::Single
-        } else {
-            TimTm2Mode::Running
-        }
+impl<'a> MonIoIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
     }
 
-    pub fn run(&self) -> TimTm2Run {
-        if self.0 & 0b10 == 0 {
-            TimTm2Run::Armed
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len() % 20 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for MonIoIter<'a> {
+    type Item = MonIoPortRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + 20];
+            self.offset += 20;
+            Some(MonIoPortRef(data))
         } else {
-            TimTm2Run::Stopped
+            None
         }
     }
+}
 
-    pub fn new_falling_edge(&self) -> bool {
-        self.0 & 0b100 != 0
-    }
+/// I/O Subsystem Status, one block per port
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x02, max_payload_len = 120)] // 20 * 6
+struct MonIo {
+    #[ubx(
+        map_type = MonIoIter,
+        from = MonIoIter::new,
+        is_valid = MonIoIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    ports: [u8; 0],
+}
 
-    pub fn new_rising_edge(&self) -> bool {
-        self.0 & 0b10000000 != 0
+impl<'a> MonIoRef<'a> {
+    /// Statistics of `port`, if the receiver reports it
+    pub fn port<P: MonPort>(&self, port: P) -> Option<MonIoPortRef<'a>> {
+        let offset = usize::from(port.port_number()) * 20;
+        self.0.get(offset..offset + 20).map(MonIoPortRef)
     }
+}
 
-    pub fn time_base(&self) -> TimTm2TimeBase {
-        match self.0 & 0b11000 {
-            0 => TimTm2TimeBase::Receiver,
-            1 => TimTm2TimeBase::Gnss,
-            2 => TimTm2TimeBase::Utc,
-            _ => unreachable!(),
-        }
-    }
+/// Buffer usage of a single port, from `MonRxBuf` or `MonTxBuf`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonBufPort {
+    /// Number of bytes pending in the buffer
+    pub pending: u16,
 
-    /// UTC availability
-    pub fn utc_available(&self) -> bool {
-        self.0 & 0b100000 != 0
-    }
+    /// Maximum usage of the buffer during the last sysmon period (%)
+    pub usage: u8,
 
-    pub fn time_valid(&self) -> bool {
-        self.0 & 0b1000000 != 0
+    /// Maximum usage of the buffer (%)
+    pub peak_usage: u8,
+}
+
+/// Receiver Buffer Status
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x07, fixed_payload_len = 24)]
+struct MonRxBuf {
+    /// Number of bytes pending in receiver buffer for each port
+    #[ubx(map_type = [u16; 6], from = le_u16_array, get_as_ref)]
+    pending: [u8; 12],
+
+    /// Maximum usage receiver buffer during the last sysmon period for each port (%)
+    usage: [u8; 6],
+
+    /// Maximum usage receiver buffer for each port (%)
+    peak_usage: [u8; 6],
+}
+
+impl<'a> MonRxBufRef<'a> {
+    /// Receiver buffer usage of `port`
+    pub fn port<P: MonPort>(&self, port: P) -> MonBufPort {
+        let i = usize::from(port.port_number());
+        MonBufPort {
+            pending: self.pending()[i],
+            usage: self.usage()[i],
+            peak_usage: self.peak_usage()[i],
+        }
     }
 }
 
-pub enum TimTm2Mode {
-    Single,
-    Running,
+/// Transmitter Buffer Status
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x08, fixed_payload_len = 28)]
+struct MonTxBuf {
+    /// Number of bytes pending in transmitter buffer for each port
+    #[ubx(map_type = [u16; 6], from = le_u16_array, get_as_ref)]
+    pending: [u8; 12],
+
+    /// Maximum usage transmitter buffer during the last sysmon period for each port (%)
+    usage: [u8; 6],
+
+    /// Maximum usage transmitter buffer for each port (%)
+    peak_usage: [u8; 6],
+
+    /// Maximum usage of transmitter buffer during the last sysmon period for all ports (%)
+    t_usage: u8,
+
+    /// Maximum usage of transmitter buffer for all ports (%)
+    t_peak_usage: u8,
+
+    #[ubx(map_type = MonTxBufErrors)]
+    errors: u8,
+
+    reserved1: u8,
 }
 
-pub enum TimTm2Run {
-    Armed,
-    Stopped,
+impl<'a> MonTxBufRef<'a> {
+    /// Transmitter buffer usage of `port`
+    pub fn port<P: MonPort>(&self, port: P) -> MonBufPort {
+        let i = usize::from(port.port_number());
+        MonBufPort {
+            pending: self.pending()[i],
+            usage: self.usage()[i],
+            peak_usage: self.peak_usage()[i],
+        }
+    }
 }
 
-pub enum TimTm2TimeBase {
-    Receiver,
-    Gnss,
-    Utc,
+/// Error bitmask of `MonTxBuf`
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MonTxBufErrors(u8);
+
+impl MonTxBufErrors {
+    /// Buffer limit of `port` reached
+    pub fn limit<P: MonPort>(self, port: P) -> bool {
+        (self.0 >> port.port_number()) & 0x1 != 0
+    }
+
+    /// Memory allocation error
+    pub fn mem(self) -> bool {
+        (self.0 >> 6) & 0x1 != 0
+    }
+
+    /// Allocation error (TX buffer full)
+    pub fn alloc(self) -> bool {
+        (self.0 >> 7) & 0x1 != 0
+    }
+
+    pub const fn from(x: u8) -> Self {
+        Self(x)
+    }
 }
 
-#[ubx_packet_recv]
-#[ubx(class = 0x02, id = 0x15, max_payload_len = 8176)] // 16 + 255 * 32
-struct RxmRawx {
-    /// Measurement time of week in receiver local time approximately aligned to the GPS time system.
-    rcv_tow: f64,
-    /// GPS week number in receiver local time.
-    week: u16,
-    /// GPS leap seconds (GPS-UTC)
-    leap_s: i8,
-    /// Number of measurements to follow
-    num_meas: u8,
-    /// Receiver tracking status bitfield
-    #[ubx(map_type = RecStatFlags)]
-    rec_stat: u8,
-    /// Message version
-    version: u8,
-    reserved1: [u8; 2],
-    /// Extended software information strings
-    #[ubx(
-        map_type = RxmRawxInfoIter,
-        from = RxmRawxInfoIter::new,
-        may_fail,
-        is_valid = RxmRawxInfoIter::is_valid,
-    )]
-    measurements: [u8; 0],
+impl fmt::Debug for MonTxBufErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MonTxBufErrors")
+            .field("limit", &(self.0 & 0x3f))
+            .field("mem", &self.mem())
+            .field("alloc", &self.alloc())
+            .finish()
+    }
 }
 
-#[ubx_extend_bitflags]
-#[ubx(from, into_raw, rest_reserved)]
-bitflags! {
-    /// `CfgNavX5Params2` parameters bitmask
-    #[derive(Default)]
-    pub struct RecStatFlags: u8 {
-        /// Leap seconds have been determined
-        const LEAP_SEC = 0x1;
-        /// Clock reset applied.
-        const CLK_RESET = 0x2;
+/// Message counts of a single port, from `MonMsgpp`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonMsgppPort {
+    /// Number of successfully parsed messages for each protocol,
+    /// indexed like the bits of `InProtoMask` (0 = UBX, 1 = NMEA, 2 = RTCM2, 5 = RTCM3)
+    pub msgs: [u16; 8],
+
+    /// Number of skipped bytes
+    pub skipped: u32,
+}
+
+/// Message Parse and Process Status
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x06, fixed_payload_len = 120)]
+struct MonMsgpp {
+    /// Number of successfully parsed messages for each protocol on port0
+    #[ubx(map_type = [u16; 8], from = le_u16_array, get_as_ref)]
+    msg1: [u8; 16],
+
+    /// Number of successfully parsed messages for each protocol on port1
+    #[ubx(map_type = [u16; 8], from = le_u16_array, get_as_ref)]
+    msg2: [u8; 16],
+
+    /// Number of successfully parsed messages for each protocol on port2
+    #[ubx(map_type = [u16; 8], from = le_u16_array, get_as_ref)]
+    msg3: [u8; 16],
+
+    /// Number of successfully parsed messages for each protocol on port3
+    #[ubx(map_type = [u16; 8], from = le_u16_array, get_as_ref)]
+    msg4: [u8; 16],
+
+    /// Number of successfully parsed messages for each protocol on port4
+    #[ubx(map_type = [u16; 8], from = le_u16_array, get_as_ref)]
+    msg5: [u8; 16],
+
+    /// Number of successfully parsed messages for each protocol on port5
+    #[ubx(map_type = [u16; 8], from = le_u16_array, get_as_ref)]
+    msg6: [u8; 16],
+
+    /// Number of skipped bytes for each port
+    #[ubx(map_type = [u32; 6], from = le_u32_array, get_as_ref)]
+    skipped: [u8; 24],
+}
+
+impl<'a> MonMsgppRef<'a> {
+    /// Message counts of `port`
+    pub fn port<P: MonPort>(&self, port: P) -> MonMsgppPort {
+        let i = usize::from(port.port_number());
+        MonMsgppPort {
+            msgs: le_u16_array(&self.0[i * 16..(i + 1) * 16]),
+            skipped: self.skipped()[i],
+        }
     }
 }
 
-/// Hardware status
+/// Extended Hardware Status
 #[ubx_packet_recv]
-#[ubx(class = 0x0a, id = 0x09, fixed_payload_len = 60)]
-struct MonHw {
-    pin_sel: u32,
-    pin_bank: u32,
-    pin_dir: u32,
-    pin_val: u32,
-    noise_per_ms: u16,
-    agc_cnt: u16,
-    #[ubx(map_type = AntennaStatus)]
-    a_status: u8,
-    #[ubx(map_type = AntennaPower)]
-    a_power: u8,
-    flags: u8,
-    reserved1: u8,
-    used_mask: u32,
-    vp: [u8; 17],
-    jam_ind: u8,
-    reserved2: [u8; 2],
-    pin_irq: u32,
-    pull_h: u32,
-    pull_l: u32,
+#[ubx(class = 0x0a, id = 0x0b, fixed_payload_len = 28)]
+struct MonHw2 {
+    /// Imbalance of I-part of complex signal, scaled (-128 = max. negative imbalance, 127 = max. positive imbalance)
+    ofs_i: i8,
+
+    /// Magnitude of I-part of complex signal, scaled (0 = no signal, 255 = max. magnitude)
+    mag_i: u8,
+
+    /// Imbalance of Q-part of complex signal, scaled (-128 = max. negative imbalance, 127 = max. positive imbalance)
+    ofs_q: i8,
+
+    /// Magnitude of Q-part of complex signal, scaled (0 = no signal, 255 = max. magnitude)
+    mag_q: u8,
+
+    #[ubx(map_type = MonHw2ConfigSource)]
+    cfg_source: u8,
+
+    reserved0: [u8; 3],
+
+    /// Low-level configuration (obsolete for ROM5 and later)
+    low_lev_cfg: u32,
+
+    reserved1: [u8; 8],
+
+    /// POST status word
+    post_status: u32,
+
+    reserved2: [u8; 4],
 }
 
+/// Source of the low-level configuration of `MonHw2`
 #[ubx_extend]
 #[ubx(from, rest_reserved)]
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum AntennaStatus {
-    Init = 0,
-    DontKnow = 1,
-    Ok = 2,
-    Short = 3,
-    Open = 4,
+pub enum MonHw2ConfigSource {
+    Flash = 102,
+    Otp = 111,
+    ConfigPins = 112,
+    Rom = 114,
 }
 
-/// GNSS status monitoring,
-/// gives currently selected constellations
+/// Receiver Status Information
 #[ubx_packet_recv]
-#[ubx(class = 0x0a, id = 0x28, fixed_payload_len = 8)]
-struct MonGnss {
-    /// Message version: 0x00
-    version: u8,
-    /// Supported major constellations bit mask
-    #[ubx(map_type = MonGnssConstellMask)]
-    supported: u8,
-    /// Default major GNSS constellations bit mask
-    #[ubx(map_type = MonGnssConstellMask)]
-    default: u8,
-    /// Currently enabled major constellations bit mask
-    #[ubx(map_type = MonGnssConstellMask)]
-    enabled: u8,
-    /// Maximum number of concurent Major GNSS
-    /// that can be supported by this receiver
-    simultaneous: u8,
-    reserved1: [u8; 3],
+#[ubx(class = 0x0a, id = 0x21, fixed_payload_len = 1)]
+struct MonRxr {
+    #[ubx(map_type = MonRxrFlags)]
+    flags: u8,
 }
 
 #[ubx_extend_bitflags]
-#[ubx(from, into_raw, rest_reserved)]
+#[ubx(from, rest_reserved)]
 bitflags! {
-    /// Selected / available Constellation Mask
-    #[derive(Default)]
-    pub struct MonGnssConstellMask: u8 {
-        /// GPS constellation
-        const GPS = 0x01;
-        /// GLO constellation
-        const GLO = 0x02;
-        /// BDC constellation
-        const BDC = 0x04;
-        /// GAL constellation
-        const GAL = 0x08;
+    /// Flags of `MonRxr`
+    pub struct MonRxrFlags: u8 {
+        /// Not in backup mode
+        const AWAKE = 0x01;
     }
 }
 
-#[ubx_extend]
-#[ubx(from, rest_reserved)]
-#[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum AntennaPower {
-    Off = 0,
-    On = 1,
-    DontKnow = 2,
-}
-
 #[derive(Debug, Clone)]
 pub struct MonVerExtensionIter<'a> {
     data: &'a [u8],
@@ -3147,6 +5988,12 @@ pub struct EsfMeasData {
     pub data_field: u32,
 }
 
+impl EsfMeasData {
+    pub fn sensor_type(&self) -> EsfSensorType {
+        EsfSensorType::from(self.data_type)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EsfMeasDataIter<'a>(core::slice::ChunksExact<'a, u8>);
 
@@ -3193,6 +6040,12 @@ pub struct EsfRawData {
     pub sensor_time_tag: u32,
 }
 
+impl EsfRawData {
+    pub fn sensor_type(&self) -> EsfSensorType {
+        EsfSensorType::from(self.data_type)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EsfRawDataIter<'a>(core::slice::ChunksExact<'a, u8>);
 
@@ -3262,6 +6115,364 @@ bitflags! {
     }
 }
 
+/// Type of an external sensor, or of the data it measured
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EsfSensorType {
+    NoData,
+    /// Z-axis gyroscope angular rate
+    GyroZ,
+    FrontLeftWheelTicks,
+    FrontRightWheelTicks,
+    RearLeftWheelTicks,
+    RearRightWheelTicks,
+    /// Single tick from a speed sensor
+    SpeedTicks,
+    Speed,
+    GyroTemperature,
+    /// Y-axis gyroscope angular rate
+    GyroY,
+    /// X-axis gyroscope angular rate
+    GyroX,
+    /// X-axis accelerometer specific force
+    AccX,
+    /// Y-axis accelerometer specific force
+    AccY,
+    /// Z-axis accelerometer specific force
+    AccZ,
+    Unknown(u8),
+}
+
+impl From<u8> for EsfSensorType {
+    fn from(x: u8) -> Self {
+        match x {
+            0 => EsfSensorType::NoData,
+            5 => EsfSensorType::GyroZ,
+            6 => EsfSensorType::FrontLeftWheelTicks,
+            7 => EsfSensorType::FrontRightWheelTicks,
+            8 => EsfSensorType::RearLeftWheelTicks,
+            9 => EsfSensorType::RearRightWheelTicks,
+            10 => EsfSensorType::SpeedTicks,
+            11 => EsfSensorType::Speed,
+            12 => EsfSensorType::GyroTemperature,
+            13 => EsfSensorType::GyroY,
+            14 => EsfSensorType::GyroX,
+            16 => EsfSensorType::AccX,
+            17 => EsfSensorType::AccY,
+            18 => EsfSensorType::AccZ,
+            _ => EsfSensorType::Unknown(x),
+        }
+    }
+}
+
+/// External sensor fusion status
+#[ubx_packet_recv]
+#[ubx(class = 0x10, id = 0x10, max_payload_len = 1036)] // 16 + 255 * 4
+struct EsfStatus {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+    /// Message version, should be 2
+    version: u8,
+    reserved1: [u8; 7],
+    #[ubx(map_type = EsfFusionMode)]
+    fusion_mode: u8,
+    reserved2: [u8; 2],
+    /// Number of sensors
+    num_sens: u8,
+    #[ubx(
+        map_type = EsfStatusSensorIter,
+        from = EsfStatusSensorIter::new,
+        is_valid = EsfStatusSensorIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    sensors: [u8; 0],
+}
+
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EsfFusionMode {
+    Initialization = 0,
+    Fusion = 1,
+    /// Fusion is temporarily disabled, e.g. because of invalid sensor data
+    Suspended = 2,
+    Disabled = 3,
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x10, id = 0x10, fixed_payload_len = 4)]
+struct EsfStatusSensor {
+    #[ubx(map_type = EsfSensorStatus)]
+    sens_status: u16,
+    /// Observation frequency (Hz)
+    freq: u8,
+    #[ubx(map_type = EsfSensorFaults)]
+    faults: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct EsfStatusSensorIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> EsfStatusSensorIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn is_valid(bytes: &'a [u8]) -> bool {
+        bytes.len() % 4 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for EsfStatusSensorIter<'a> {
+    type Item = EsfStatusSensorRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + 4];
+            self.offset += 4;
+            Some(EsfStatusSensorRef(data))
+        } else {
+            None
+        }
+    }
+}
+
+/// Status of a sensor in `EsfStatus`
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EsfSensorStatus(u16);
+
+impl EsfSensorStatus {
+    pub fn sensor_type(self) -> EsfSensorType {
+        EsfSensorType::from((self.0 & 0x3f) as u8)
+    }
+
+    /// Sensor data is used for the current sensor fusion solution
+    pub fn used(self) -> bool {
+        (self.0 >> 6) & 0x1 != 0
+    }
+
+    /// Sensor is set up (configured), but not necessarily used
+    pub fn ready(self) -> bool {
+        (self.0 >> 7) & 0x1 != 0
+    }
+
+    pub fn calib_status(self) -> EsfCalibStatus {
+        match (self.0 >> 8) & 0x3 {
+            0 => EsfCalibStatus::NotCalibrated,
+            1 => EsfCalibStatus::Calibrating,
+            _ => EsfCalibStatus::Calibrated,
+        }
+    }
+
+    pub fn time_status(self) -> EsfTimeStatus {
+        match (self.0 >> 10) & 0x3 {
+            0 => EsfTimeStatus::NoData,
+            1 => EsfTimeStatus::FirstByteReception,
+            2 => EsfTimeStatus::EventInput,
+            _ => EsfTimeStatus::TimeTag,
+        }
+    }
+
+    pub const fn from(x: u16) -> Self {
+        Self(x)
+    }
+}
+
+impl fmt::Debug for EsfSensorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EsfSensorStatus")
+            .field("sensor_type", &self.sensor_type())
+            .field("used", &self.used())
+            .field("ready", &self.ready())
+            .field("calib_status", &self.calib_status())
+            .field("time_status", &self.time_status())
+            .finish()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EsfCalibStatus {
+    NotCalibrated,
+    Calibrating,
+    Calibrated,
+}
+
+/// How the measurements of a sensor are time tagged
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EsfTimeStatus {
+    NoData,
+    /// On reception of the first byte of the measurement
+    FirstByteReception,
+    /// On an event input
+    EventInput,
+    /// Time tag provided with the data
+    TimeTag,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    pub struct EsfSensorFaults: u8 {
+        const BAD_MEAS = 0x1;
+        const BAD_T_TAG = 0x2;
+        const MISSING_MEAS = 0x4;
+        const NOISY_MEAS = 0x8;
+    }
+}
+
+/// IMU mount alignment
+#[ubx_packet_recv]
+#[ubx(class = 0x10, id = 0x14, fixed_payload_len = 16)]
+struct EsfAlg {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+    /// Message version, should be 1
+    version: u8,
+    #[ubx(map_type = EsfAlgFlags)]
+    flags: u8,
+    #[ubx(map_type = EsfAlgErrors)]
+    errors: u8,
+    reserved1: u8,
+    /// IMU mount yaw angle (degrees)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    yaw: u32,
+    /// IMU mount pitch angle (degrees)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    pitch: i16,
+    /// IMU mount roll angle (degrees)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    roll: i16,
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EsfAlgFlags(u8);
+
+impl EsfAlgFlags {
+    /// Automatic IMU mount alignment is enabled
+    pub fn auto_mnt_alg_on(self) -> bool {
+        self.0 & 0x1 != 0
+    }
+
+    pub fn status(self) -> EsfAlgStatus {
+        match (self.0 >> 1) & 0x7 {
+            0 => EsfAlgStatus::UserDefined,
+            1 => EsfAlgStatus::RollPitchAlignmentOngoing,
+            2 => EsfAlgStatus::RollPitchYawAlignmentOngoing,
+            3 => EsfAlgStatus::CoarseAlignment,
+            4 => EsfAlgStatus::FineAlignment,
+            x => EsfAlgStatus::Unknown(x),
+        }
+    }
+
+    pub const fn from(x: u8) -> Self {
+        Self(x)
+    }
+}
+
+impl fmt::Debug for EsfAlgFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EsfAlgFlags")
+            .field("auto_mnt_alg_on", &self.auto_mnt_alg_on())
+            .field("status", &self.status())
+            .finish()
+    }
+}
+
+/// Status of the IMU mount alignment
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EsfAlgStatus {
+    /// User-defined (fixed) angles are used
+    UserDefined,
+    RollPitchAlignmentOngoing,
+    RollPitchYawAlignmentOngoing,
+    /// Coarse alignment is used, with an initial accuracy
+    CoarseAlignment,
+    /// Fine alignment is used, the angles are refined over time
+    FineAlignment,
+    Unknown(u8),
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    pub struct EsfAlgErrors: u8 {
+        /// IMU-mount tilt (roll and/or pitch) alignment error
+        const TILT_ALG_ERROR = 0x1;
+        /// IMU-mount yaw alignment error
+        const YAW_ALG_ERROR = 0x2;
+        /// IMU-mount misalignment Euler angle singularity error, the yaw
+        /// angle can't be decoupled from the roll angle
+        const ANGLE_ERROR = 0x4;
+    }
+}
+
+/// Calibrated sensor measurements
+#[ubx_packet_recv]
+#[ubx(class = 0x10, id = 0x04, max_payload_len = 1240)]
+struct EsfCal {
+    /// Sensor time tag
+    s_ttag: u32,
+    #[ubx(
+        map_type = EsfCalDataIter,
+        from = EsfCalDataIter::new,
+        is_valid = EsfCalDataIter::is_valid,
+        may_fail,
+    )]
+    data: [u8; 0],
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EsfCalData {
+    pub data_type: u8,
+    pub data_field: u32,
+}
+
+impl EsfCalData {
+    pub fn sensor_type(&self) -> EsfSensorType {
+        EsfSensorType::from(self.data_type)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EsfCalDataIter<'a>(core::slice::ChunksExact<'a, u8>);
+
+impl<'a> EsfCalDataIter<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes.chunks_exact(8))
+    }
+
+    fn is_valid(bytes: &'a [u8]) -> bool {
+        bytes.len() % 8 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for EsfCalDataIter<'a> {
+    type Item = EsfCalData;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Each measurement is followed by 4 reserved bytes
+        let chunk = self.0.next()?;
+        let data = u32::from_le_bytes(chunk[0..4].try_into().unwrap());
+        Some(EsfCalData {
+            data_type: ((data >> 24) & 0x3F).try_into().unwrap(),
+            data_field: data & 0xFFFFFF,
+        })
+    }
+}
+
 #[ubx_packet_recv]
 #[ubx(class = 0x28, id = 0x00, fixed_payload_len = 72)]
 struct HnrPvt {
@@ -3388,33 +6599,659 @@ struct RxmSfrbx {
 #[derive(Debug, Clone)]
 pub struct DwrdIter<'a>(core::slice::ChunksExact<'a, u8>);
 
-impl<'a> DwrdIter<'a> {
-    fn new(bytes: &'a [u8]) -> Self {
-        DwrdIter(bytes.chunks_exact(4))
+impl<'a> DwrdIter<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        DwrdIter(bytes.chunks_exact(4))
+    }
+
+    fn is_valid(bytes: &'a [u8]) -> bool {
+        bytes.len() % 4 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for DwrdIter<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+/// Satellite measurements for RRLP, used for assisted positioning
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x14, max_payload_len = 3104)] // 44 + 127 * 24
+struct RxmMeasx {
+    /// Message version, should be 1
+    version: u8,
+    reserved1: [u8; 3],
+
+    /// GPS measurement reference time of week (ms)
+    gps_tow: u32,
+
+    /// GLONASS measurement reference time of week (ms)
+    glo_tow: u32,
+
+    /// BeiDou measurement reference time of week (ms)
+    bds_tow: u32,
+    reserved2: [u8; 4],
+
+    /// QZSS measurement reference time of week (ms)
+    qzss_tow: u32,
+
+    /// GPS measurement reference time accuracy (ms)
+    #[ubx(map_type = f64, scale = 0.0625)]
+    gps_tow_acc: u16,
+
+    /// GLONASS measurement reference time accuracy (ms)
+    #[ubx(map_type = f64, scale = 0.0625)]
+    glo_tow_acc: u16,
+
+    /// BeiDou measurement reference time accuracy (ms)
+    #[ubx(map_type = f64, scale = 0.0625)]
+    bds_tow_acc: u16,
+    reserved3: [u8; 2],
+
+    /// QZSS measurement reference time accuracy (ms)
+    #[ubx(map_type = f64, scale = 0.0625)]
+    qzss_tow_acc: u16,
+
+    /// Number of satellites
+    num_sv: u8,
+
+    #[ubx(map_type = RxmMeasxTowSet)]
+    flags: u8,
+    reserved4: [u8; 8],
+
+    #[ubx(
+        map_type = RxmMeasxIter,
+        from = RxmMeasxIter::new,
+        is_valid = RxmMeasxIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    svs: [u8; 0],
+}
+
+/// How the time of week fields of `RxmMeasx` are set
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RxmMeasxTowSet {
+    /// Time of week values are not set
+    No,
+    /// Time of week values are set
+    Yes,
+    /// Time of week values are set, and should be used
+    SetAndUsed,
+    Unknown(u8),
+}
+
+impl From<u8> for RxmMeasxTowSet {
+    fn from(flags: u8) -> Self {
+        match flags & 0x3 {
+            0 => RxmMeasxTowSet::No,
+            1 => RxmMeasxTowSet::Yes,
+            2 => RxmMeasxTowSet::SetAndUsed,
+            x => RxmMeasxTowSet::Unknown(x),
+        }
+    }
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x14, fixed_payload_len = 24)]
+struct RxmMeasxSv {
+    #[ubx(map_type = GnssId)]
+    gnss_id: u8,
+
+    /// Satellite identifier
+    sv_id: u8,
+
+    /// Carrier-to-noise density ratio (dBHz)
+    c_no: u8,
+
+    #[ubx(map_type = MultipathIndicator)]
+    mpath_indic: u8,
+
+    /// Doppler measurement (m/s)
+    #[ubx(map_type = f64, scale = 0.04)]
+    doppler_ms: i32,
+
+    /// Doppler measurement (Hz)
+    #[ubx(map_type = f64, scale = 0.2)]
+    doppler_hz: i32,
+
+    /// Whole value of the code phase measurement (chips)
+    whole_chips: u16,
+
+    /// Fractional value of the code phase measurement, in 1/1024 chips
+    frac_chips: u16,
+
+    /// Code phase (ms)
+    #[ubx(map_type = f64, scale = 4.76837158203125e-7)] // 2^-21
+    code_phase: u32,
+
+    /// Integer (part of) the code phase (ms)
+    int_code_phase: u8,
+
+    /// Pseudorange RMS error index
+    pseu_range_rms_err: u8,
+    reserved5: [u8; 2],
+}
+
+/// Multipath level of a `RxmMeasxSv` measurement
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MultipathIndicator {
+    NotMeasured,
+    Low,
+    Medium,
+    High,
+    Unknown(u8),
+}
+
+impl From<u8> for MultipathIndicator {
+    fn from(x: u8) -> Self {
+        match x {
+            0 => MultipathIndicator::NotMeasured,
+            1 => MultipathIndicator::Low,
+            2 => MultipathIndicator::Medium,
+            3 => MultipathIndicator::High,
+            _ => MultipathIndicator::Unknown(x),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RxmMeasxIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> RxmMeasxIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn is_valid(bytes: &'a [u8]) -> bool {
+        bytes.len() % 24 == 0
+    }
+}
+
+impl<'a> core::iter::Iterator for RxmMeasxIter<'a> {
+    type Item = RxmMeasxSvRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset < self.data.len() {
+            let data = &self.data[self.offset..self.offset + 24];
+            self.offset += 24;
+            Some(RxmMeasxSvRef(data))
+        } else {
+            None
+        }
+    }
+}
+
+/// Galileo SAR short return link message
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x59, fixed_payload_len = 16)]
+struct RxmRlmShort {
+    /// Message version, should be 0
+    version: u8,
+    /// Message type, should be 1 for short messages
+    msg_type: u8,
+    /// Identifier of the satellite which sent the message
+    sv_id: u8,
+    reserved0: u8,
+    /// Beacon identifier (60 bits), with bytes ordered from earliest to latest received
+    beacon: [u8; 8],
+    /// Message code (4 bits)
+    message: u8,
+    /// Parameters (16 bits), with bytes ordered from earliest to latest received
+    params: [u8; 2],
+    reserved1: u8,
+}
+
+/// Galileo SAR long return link message
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x59, fixed_payload_len = 28)]
+struct RxmRlmLong {
+    /// Message version, should be 0
+    version: u8,
+    /// Message type, should be 2 for long messages
+    msg_type: u8,
+    /// Identifier of the satellite which sent the message
+    sv_id: u8,
+    reserved0: u8,
+    /// Beacon identifier (60 bits), with bytes ordered from earliest to latest received
+    beacon: [u8; 8],
+    /// Message code (4 bits)
+    message: u8,
+    /// Parameters (96 bits), with bytes ordered from earliest to latest received
+    params: [u8; 12],
+    reserved1: [u8; 3],
+}
+
+/// Differential correction input status
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x34, fixed_payload_len = 12)]
+struct RxmCor {
+    /// Message version, should be 1
+    version: u8,
+
+    /// Energy per bit to noise power spectral density ratio (dB), only valid
+    /// for corrections received over L-band
+    #[ubx(map_type = f32, scale = 0.125)]
+    ebno: u8,
+    reserved0: [u8; 2],
+
+    #[ubx(map_type = RxmCorStatusInfo)]
+    status_info: u32,
+
+    /// Type of the correction message, if `status_info().msg_type_valid()`
+    msg_type: u16,
+
+    /// Subtype of the correction message, if `status_info().msg_sub_type_valid()`
+    msg_sub_type: u16,
+}
+
+/// Status of a correction message in `RxmCor`
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RxmCorStatusInfo(u32);
+
+impl RxmCorStatusInfo {
+    pub fn protocol(self) -> CorrectionProtocol {
+        CorrectionProtocol::from((self.0 & 0x1f) as u8)
+    }
+
+    /// Whether the message contained errors, `None` if unknown
+    pub fn is_erroneous(self) -> Option<bool> {
+        Self::tri_state(self.0 >> 5)
+    }
+
+    /// Whether the message was used by the receiver, `None` if unknown
+    pub fn is_used(self) -> Option<bool> {
+        Self::tri_state(self.0 >> 7)
+    }
+
+    /// Identifier of the correction stream
+    pub fn correction_id(self) -> u16 {
+        (self.0 >> 9) as u16
+    }
+
+    /// `msg_type` is valid
+    pub fn msg_type_valid(self) -> bool {
+        (self.0 >> 25) & 0x1 != 0
+    }
+
+    /// `msg_sub_type` is valid
+    pub fn msg_sub_type_valid(self) -> bool {
+        (self.0 >> 26) & 0x1 != 0
+    }
+
+    /// The message was received from an external input, instead of a
+    /// receiver-internal source
+    pub fn msg_input_handle(self) -> bool {
+        (self.0 >> 27) & 0x1 != 0
+    }
+
+    /// Whether the message was encrypted, `None` if unknown
+    pub fn is_encrypted(self) -> Option<bool> {
+        Self::tri_state(self.0 >> 28)
+    }
+
+    /// Whether the message was successfully decrypted, `None` if unknown
+    pub fn is_decrypted(self) -> Option<bool> {
+        Self::tri_state(self.0 >> 30)
+    }
+
+    /// Two bit field where 1 means false, 2 means true and anything else is unknown
+    fn tri_state(bits: u32) -> Option<bool> {
+        match bits & 0x3 {
+            1 => Some(false),
+            2 => Some(true),
+            _ => None,
+        }
+    }
+
+    pub const fn from(x: u32) -> Self {
+        Self(x)
+    }
+}
+
+impl fmt::Debug for RxmCorStatusInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RxmCorStatusInfo")
+            .field("protocol", &self.protocol())
+            .field("is_erroneous", &self.is_erroneous())
+            .field("is_used", &self.is_used())
+            .field("correction_id", &self.correction_id())
+            .field("msg_type_valid", &self.msg_type_valid())
+            .field("msg_sub_type_valid", &self.msg_sub_type_valid())
+            .field("msg_input_handle", &self.msg_input_handle())
+            .field("is_encrypted", &self.is_encrypted())
+            .field("is_decrypted", &self.is_decrypted())
+            .finish()
+    }
+}
+
+/// Protocol of a correction message
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CorrectionProtocol {
+    Rtcm3,
+    Spartn,
+    /// SPARTN from the L-band point to multipoint service
+    Pmp,
+    QzssL6,
+    Unknown(u8),
+}
+
+impl From<u8> for CorrectionProtocol {
+    fn from(x: u8) -> Self {
+        match x {
+            1 => CorrectionProtocol::Rtcm3,
+            2 => CorrectionProtocol::Spartn,
+            29 => CorrectionProtocol::Pmp,
+            30 => CorrectionProtocol::QzssL6,
+            _ => CorrectionProtocol::Unknown(x),
+        }
+    }
+}
+
+/// Request the receiver to enter backup or inactive mode
+#[ubx_packet_send]
+#[ubx(
+    class = 0x02,
+    id = 0x41,
+    fixed_payload_len = 16,
+    flags = "default_for_builder"
+)]
+struct RxmPmreq {
+    /// Message version, should be 0
+    version: u8,
+    reserved1: [u8; 3],
+    /// Duration of the requested task in ms, 0 for infinite duration
+    duration: u32,
+    #[ubx(map_type = RxmPmreqFlags)]
+    flags: u32,
+    /// Sources which wake up the receiver before the end of `duration`
+    #[ubx(map_type = RxmPmreqWakeupSources)]
+    wakeup_sources: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    #[derive(Default)]
+    pub struct RxmPmreqFlags: u32 {
+        /// The receiver goes into backup mode for `duration` ms
+        const BACKUP = 0x2;
+        /// Force the receiver into backup mode, even if some interfaces are
+        /// still active (protocol version 18 and later)
+        const FORCE = 0x4;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    #[derive(Default)]
+    pub struct RxmPmreqWakeupSources: u32 {
+        /// Wake up on an edge of the UART RX pin
+        const UART_RX = 0x8;
+        /// Wake up on an edge of the EXTINT0 pin
+        const EXTINT0 = 0x20;
+        /// Wake up on an edge of the EXTINT1 pin
+        const EXTINT1 = 0x40;
+        /// Wake up on an edge of the SPI CS pin
+        const SPI_CS = 0x80;
+    }
+}
+
+/// Status of a received SPARTN message
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x33, fixed_payload_len = 8)]
+struct RxmSpartn {
+    /// Message version, should be 1
+    version: u8,
+
+    /// Whether the message was used by the receiver, `None` if unknown
+    #[ubx(map_type = Option<bool>, from = RxmSpartn::msg_used, alias = msg_used)]
+    flags: u8,
+
+    /// SPARTN message subtype
+    sub_type: u16,
+    reserved0: [u8; 2],
+
+    /// SPARTN message type
+    msg_type: u16,
+}
+
+impl RxmSpartn {
+    fn msg_used(flags: u8) -> Option<bool> {
+        match (flags >> 1) & 0x3 {
+            1 => Some(false),
+            2 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+/// Dynamic keys used to decrypt SPARTN messages.
+///
+/// Received as the response to a poll, see `RxmSpartnKeyBuilder` to send keys to the receiver.
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x36, max_payload_len = 2108)] // 4 + 8 * (8 + 255)
+struct RxmSpartnKey {
+    /// Message version, should be 1
+    version: u8,
+    /// Number of keys and reserved bytes, followed by the key descriptions
+    /// and then the keys themselves
+    #[ubx(
+        map_type = RxmSpartnKeyIter,
+        from = RxmSpartnKeyIter::new,
+        is_valid = RxmSpartnKeyIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    keys: [u8; 0],
+}
+
+impl<'a> RxmSpartnKeyRef<'a> {
+    /// Number of keys
+    pub fn num_keys(&self) -> u8 {
+        self.0[1]
+    }
+}
+
+/// Dynamic SPARTN key with the GPS time from which it is valid
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SpartnKey<'a> {
+    /// GPS week number from which the key is valid
+    pub valid_from_wno: u16,
+    /// GPS time of week (s) from which the key is valid
+    pub valid_from_tow: u32,
+    pub key: &'a [u8],
+}
+
+impl<'a> SpartnKey<'a> {
+    const HEADER_SIZE: usize = 8;
+
+    fn extend_header_to<T>(&self, buf: &mut T)
+    where
+        T: core::iter::Extend<u8>,
+    {
+        let mut header = [0; Self::HEADER_SIZE];
+        header[1] = self.key.len() as u8;
+        header[2..4].copy_from_slice(&self.valid_from_wno.to_le_bytes());
+        header[4..8].copy_from_slice(&self.valid_from_tow.to_le_bytes());
+        for b in header.iter() {
+            buf.extend(core::iter::once(*b));
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RxmSpartnKeyIter<'a> {
+    headers: core::slice::ChunksExact<'a, u8>,
+    keys: &'a [u8],
+}
+
+impl<'a> RxmSpartnKeyIter<'a> {
+    /// Size of `num_keys` and the reserved bytes which precede the key descriptions
+    const PREFIX_SIZE: usize = 3;
+
+    fn new(data: &'a [u8]) -> Self {
+        let headers_len = usize::from(data[0]) * SpartnKey::HEADER_SIZE;
+        let (headers, keys) = data[Self::PREFIX_SIZE..].split_at(headers_len);
+        Self {
+            headers: headers.chunks_exact(SpartnKey::HEADER_SIZE),
+            keys,
+        }
+    }
+
+    fn is_valid(data: &'a [u8]) -> bool {
+        if data.len() < Self::PREFIX_SIZE {
+            return false;
+        }
+        let headers_len = usize::from(data[0]) * SpartnKey::HEADER_SIZE;
+        match data[Self::PREFIX_SIZE..].len().checked_sub(headers_len) {
+            Some(keys_len) => {
+                let headers = &data[Self::PREFIX_SIZE..Self::PREFIX_SIZE + headers_len];
+                let expect: usize = headers
+                    .chunks_exact(SpartnKey::HEADER_SIZE)
+                    .map(|header| usize::from(header[1]))
+                    .sum();
+                expect == keys_len
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a> core::iter::Iterator for RxmSpartnKeyIter<'a> {
+    type Item = SpartnKey<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self.headers.next()?;
+        let (key, keys) = self.keys.split_at(usize::from(header[1]));
+        self.keys = keys;
+        Some(SpartnKey {
+            valid_from_wno: u16::from_le_bytes([header[2], header[3]]),
+            valid_from_tow: u32::from_le_bytes(header[4..8].try_into().unwrap()),
+            key,
+        })
+    }
+}
+
+/// Sets the dynamic keys used to decrypt SPARTN messages.
+///
+/// All key descriptions are written before the keys themselves, so unlike
+/// the other builders this one is not generated by `ubx_packet_send`.
+///
+/// There can be up to 255 keys of up to 255 bytes each, within the
+/// `RxmSpartnKey::MAX_PAYLOAD_LEN` bytes of the payload.
+pub struct RxmSpartnKeyBuilder<'a> {
+    /// Message version, should be 1
+    pub version: u8,
+    pub keys: &'a [SpartnKey<'a>],
+}
+
+impl<'a> RxmSpartnKeyBuilder<'a> {
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn into_packet_vec(self) -> Result<Vec<u8>, BuilderError> {
+        let mut vec = Vec::new();
+        self.extend_to(&mut vec)?;
+        Ok(vec)
+    }
+
+    /// Writes the packet to `out`, fails without writing anything if the
+    /// keys don't fit in the packet
+    #[inline]
+    pub fn extend_to<T>(self, out: &mut T) -> Result<(), BuilderError>
+    where
+        T: core::iter::Extend<u8> + core::ops::DerefMut<Target = [u8]>,
+    {
+        let payload_len = self.payload_len()?;
+
+        let start = out.len();
+        let len_bytes = payload_len.to_le_bytes();
+        let header = [
+            SYNC_CHAR_1,
+            SYNC_CHAR_2,
+            RxmSpartnKey::CLASS,
+            RxmSpartnKey::ID,
+            len_bytes[0],
+            len_bytes[1],
+            self.version,
+            self.keys.len() as u8,
+            0,
+            0,
+        ];
+        for b in header.iter() {
+            out.extend(core::iter::once(*b));
+        }
+        for key in self.keys {
+            key.extend_header_to(out);
+        }
+        for key in self.keys {
+            for b in key.key.iter() {
+                out.extend(core::iter::once(*b));
+            }
+        }
+
+        let (ck_a, ck_b) = ubx_checksum(&out[start + 2..]);
+        out.extend(core::iter::once(ck_a));
+        out.extend(core::iter::once(ck_b));
+        Ok(())
     }
 
-    fn is_valid(bytes: &'a [u8]) -> bool {
-        bytes.len() % 4 == 0
+    fn payload_len(&self) -> Result<u16, BuilderError> {
+        check_len("keys", self.keys.len(), u8::MAX.into())?;
+        let mut len = 4;
+        for key in self.keys {
+            check_len("key", key.key.len(), u8::MAX.into())?;
+            len += SpartnKey::HEADER_SIZE + key.key.len();
+        }
+        check_len("payload", len, RxmSpartnKey::MAX_PAYLOAD_LEN.into())?;
+        Ok(len as u16)
     }
 }
 
-impl<'a> core::iter::Iterator for DwrdIter<'a> {
-    type Item = u32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0
-            .next()
-            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+/// Checks the length of a variable length part of a packet
+fn check_len(field: &'static str, len: usize, max: usize) -> Result<(), BuilderError> {
+    if len > max {
+        return Err(BuilderError::TooLong { field, len, max });
     }
+    Ok(())
 }
 
+/// Velocity Solution in ECEF
 #[ubx_packet_recv]
 #[ubx(class = 0x01, id = 0x11, fixed_payload_len = 20)]
 struct NavVelECEF {
+    /// GPS Millisecond Time of Week
     itow: u32,
+
+    /// ECEF X velocity (m/s)
+    #[ubx(map_type = f64, scale = 1e-2)]
     ecef_vx: i32,
+
+    /// ECEF Y velocity (m/s)
+    #[ubx(map_type = f64, scale = 1e-2)]
     ecef_vy: i32,
-    ecef_vz: u32,
+
+    /// ECEF Z velocity (m/s)
+    #[ubx(map_type = f64, scale = 1e-2)]
+    ecef_vz: i32,
+
+    /// Speed Accuracy Estimate (m/s)
+    #[ubx(map_type = f64, scale = 1e-2)]
     s_acc: u32,
 }
 
@@ -3542,19 +7379,507 @@ impl<'a> core::iter::Iterator for RxmRawxInfoIter<'a> {
     }
 }
 
+/// Create a log file
+#[ubx_packet_send]
+#[ubx(class = 0x21, id = 0x07, fixed_payload_len = 8)]
+struct LogCreate {
+    /// Message version, should be 0
+    version: u8,
+    #[ubx(map_type = LogCfg)]
+    log_cfg: u8,
+    reserved1: u8,
+    #[ubx(map_type = LogSize)]
+    log_size: u8,
+    /// Maximum size of the log in bytes, only used with `LogSize::UserDefined`
+    user_defined_size: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    #[derive(Default)]
+    pub struct LogCfg: u8 {
+        /// The log is a circular buffer, the oldest entries are overwritten
+        /// when it is full
+        const CIRCULAR = 0x1;
+    }
+}
+
+/// Size of the log created with `LogCreate`
+#[repr(u8)]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogSize {
+    /// Maximum safe size, leaving enough space in the file store for the receiver
+    MaximumSafe = 0,
+    Minimum = 1,
+    UserDefined = 2,
+}
+
+impl LogSize {
+    const fn into_raw(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Erase the log file
+#[ubx_packet_send]
+#[ubx(class = 0x21, id = 0x03, fixed_payload_len = 0)]
+struct LogErase {}
+
+/// Log information, response to a `LogInfo` poll
+#[ubx_packet_recv]
+#[ubx(class = 0x21, id = 0x08, fixed_payload_len = 48)]
+struct LogInfo {
+    /// Message version, should be 1
+    version: u8,
+    reserved1: [u8; 3],
+    /// Capacity of the file store in bytes
+    filestore_capacity: u32,
+    reserved2: [u8; 8],
+    /// Maximum size the log is allowed to grow to, in bytes
+    current_max_log_size: u32,
+    /// Approximate amount of space in the log used so far, in bytes
+    current_log_size: u32,
+    /// Number of entries in the log
+    entry_count: u32,
+    /// Oldest timestamp in the log, all date and time fields are 0 if there are no
+    /// time-stamped entries
+    oldest_year: u16,
+    oldest_month: u8,
+    oldest_day: u8,
+    oldest_hour: u8,
+    oldest_minute: u8,
+    oldest_second: u8,
+    reserved3: u8,
+    /// Newest timestamp in the log
+    newest_year: u16,
+    newest_month: u8,
+    newest_day: u8,
+    newest_hour: u8,
+    newest_minute: u8,
+    newest_second: u8,
+    reserved4: u8,
+    #[ubx(map_type = LogInfoStatus)]
+    status: u8,
+    reserved5: [u8; 3],
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    pub struct LogInfoStatus: u8 {
+        /// Log entry recording is enabled
+        const RECORDING = 0x8;
+        /// No log file exists
+        const INACTIVE = 0x10;
+        /// The log is a circular buffer
+        const CIRCULAR = 0x20;
+    }
+}
+
+/// Store an arbitrary string in the log
+///
+/// Not generated by `ubx_packet_send`, the payload is the string itself.
+pub struct LogStringBuilder<'a> {
+    /// Up to `LogRetrieveString::MAX_BYTE_COUNT` bytes to store, they don't
+    /// need to be valid UTF-8
+    pub bytes: &'a [u8],
+}
+
+impl<'a> LogStringBuilder<'a> {
+    pub const CLASS: u8 = 0x21;
+    pub const ID: u8 = 0x04;
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn into_packet_vec(self) -> Result<Vec<u8>, BuilderError> {
+        let mut vec = Vec::new();
+        self.extend_to(&mut vec)?;
+        Ok(vec)
+    }
+
+    /// Writes the packet to `out`, fails without writing anything if there
+    /// are too many bytes
+    #[inline]
+    pub fn extend_to<T>(self, out: &mut T) -> Result<(), BuilderError>
+    where
+        T: core::iter::Extend<u8> + core::ops::DerefMut<Target = [u8]>,
+    {
+        check_len("bytes", self.bytes.len(), LogRetrieveString::MAX_BYTE_COUNT)?;
+
+        let start = out.len();
+        let len_bytes = (self.bytes.len() as u16).to_le_bytes();
+        let header = [
+            SYNC_CHAR_1,
+            SYNC_CHAR_2,
+            Self::CLASS,
+            Self::ID,
+            len_bytes[0],
+            len_bytes[1],
+        ];
+        for b in header.iter().chain(self.bytes) {
+            out.extend(core::iter::once(*b));
+        }
+
+        let (ck_a, ck_b) = ubx_checksum(&out[start + 2..]);
+        out.extend(core::iter::once(ck_a));
+        out.extend(core::iter::once(ck_b));
+        Ok(())
+    }
+}
+
+/// Find the index of the first log entry at or after the given UTC time,
+/// the receiver answers with `LogFindTimeResponse`
+#[ubx_packet_send]
+#[ubx(
+    class = 0x21,
+    id = 0x0e,
+    fixed_payload_len = 12,
+    flags = "default_for_builder"
+)]
+struct LogFindTime {
+    /// Message version, should be 0
+    version: u8,
+    /// Message type, should be 0 for requests
+    request_type: u8,
+    reserved1: [u8; 2],
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    reserved2: u8,
+}
+
+impl LogFindTimeBuilder {
+    /// Request for the first entry at or after `time`
+    pub fn at(time: &DateTime<Utc>) -> Self {
+        Self {
+            year: time.year() as u16,
+            month: time.month() as u8,
+            day: time.day() as u8,
+            hour: time.hour() as u8,
+            minute: time.minute() as u8,
+            second: time.second() as u8,
+            ..Self::default()
+        }
+    }
+}
+
+/// Response to `LogFindTime`
+#[ubx_packet_recv]
+#[ubx(class = 0x21, id = 0x0e, fixed_payload_len = 8)]
+struct LogFindTimeResponse {
+    /// Message version, should be 1
+    version: u8,
+    /// Message type, should be 1 for responses
+    response_type: u8,
+    reserved1: [u8; 2],
+    /// Index of the first log entry at or after the requested time, 0xffffffff
+    /// if there is none
+    entry_number: u32,
+}
+
+/// Request log entries, the receiver answers with one `LogRetrievePos`,
+/// `LogRetrievePosExtra` or `LogRetrieveString` per entry
+#[ubx_packet_send]
+#[ubx(
+    class = 0x21,
+    id = 0x09,
+    fixed_payload_len = 12,
+    flags = "default_for_builder"
+)]
+struct LogRetrieve {
+    /// Index of the first entry to retrieve, entries are numbered from 0
+    start_number: u32,
+    /// Number of entries to retrieve, at most `LogRetrieve::MAX_ENTRY_COUNT`
+    entry_count: u32,
+    /// Message version, should be 0
+    version: u8,
+    reserved1: [u8; 3],
+}
+
+impl LogRetrieve {
+    /// Maximum number of entries which can be retrieved with one request
+    pub const MAX_ENTRY_COUNT: u32 = 256;
+}
+
+/// Position fix log entry
+#[ubx_packet_recv]
+#[ubx(class = 0x21, id = 0x0b, fixed_payload_len = 40)]
+struct LogRetrievePos {
+    /// Index of the entry in the log
+    entry_index: u32,
+    #[ubx(map_type = f64, scale = 1e-7, alias = lon_degrees)]
+    lon: i32,
+    #[ubx(map_type = f64, scale = 1e-7, alias = lat_degrees)]
+    lat: i32,
+    /// Height above mean sea level (m)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    h_msl: i32,
+    /// Horizontal accuracy estimate (m)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    h_acc: u32,
+    /// Ground speed (m/s)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    g_speed: u32,
+    /// Heading of motion (degrees)
+    #[ubx(map_type = f64, scale = 1e-5, alias = heading_degrees)]
+    heading: u32,
+    /// Message version, should be 0
+    version: u8,
+    #[ubx(map_type = LogFixType)]
+    fix_type: u8,
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    reserved1: u8,
+    /// Number of satellites used in the position fix
+    num_sv: u8,
+    reserved2: u8,
+}
+
+/// Fix type of a `LogRetrievePos` entry
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LogFixType {
+    NoFix = 0,
+    Fix2D = 2,
+    Fix3D = 3,
+    GpsPlusDeadReckoning = 4,
+}
+
+/// Odometer log entry
+#[ubx_packet_recv]
+#[ubx(class = 0x21, id = 0x0f, fixed_payload_len = 32)]
+struct LogRetrievePosExtra {
+    /// Index of the entry in the log
+    entry_index: u32,
+    /// Message version, should be 0
+    version: u8,
+    reserved1: u8,
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    reserved2: [u8; 3],
+    /// Odometer distance traveled since the last reset (m)
+    distance: u32,
+    reserved3: [u8; 12],
+}
+
+/// String log entry, stored with `LogStringBuilder`
+#[ubx_packet_recv]
+#[ubx(class = 0x21, id = 0x0d, max_payload_len = 272)] // 16 + 256
+struct LogRetrieveString {
+    /// Index of the entry in the log
+    entry_index: u32,
+    /// Message version, should be 0
+    version: u8,
+    reserved1: u8,
+    /// Time at which the string was logged, all date and time fields are 0
+    /// if the time was unknown
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    reserved2: u8,
+    /// Size of the string in bytes, followed by the string bytes, see also
+    /// `byte_count()` and `as_str()`
+    #[ubx(
+        map_type = core::slice::Iter<'a, u8>,
+        from = LogRetrieveString::bytes,
+        is_valid = LogRetrieveString::is_valid_bytes,
+        may_fail,
+        get_as_ref,
+    )]
+    bytes: [u8; 0],
+}
+
+impl LogRetrieveString {
+    /// Maximum size of the string
+    pub const MAX_BYTE_COUNT: usize = 256;
+
+    fn bytes(data: &[u8]) -> core::slice::Iter<'_, u8> {
+        data[2..].iter()
+    }
+
+    fn is_valid_bytes(data: &[u8]) -> bool {
+        if data.len() < 2 {
+            return false;
+        }
+        let byte_count = usize::from(u16::from_le_bytes([data[0], data[1]]));
+        byte_count <= Self::MAX_BYTE_COUNT && byte_count == data.len() - 2
+    }
+}
+
+impl<'a> LogRetrieveStringRef<'a> {
+    /// Size of the string in bytes
+    pub fn byte_count(&self) -> u16 {
+        u16::from_le_bytes([self.0[14], self.0[15]])
+    }
+
+    /// The string, if it is valid UTF-8
+    pub fn as_str(&self) -> Option<&'a str> {
+        let end = 16 + usize::from(self.byte_count());
+        core::str::from_utf8(&self.0[16..end]).ok()
+    }
+}
+
+/// Request batched data, the receiver answers with one `LogBatch` per
+/// batch entry
+#[ubx_packet_send]
+#[ubx(
+    class = 0x21,
+    id = 0x10,
+    fixed_payload_len = 4,
+    flags = "default_for_builder"
+)]
+struct LogRetrieveBatch {
+    /// Message version, should be 0
+    version: u8,
+    #[ubx(map_type = LogRetrieveBatchFlags)]
+    flags: u8,
+    reserved1: [u8; 2],
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    #[derive(Default)]
+    pub struct LogRetrieveBatchFlags: u8 {
+        /// Send `MonBatch` before the batched data
+        const SEND_MON_FIRST = 0x1;
+    }
+}
+
+/// Batched data
+#[ubx_packet_recv]
+#[ubx(class = 0x21, id = 0x11, fixed_payload_len = 100)]
+struct LogBatch {
+    /// Message version, should be 0
+    version: u8,
+    #[ubx(map_type = LogBatchContentValid)]
+    content_valid: u8,
+    /// Message counter, incremented for each sent `LogBatch`
+    msg_cnt: u16,
+    /// GPS Millisecond Time of Week
+    itow: u32,
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    min: u8,
+    sec: u8,
+    /// Validity flags, same as in `NavPosVelTime`
+    valid: u8,
+    /// Time accuracy estimate (ns)
+    t_acc: u32,
+    /// Fraction of second (ns)
+    frac_sec: i32,
+    #[ubx(map_type = GpsFix)]
+    fix_type: u8,
+    #[ubx(map_type = NavPosVelTimeFlags)]
+    flags: u8,
+    #[ubx(map_type = NavPosVelTimeFlags2)]
+    flags2: u8,
+    num_sv: u8,
+    #[ubx(map_type = f64, scale = 1e-7, alias = lon_degrees)]
+    lon: i32,
+    #[ubx(map_type = f64, scale = 1e-7, alias = lat_degrees)]
+    lat: i32,
+    /// Height above ellipsoid (m)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    height: i32,
+    /// Height above mean sea level (m)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    h_msl: i32,
+    /// Horizontal accuracy estimate (m)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    h_acc: u32,
+    /// Vertical accuracy estimate (m)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    v_acc: u32,
+    /// North velocity (m/s)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    vel_n: i32,
+    /// East velocity (m/s)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    vel_e: i32,
+    /// Down velocity (m/s)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    vel_d: i32,
+    /// Ground speed (m/s)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    g_speed: i32,
+    /// Heading of motion (degrees)
+    #[ubx(map_type = f64, scale = 1e-5, alias = head_mot_degrees)]
+    head_mot: i32,
+    /// Speed accuracy estimate (m/s)
+    #[ubx(map_type = f64, scale = 1e-3)]
+    s_acc: u32,
+    /// Heading accuracy estimate (degrees)
+    #[ubx(map_type = f64, scale = 1e-5)]
+    head_acc: u32,
+    /// Position DOP
+    #[ubx(map_type = f64, scale = 1e-2)]
+    p_dop: u16,
+    reserved1: [u8; 2],
+    /// Ground distance since the last odometer reset (m), if `EXTRA_ODO` is valid
+    distance: u32,
+    /// Total cumulative ground distance (m), if `EXTRA_ODO` is valid
+    total_distance: u32,
+    /// Ground distance accuracy (m), if `EXTRA_ODO` is valid
+    distance_std: u32,
+    reserved2: [u8; 4],
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Optional content of `LogBatch`
+    pub struct LogBatchContentValid: u8 {
+        /// Extra PVT information (`h_msl`, velocities, accuracies, ...) is valid
+        const EXTRA_PVT = 0x1;
+        /// Odometer data is valid
+        const EXTRA_ODO = 0x2;
+    }
+}
+
 define_recv_packets!(
     enum PacketRef {
         _ = UbxUnknownPacketRef,
         NavPosLlh,
+        NavPosECEF,
+        NavHpPosECEF,
         NavStatus,
         NavDop,
         NavPosVelTime,
         NavSolution,
         NavVelNed,
         NavHpPosLlh,
+        NavRelPosNedV0,
+        NavRelPosNedV1,
         NavTimeUTC,
+        NavTimeGps,
+        NavTimeGal,
+        NavTimeGlo,
+        NavTimeBds,
         NavTimeLs,
         NavSat,
+        NavSbas,
+        NavSig,
         NavEoe,
         NavOdo,
         CfgOdo,
@@ -3571,9 +7896,15 @@ define_recv_packets!(
         CfgPrtUart,
         CfgNav5,
         CfgAnt,
+        CfgGnss,
+        CfgPms,
+        CfgPm2,
+        CfgSbas,
+        CfgDgnss,
         CfgTmode2,
         CfgTmode3,
         CfgTp5,
+        CfgValGet,
         InfError,
         InfWarning,
         InfNotice,
@@ -3585,6 +7916,16 @@ define_recv_packets!(
         MonVer,
         MonGnss,
         MonHw,
+        MonRf,
+        MonComms,
+        MonSpan,
+        MonSys,
+        MonIo,
+        MonRxBuf,
+        MonTxBuf,
+        MonMsgpp,
+        MonHw2,
+        MonRxr,
         RxmRtcm,
         EsfMeas,
         EsfIns,
@@ -3594,7 +7935,23 @@ define_recv_packets!(
         NavVelECEF,
         MgaGpsEPH,
         RxmSfrbx,
+        RxmMeasx,
+        RxmRlmShort,
+        RxmRlmLong,
+        RxmCor,
+        RxmSpartn,
+        RxmSpartnKey,
         EsfRaw,
+        EsfStatus,
+        EsfAlg,
+        EsfCal,
+        LogInfo,
+        LogFindTimeResponse,
+        LogRetrievePos,
+        LogRetrievePosExtra,
+        LogRetrieveString,
+        LogBatch,
         TimSvin,
+        NavSvin,
     }
 );
@@ -17,6 +17,21 @@ pub struct Position {
     pub alt: f64,
 }
 
+/// Represents a position in the Earth-Centered, Earth-Fixed frame, can be constructed
+/// from NavPosECEF, NavHpPosECEF, NavSolution and NavSvin packets.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct PositionECEF {
+    /// X coordinate in meters
+    pub x: f64,
+
+    /// Y coordinate in meters
+    pub y: f64,
+
+    /// Z coordinate in meters
+    pub z: f64,
+}
+
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Velocity {
@@ -47,6 +62,46 @@ impl<'a> From<&NavHpPosLlhRef<'a>> for Position {
     }
 }
 
+impl<'a> From<&NavPosECEFRef<'a>> for PositionECEF {
+    fn from(packet: &NavPosECEFRef<'a>) -> Self {
+        PositionECEF {
+            x: packet.ecef_x(),
+            y: packet.ecef_y(),
+            z: packet.ecef_z(),
+        }
+    }
+}
+
+impl<'a> From<&NavHpPosECEFRef<'a>> for PositionECEF {
+    fn from(packet: &NavHpPosECEFRef<'a>) -> Self {
+        PositionECEF {
+            x: packet.ecef_x_meters(),
+            y: packet.ecef_y_meters(),
+            z: packet.ecef_z_meters(),
+        }
+    }
+}
+
+impl<'a> From<&NavSvinRef<'a>> for PositionECEF {
+    fn from(packet: &NavSvinRef<'a>) -> Self {
+        PositionECEF {
+            x: packet.mean_x_meters(),
+            y: packet.mean_y_meters(),
+            z: packet.mean_z_meters(),
+        }
+    }
+}
+
+impl<'a> From<&NavSolutionRef<'a>> for PositionECEF {
+    fn from(packet: &NavSolutionRef<'a>) -> Self {
+        PositionECEF {
+            x: packet.ecef_x(),
+            y: packet.ecef_y(),
+            z: packet.ecef_z(),
+        }
+    }
+}
+
 impl<'a> From<&NavVelNedRef<'a>> for Velocity {
     fn from(packet: &NavVelNedRef<'a>) -> Self {
         Velocity {
@@ -66,6 +121,25 @@ impl<'a> From<&NavPosVelTimeRef<'a>> for Position {
     }
 }
 
+impl<'a> From<&LogRetrievePosRef<'a>> for Position {
+    fn from(packet: &LogRetrievePosRef<'a>) -> Self {
+        Position {
+            lon: packet.lon_degrees(),
+            lat: packet.lat_degrees(),
+            alt: packet.h_msl(),
+        }
+    }
+}
+
+impl<'a> From<&LogRetrievePosRef<'a>> for Velocity {
+    fn from(packet: &LogRetrievePosRef<'a>) -> Self {
+        Velocity {
+            speed: packet.g_speed(),
+            heading: packet.heading_degrees(),
+        }
+    }
+}
+
 impl<'a> From<&NavPosVelTimeRef<'a>> for Velocity {
     fn from(packet: &NavPosVelTimeRef<'a>) -> Self {
         Velocity {
@@ -102,6 +176,240 @@ impl<'a> TryFrom<&NavPosVelTimeRef<'a>> for DateTime<Utc> {
     }
 }
 
+/// GNSS time scale of a `GnssTime`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeScale {
+    /// GPS time, week 0 starts on 1980-01-06
+    Gps,
+    /// Galileo System Time, week 0 starts on 1999-08-22
+    Galileo,
+    /// BeiDou Time, week 0 starts on 2006-01-01
+    BeiDou,
+}
+
+impl TimeScale {
+    /// Start of week 0, in the time scale itself
+    fn epoch(self) -> NaiveDateTime {
+        let (year, month, day) = match self {
+            TimeScale::Gps => (1980, 1, 6),
+            TimeScale::Galileo => (1999, 8, 22),
+            TimeScale::BeiDou => (2006, 1, 1),
+        };
+        NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .unwrap()
+    }
+}
+
+/// Represents a time as week number and time of week of a GNSS time scale,
+/// can be constructed from NavTimeGps, NavTimeGal and NavTimeBds packets
+/// with a valid week number and time of week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GnssTime {
+    pub time_scale: TimeScale,
+
+    /// Week number since the start of the time scale
+    pub week: u16,
+
+    /// Time of week in seconds, range 0..604799
+    pub tow: u32,
+
+    /// Fraction of the second in nanoseconds, range 0..999999999
+    pub nanos: u32,
+
+    /// Offset of the time scale to UTC in seconds, if the receiver knows it
+    pub leap_seconds: Option<i8>,
+}
+
+impl GnssTime {
+    /// `tow_nanos` may be out of the week by the fractional part of the time of week
+    fn new(
+        time_scale: TimeScale,
+        week: i16,
+        tow_nanos: i64,
+        leap_seconds: Option<i8>,
+    ) -> Result<Self, DateTimeError> {
+        const WEEK_NANOS: i64 = 604_800 * 1_000_000_000;
+        let week = i64::from(week) + tow_nanos.div_euclid(WEEK_NANOS);
+        let tow_nanos = tow_nanos.rem_euclid(WEEK_NANOS);
+        Ok(GnssTime {
+            time_scale,
+            week: u16::try_from(week).map_err(|_| DateTimeError::InvalidDate)?,
+            tow: (tow_nanos / 1_000_000_000) as u32,
+            nanos: (tow_nanos % 1_000_000_000) as u32,
+            leap_seconds,
+        })
+    }
+}
+
+impl<'a> TryFrom<&NavTimeGpsRef<'a>> for GnssTime {
+    type Error = DateTimeError;
+    fn try_from(sol: &NavTimeGpsRef<'a>) -> Result<Self, Self::Error> {
+        let valid = sol.valid();
+        if !valid.contains(NavTimeGpsFlags::WEEK_VALID) {
+            return Err(DateTimeError::InvalidDate);
+        }
+        if !valid.contains(NavTimeGpsFlags::TOW_VALID) {
+            return Err(DateTimeError::InvalidTime);
+        }
+        let leap_seconds = if valid.contains(NavTimeGpsFlags::LEAP_S_VALID) {
+            Some(sol.leap_s())
+        } else {
+            None
+        };
+        GnssTime::new(
+            TimeScale::Gps,
+            sol.week(),
+            i64::from(sol.itow()) * 1_000_000 + i64::from(sol.ftow()),
+            leap_seconds,
+        )
+    }
+}
+
+impl<'a> TryFrom<&NavTimeGalRef<'a>> for GnssTime {
+    type Error = DateTimeError;
+    fn try_from(sol: &NavTimeGalRef<'a>) -> Result<Self, Self::Error> {
+        let valid = sol.valid();
+        if !valid.contains(NavTimeGalFlags::GAL_WNO_VALID) {
+            return Err(DateTimeError::InvalidDate);
+        }
+        if !valid.contains(NavTimeGalFlags::GAL_TOW_VALID) {
+            return Err(DateTimeError::InvalidTime);
+        }
+        let leap_seconds = if valid.contains(NavTimeGalFlags::LEAP_S_VALID) {
+            Some(sol.leap_s())
+        } else {
+            None
+        };
+        GnssTime::new(
+            TimeScale::Galileo,
+            sol.gal_wno(),
+            i64::from(sol.gal_tow()) * 1_000_000_000 + i64::from(sol.fgal_tow()),
+            leap_seconds,
+        )
+    }
+}
+
+impl<'a> TryFrom<&NavTimeBdsRef<'a>> for GnssTime {
+    type Error = DateTimeError;
+    fn try_from(sol: &NavTimeBdsRef<'a>) -> Result<Self, Self::Error> {
+        let valid = sol.valid();
+        if !valid.contains(NavTimeBdsFlags::WEEK_VALID) {
+            return Err(DateTimeError::InvalidDate);
+        }
+        if !valid.contains(NavTimeBdsFlags::SOW_VALID) {
+            return Err(DateTimeError::InvalidTime);
+        }
+        let leap_seconds = if valid.contains(NavTimeBdsFlags::LEAP_S_VALID) {
+            Some(sol.leap_s())
+        } else {
+            None
+        };
+        GnssTime::new(
+            TimeScale::BeiDou,
+            sol.week(),
+            i64::from(sol.sow()) * 1_000_000_000 + i64::from(sol.fsow()),
+            leap_seconds,
+        )
+    }
+}
+
+impl TryFrom<&GnssTime> for DateTime<Utc> {
+    type Error = DateTimeError;
+    fn try_from(time: &GnssTime) -> Result<Self, Self::Error> {
+        let leap_seconds = time.leap_seconds.ok_or(DateTimeError::UnknownLeapSeconds)?;
+        let dt = time.time_scale.epoch()
+            + chrono::Duration::weeks(i64::from(time.week))
+            + chrono::Duration::seconds(i64::from(time.tow) - i64::from(leap_seconds))
+            + chrono::Duration::nanoseconds(i64::from(time.nanos));
+
+        Ok(Utc.from_utc_datetime(&dt))
+    }
+}
+
+impl<'a> TryFrom<&NavTimeGloRef<'a>> for DateTime<Utc> {
+    type Error = DateTimeError;
+    /// GLONASS time is UTC(SU) + 3 hours, so no leap seconds are involved
+    fn try_from(sol: &NavTimeGloRef<'a>) -> Result<Self, Self::Error> {
+        let valid = sol.valid();
+        if !valid.contains(NavTimeGloFlags::DATE_VALID) || sol.n4() == 0 || sol.nt() == 0 {
+            return Err(DateTimeError::InvalidDate);
+        }
+        if !valid.contains(NavTimeGloFlags::TOD_VALID) {
+            return Err(DateTimeError::InvalidTime);
+        }
+        let days = (i64::from(sol.n4()) - 1) * 1461 + i64::from(sol.nt()) - 1;
+        let dt = NaiveDate::from_ymd_opt(1996, 1, 1)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .unwrap()
+            + chrono::Duration::days(days)
+            + chrono::Duration::seconds(i64::from(sol.tod()) - 3 * 3600)
+            + chrono::Duration::nanoseconds(i64::from(sol.ftod()));
+
+        Ok(Utc.from_utc_datetime(&dt))
+    }
+}
+
+/// Date and time of a log entry, all fields are 0 if the receiver didn't know the time
+fn log_entry_datetime(
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+) -> Result<DateTime<Utc>, DateTimeError> {
+    let date = NaiveDate::from_ymd_opt(i32::from(year), u32::from(month), u32::from(day))
+        .ok_or(DateTimeError::InvalidDate)?;
+    let time = NaiveTime::from_hms_opt(u32::from(hour), u32::from(minute), u32::from(second))
+        .ok_or(DateTimeError::InvalidTime)?;
+    Ok(Utc.from_utc_datetime(&NaiveDateTime::new(date, time)))
+}
+
+impl<'a> TryFrom<&LogRetrievePosRef<'a>> for DateTime<Utc> {
+    type Error = DateTimeError;
+    fn try_from(entry: &LogRetrievePosRef<'a>) -> Result<Self, Self::Error> {
+        log_entry_datetime(
+            entry.year(),
+            entry.month(),
+            entry.day(),
+            entry.hour(),
+            entry.minute(),
+            entry.second(),
+        )
+    }
+}
+
+impl<'a> TryFrom<&LogRetrievePosExtraRef<'a>> for DateTime<Utc> {
+    type Error = DateTimeError;
+    fn try_from(entry: &LogRetrievePosExtraRef<'a>) -> Result<Self, Self::Error> {
+        log_entry_datetime(
+            entry.year(),
+            entry.month(),
+            entry.day(),
+            entry.hour(),
+            entry.minute(),
+            entry.second(),
+        )
+    }
+}
+
+impl<'a> TryFrom<&LogRetrieveStringRef<'a>> for DateTime<Utc> {
+    type Error = DateTimeError;
+    fn try_from(entry: &LogRetrieveStringRef<'a>) -> Result<Self, Self::Error> {
+        log_entry_datetime(
+            entry.year(),
+            entry.month(),
+            entry.day(),
+            entry.hour(),
+            entry.minute(),
+            entry.second(),
+        )
+    }
+}
+
 pub(crate) struct FieldIter<I>(pub(crate) I);
 
 impl<I> fmt::Debug for FieldIter<I>
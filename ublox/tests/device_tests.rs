@@ -0,0 +1,276 @@
+#![cfg(feature = "std")]
+
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+use ublox::{
+    CfgMsgAllPortsBuilder, CfgNav5, CfgNav5Builder, Device, DeviceError, LogEntry, NavPosLlh,
+    PacketOwned,
+};
+
+static ACK_ACK_CFG_MSG: [u8; 10] = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x6, 0x1, 0xf, 0x38];
+static ACK_NAK_CFG_MSG: [u8; 10] = [0xb5, 0x62, 0x5, 0x0, 0x2, 0x0, 0x6, 0x1, 0xe, 0x33];
+// AckAck for CfgNav5, not the packet we are waiting for in these tests
+static ACK_ACK_CFG_NAV5: [u8; 10] = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x6, 0x24, 0x32, 0x5b];
+
+/// Transport which answers each write with the next scripted reply,
+/// and times out on reads when there is nothing to read
+struct MockTransport {
+    replies: VecDeque<Vec<u8>>,
+    rx: VecDeque<u8>,
+    written: Vec<Vec<u8>>,
+}
+
+impl MockTransport {
+    fn new(replies: Vec<Vec<u8>>) -> Self {
+        Self {
+            replies: replies.into(),
+            rx: VecDeque::new(),
+            written: Vec::new(),
+        }
+    }
+}
+
+impl Read for MockTransport {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.rx.is_empty() {
+            thread::sleep(Duration::from_millis(1));
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "timeout"));
+        }
+        let n = buf.len().min(self.rx.len());
+        for (dst, src) in buf.iter_mut().zip(self.rx.drain(..n)) {
+            *dst = src;
+        }
+        Ok(n)
+    }
+}
+
+impl Write for MockTransport {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.written.push(buf.to_vec());
+        if let Some(reply) = self.replies.pop_front() {
+            self.rx.extend(reply);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn cfg_msg_packet() -> [u8; 16] {
+    CfgMsgAllPortsBuilder::set_rate_for::<NavPosLlh>([0, 1, 0, 0, 0, 0]).into_packet_bytes()
+}
+
+fn new_device(replies: Vec<Vec<u8>>) -> Device<MockTransport> {
+    let mut device = Device::new(MockTransport::new(replies));
+    device.set_response_timeout(Duration::from_millis(20));
+    device
+}
+
+#[test]
+fn test_device_send_with_ack() {
+    let mut reply = ACK_ACK_CFG_NAV5.to_vec();
+    reply.extend_from_slice(&ACK_ACK_CFG_MSG);
+    let mut device = new_device(vec![reply]);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler_received = received.clone();
+    device.set_handler(move |packet| handler_received.lock().unwrap().push(packet));
+
+    device.send_with_ack(&cfg_msg_packet()).unwrap();
+    assert_eq!(device.get_ref().written, vec![cfg_msg_packet().to_vec()]);
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 1);
+    match received[0] {
+        PacketOwned::AckAck(ref ack) => {
+            let ack = ack.as_packet_ref();
+            assert_eq!((ack.class(), ack.msg_id()), (0x06, 0x24));
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_device_nak() {
+    let mut device = new_device(vec![ACK_NAK_CFG_MSG.to_vec()]);
+    match device.send_with_ack(&cfg_msg_packet()) {
+        Err(DeviceError::Nak { class, msg_id }) => assert_eq!((class, msg_id), (0x06, 0x01)),
+        _ => panic!(),
+    }
+    assert_eq!(device.get_ref().written.len(), 1);
+}
+
+#[test]
+fn test_device_retry() {
+    let mut device = new_device(vec![Vec::new(), ACK_ACK_CFG_MSG.to_vec()]);
+    device.send_with_ack(&cfg_msg_packet()).unwrap();
+    assert_eq!(device.get_ref().written.len(), 2);
+}
+
+#[test]
+fn test_device_timeout() {
+    let mut device = new_device(Vec::new());
+    device.set_retries(1);
+    match device.send_with_ack(&cfg_msg_packet()) {
+        Err(DeviceError::Timeout { class, msg_id }) => assert_eq!((class, msg_id), (0x06, 0x01)),
+        _ => panic!(),
+    }
+    assert_eq!(device.get_ref().written.len(), 2);
+}
+
+#[test]
+fn test_device_send_not_ubx() {
+    let mut device = new_device(Vec::new());
+    match device.send_with_ack(b"$PUBX,00*33\r\n") {
+        Err(DeviceError::Io(err)) => assert_eq!(err.kind(), io::ErrorKind::InvalidInput),
+        _ => panic!(),
+    }
+    assert!(device.get_ref().written.is_empty());
+}
+
+#[test]
+fn test_device_poll() {
+    let nav5 = CfgNav5Builder {
+        pacc: 21,
+        ..CfgNav5Builder::default()
+    };
+    let mut device = new_device(vec![nav5.into_packet_bytes().to_vec()]);
+
+    match device.poll::<CfgNav5>().unwrap() {
+        PacketOwned::CfgNav5(packet) => assert_eq!(packet.as_packet_ref().pacc(), 21),
+        _ => panic!(),
+    }
+    assert_eq!(
+        device.get_ref().written,
+        vec![vec![0xb5, 0x62, 0x06, 0x24, 0x00, 0x00, 0x2a, 0x84]]
+    );
+}
+
+#[test]
+fn test_device_poll_consumes_ack() {
+    let mut reply = CfgNav5Builder::default().into_packet_bytes().to_vec();
+    reply.extend_from_slice(&ACK_ACK_CFG_NAV5);
+    let ack_nak_cfg_nav5 = ubx_frame(0x05, 0x00, &[0x06, 0x24]);
+    let mut device = new_device(vec![reply, ack_nak_cfg_nav5]);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler_received = received.clone();
+    device.set_handler(move |packet| handler_received.lock().unwrap().push(packet));
+
+    device.poll::<CfgNav5>().unwrap();
+    let nav5 = CfgNav5Builder::default().into_packet_bytes();
+    match device.send_with_ack(&nav5) {
+        Err(DeviceError::Nak { class, msg_id }) => assert_eq!((class, msg_id), (0x06, 0x24)),
+        _ => panic!(),
+    }
+    assert!(received.lock().unwrap().is_empty());
+}
+
+fn ubx_frame(class: u8, msg_id: u8, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0xb5, 0x62, class, msg_id];
+    bytes.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(payload);
+    let (mut ck_a, mut ck_b) = (0u8, 0u8);
+    for byte in &bytes[2..] {
+        ck_a = ck_a.wrapping_add(*byte);
+        ck_b = ck_b.wrapping_add(ck_a);
+    }
+    bytes.extend_from_slice(&[ck_a, ck_b]);
+    bytes
+}
+
+fn log_info(entry_count: u32) -> Vec<u8> {
+    let mut payload = vec![0; 48];
+    payload[0] = 1;
+    payload[24..28].copy_from_slice(&entry_count.to_le_bytes());
+    ubx_frame(0x21, 0x08, &payload)
+}
+
+fn log_retrieve(start_number: u32, entry_count: u32) -> Vec<u8> {
+    let mut payload = start_number.to_le_bytes().to_vec();
+    payload.extend_from_slice(&entry_count.to_le_bytes());
+    payload.extend_from_slice(&[0, 0, 0, 0]);
+    ubx_frame(0x21, 0x09, &payload)
+}
+
+fn log_pos(index: u32) -> Vec<u8> {
+    let mut payload = vec![0; 40];
+    payload[0..4].copy_from_slice(&index.to_le_bytes());
+    ubx_frame(0x21, 0x0b, &payload)
+}
+
+fn log_string(index: u32, string: &[u8]) -> Vec<u8> {
+    let mut payload = vec![0; 16];
+    payload[0..4].copy_from_slice(&index.to_le_bytes());
+    payload[14..16].copy_from_slice(&(string.len() as u16).to_le_bytes());
+    payload.extend_from_slice(string);
+    ubx_frame(0x21, 0x0d, &payload)
+}
+
+#[test]
+fn test_device_download_log() {
+    let mut entries = log_pos(0);
+    entries.extend(log_string(1, b"lap 1"));
+    entries.extend(log_pos(2));
+    let mut device = new_device(vec![log_info(3), entries]);
+
+    let entries: Vec<_> = device
+        .download_log()
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        entries.iter().map(LogEntry::index).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    match entries[1] {
+        LogEntry::String(ref entry) => {
+            assert_eq!(entry.as_packet_ref().as_str(), Some("lap 1"))
+        }
+        _ => panic!(),
+    }
+    assert_eq!(
+        device.get_ref().written,
+        vec![ubx_frame(0x21, 0x08, &[]), log_retrieve(0, 3),]
+    );
+}
+
+#[test]
+fn test_device_download_log_lost_entry() {
+    let mut entries = log_pos(0);
+    entries.extend(log_pos(2));
+    let mut retried = log_string(1, b"lap 1");
+    retried.extend(log_pos(2));
+    let mut device = new_device(vec![log_info(3), entries, retried]);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let handler_received = received.clone();
+    device.set_handler(move |packet| handler_received.lock().unwrap().push(packet));
+
+    let indices: Vec<_> = device
+        .download_log()
+        .unwrap()
+        .map(|entry| entry.unwrap().index())
+        .collect();
+    assert_eq!(indices, vec![0, 1, 2]);
+    assert_eq!(
+        device.get_ref().written[1..],
+        [log_retrieve(0, 3), log_retrieve(1, 2)]
+    );
+    // The entry received out of order is requested again, not passed to the handler
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_device_download_empty_log() {
+    let mut device = new_device(vec![log_info(0)]);
+    assert!(device.download_log().unwrap().next().is_none());
+    assert_eq!(device.get_ref().written.len(), 1);
+}
@@ -1,4 +1,8 @@
-use ublox::{CfgMsgSinglePortBuilder, NavPosLlh, NavStatus};
+use ublox::{
+    cfg_val::{CfgVal, ImuMntAlgYaw, Uart1Baudrate},
+    CfgCfgBuilder, CfgCfgDevices, CfgCfgMask, CfgMsgSinglePortBuilder, CfgValDelBuilder,
+    CfgValDelLayer, CfgValError, CfgValGetLayer, CfgValGetPollBuilder, NavPosLlh, NavStatus,
+};
 
 #[test]
 fn test_cfg_msg_simple() {
@@ -12,3 +16,106 @@ fn test_cfg_msg_simple() {
         CfgMsgSinglePortBuilder::set_rate_for::<NavStatus>(1).into_packet_bytes()
     );
 }
+
+#[test]
+fn test_cfg_val_get_poll() {
+    assert_eq!(
+        vec![
+            0xb5, 0x62, 0x06, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x52, 0x40,
+            0x2c, 0x79
+        ],
+        CfgValGetPollBuilder {
+            version: 0,
+            layer: CfgValGetLayer::Ram,
+            position: 0,
+            keys: &[Uart1Baudrate::KEY],
+        }
+        .into_packet_vec()
+    );
+}
+
+#[test]
+fn test_cfg_val_del() {
+    assert_eq!(
+        vec![
+            0xb5, 0x62, 0x06, 0x8c, 0x08, 0x00, 0x00, 0x06, 0x00, 0x00, 0x01, 0x00, 0x52, 0x40,
+            0x33, 0xae
+        ],
+        CfgValDelBuilder {
+            version: 0,
+            layers: CfgValDelLayer::BbrAndFlash,
+            reserved1: 0,
+            keys: &[Uart1Baudrate::KEY],
+        }
+        .into_packet_vec()
+    );
+}
+
+#[test]
+fn test_cfg_val_scaled() {
+    assert!(matches!(
+        CfgVal::tp_ant_cable_delay_ns(50.0),
+        Ok(CfgVal::TpAntCableDelay(50))
+    ));
+    assert!(matches!(
+        CfgVal::tmode_lat_deg(-47.397_634_1),
+        Ok(CfgVal::TmodeLat(-473_976_341))
+    ));
+
+    let yaw = CfgVal::imu_mnt_alg_yaw_deg(12.345).unwrap();
+    assert!(matches!(yaw, CfgVal::ImuMntAlgYaw(1235)));
+    assert_eq!(yaw.scaled_value(), Some(12.35));
+    assert_eq!(yaw.unit(), Some("deg"));
+
+    assert_eq!(
+        CfgVal::imu_mnt_alg_yaw_deg(360.5).unwrap_err(),
+        CfgValError::OutOfRange {
+            item: "ImuMntAlgYaw",
+            value: 360.5,
+            min: ImuMntAlgYaw::MIN,
+            max: ImuMntAlgYaw::MAX,
+        }
+    );
+    assert!(CfgVal::tp_ant_cable_delay_ns(40_000.0).is_err());
+    assert!(CfgVal::rate_meas_ms(f64::NAN).is_err());
+
+    let pdop = CfgVal::nav_spg_outfil_pdop(2.5).unwrap();
+    assert!(matches!(pdop, CfgVal::NavSpgOutfilPdop(25)));
+    assert_eq!(pdop.scaled_value(), Some(2.5));
+    assert_eq!(pdop.unit(), Some(""));
+    assert!(CfgVal::log_filter_speed_thrs_mps(-1.0).is_err());
+    assert!(CfgVal::rate_nav_prio_hz(31.0).is_err());
+
+    assert_eq!(CfgVal::Uart1Baudrate(9600).scaled_value(), None);
+    assert_eq!(CfgVal::Uart1Baudrate(9600).unit(), None);
+}
+
+#[test]
+fn test_cfg_cfg() {
+    assert_eq!(
+        [
+            0xb5, 0x62, 0x06, 0x09, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x03, 0x3a, 0xaf
+        ],
+        CfgCfgBuilder::save(
+            CfgCfgMask::IO_PORT
+                | CfgCfgMask::MSG_CONF
+                | CfgCfgMask::NAV_CONF
+                | CfgCfgMask::RXM_CONF,
+            CfgCfgDevices::BBR | CfgCfgDevices::FLASH,
+        )
+        .into_packet_bytes()
+    );
+
+    assert_eq!(
+        [
+            0xb5, 0x62, 0x06, 0x09, 0x0d, 0x00, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+            0xff, 0xff, 0xff, 0xff, 0x03, 0x17, 0x80
+        ],
+        CfgCfgBuilder::reset_to_default(
+            CfgCfgMask::all(),
+            CfgCfgDevices::BBR | CfgCfgDevices::FLASH
+        )
+        .into_packet_bytes()
+    );
+}
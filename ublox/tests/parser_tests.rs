@@ -1,10 +1,42 @@
 #![cfg(feature = "alloc")]
 
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use std::convert::TryFrom;
 use ublox::{
-    CfgNav5Builder, CfgNav5DynModel, CfgNav5FixMode, CfgNav5Params, CfgNav5UtcStandard, PacketRef,
-    Parser, ParserError, ParserIter,
+    cfg_val::{
+        CfgVal, KeyId, NmeaVersion, PmpRate, SpartnSource, TmodeReceiverMode, Uart1StopBits,
+    },
+    AntennaPower, AntennaStatus, BuilderError, CarrierPhaseSolution, CfgDgnssBuilder, CfgDgnssMode,
+    CfgGnssBeiDouSignals, CfgGnssBlock, CfgGnssBuilder, CfgGnssError, CfgGnssGalileoSignals,
+    CfgGnssGlonassSignals, CfgGnssGpsSignals, CfgGnssSignals, CfgNav5Builder, CfgNav5DynModel,
+    CfgNav5FixMode, CfgNav5Params, CfgNav5UtcStandard, CfgPm2Builder, CfgPm2Flags, CfgPm2Mode,
+    CfgPmsBuilder, CfgPmsPowerSetup, CfgSbasBuilder, CfgSbasMode, CfgSbasScanMode1,
+    CfgSbasScanMode2, CfgSbasUsage, CfgValGetLayer, CfgValIter, CorrectionProtocol, DateTimeError,
+    EsfAlgErrors, EsfAlgStatus, EsfCalibStatus, EsfFusionMode, EsfSensorFaults, EsfSensorType,
+    EsfTimeStatus, GnssId, GnssTime, GpsFix, I2cPortId, JammingState, LogBatchContentValid, LogCfg,
+    LogCreateBuilder, LogFindTimeBuilder, LogFixType, LogInfoStatus, LogSize, LogStringBuilder,
+    MonBufPort, MonHw2ConfigSource, MonRxrFlags, MonSysBootType, MultipathIndicator,
+    NavHpPosECEFFlags, NavSatQualityIndicator, NavSatSvHealth, NavSbasMode, NavSigCorrectionSource,
+    NavSigIonoModel, NavTimeGpsFlags, PacketRef, Parser, ParserError, ParserIter, Position,
+    PositionECEF, RxmMeasxTowSet, RxmPmreqBuilder, RxmPmreqFlags, RxmPmreqWakeupSources,
+    RxmSpartnKeyBuilder, SbasService, SbasSystem, SignalId, SpartnKey, SpiPortId, TimeScale,
+    UartPortId, UsbPortId, Velocity,
 };
 
+/// Frames `payload` as an UBX packet, with a valid checksum
+fn ubx_frame(class: u8, msg_id: u8, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0xb5, 0x62, class, msg_id];
+    bytes.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(payload);
+    let (mut ck_a, mut ck_b) = (0u8, 0u8);
+    for byte in &bytes[2..] {
+        ck_a = ck_a.wrapping_add(*byte);
+        ck_b = ck_b.wrapping_add(ck_a);
+    }
+    bytes.extend_from_slice(&[ck_a, ck_b]);
+    bytes
+}
+
 macro_rules! my_vec {
         ($($x:expr),*) => {{
             let v: Vec<Result<(u8, u8), ParserError>> =  vec![$($x),*];
@@ -195,6 +227,1515 @@ fn test_parse_cfg_nav5() {
     assert!(found);
 }
 
+#[test]
+fn test_parse_cfg_val_get() {
+    #[rustfmt::skip]
+    let bytes = [
+        0xb5, 0x62, 0x06, 0x8b, 0x11, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x52, 0x40, 0x00, 0xc2, 0x01, 0x00,
+        0x05, 0x00, 0x52, 0x10, 0x01,
+        0x61, 0x08,
+    ];
+
+    let mut parser = Parser::default();
+    let mut found = false;
+    let mut it = parser.consume(&bytes);
+    while let Some(pack) = it.next() {
+        match pack {
+            Ok(PacketRef::CfgValGet(pack)) => {
+                found = true;
+
+                assert_eq!(1, pack.version());
+                assert_eq!(CfgValGetLayer::Ram, pack.layer());
+                assert_eq!(0, pack.position());
+                let mut values = pack.cfg_data();
+                assert!(matches!(
+                    values.next(),
+                    Some(CfgVal::Uart1Baudrate(115_200))
+                ));
+                assert!(matches!(values.next(), Some(CfgVal::Uart1Enabled(true))));
+                assert!(values.next().is_none());
+            }
+            _ => panic!(),
+        }
+    }
+    assert!(found);
+}
+
+#[test]
+fn test_parse_cfg_val_get_unknown() {
+    #[rustfmt::skip]
+    let bytes = [
+        0xb5, 0x62, 0x06, 0x8b, 0x16, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+        // Key not in the database
+        0x01, 0x00, 0xff, 0x40, 0x04, 0x03, 0x02, 0x01,
+        // Uart1StopBits with out of range value
+        0x02, 0x00, 0x52, 0x20, 0x09,
+        0x05, 0x00, 0x52, 0x10, 0x01,
+        0xd7, 0x83,
+    ];
+
+    let mut parser = Parser::default();
+    let mut found = false;
+    let mut it = parser.consume(&bytes);
+    while let Some(pack) = it.next() {
+        match pack {
+            Ok(PacketRef::CfgValGet(pack)) => {
+                found = true;
+
+                let mut values = pack.cfg_data();
+                match values.next() {
+                    Some(CfgVal::Unknown(key_id, value)) => {
+                        assert_eq!(key_id, KeyId::new(0x40ff_0001));
+                        assert_eq!(value, [4, 3, 2, 1, 0, 0, 0, 0]);
+                    }
+                    _ => panic!(),
+                }
+                match values.next() {
+                    Some(CfgVal::Unknown(key_id, value)) => {
+                        assert_eq!(key_id, Uart1StopBits::KEY);
+                        assert_eq!(value[0], 9);
+                    }
+                    _ => panic!(),
+                }
+                assert!(matches!(values.next(), Some(CfgVal::Uart1Enabled(true))));
+                assert!(values.next().is_none());
+            }
+            _ => panic!(),
+        }
+    }
+    assert!(found);
+}
+
+#[test]
+fn test_cfg_val_parse_errors() {
+    assert_eq!(
+        CfgVal::parse(&[0x02, 0x00, 0x52, 0x20, 0x09]).unwrap_err(),
+        ParserError::InvalidField {
+            packet: "CfgVal",
+            field: "Uart1StopBits",
+        }
+    );
+    assert_eq!(
+        CfgVal::parse(&[0x01, 0x00, 0x52, 0x40, 0x00]).unwrap_err(),
+        ParserError::InvalidPacketLen {
+            packet: "CfgVal",
+            expect: 8,
+            got: 5,
+        }
+    );
+
+    let mut buf = [0; 8];
+    let unknown = CfgVal::parse(&[0x01, 0x00, 0xff, 0x30, 0x34, 0x12]).unwrap();
+    assert_eq!(unknown.len(), 6);
+    assert_eq!(unknown.write_to(&mut buf), 6);
+    assert_eq!(buf[..6], [0x01, 0x00, 0xff, 0x30, 0x34, 0x12]);
+}
+
+#[test]
+fn test_cfg_val_roundtrip() {
+    let values = [
+        CfgVal::NavSpgDynModel(CfgNav5DynModel::Automotive),
+        CfgVal::NavSpgInfilMinElev(-5),
+        CfgVal::NavSpgUsrDatMajA(6_378_137.0),
+        CfgVal::NavHpgDgnssMode(CfgDgnssMode::RtkFloat),
+        CfgVal::TmodeMode(TmodeReceiverMode::SurveyIn),
+        CfgVal::TmodeLat(-473_976_340),
+        CfgVal::NmeaProtVer(NmeaVersion::V411),
+        CfgVal::NavSpgDynModel(CfgNav5DynModel::Mower),
+        CfgVal::NavSpgUtcStandard(CfgNav5UtcStandard::Eu),
+        CfgVal::PmpDataRate(PmpRate::B2400),
+        CfgVal::SpartnUseSource(SpartnSource::LBand),
+    ];
+    let mut buf = [0; 96];
+    let mut it = CfgValIter::new(&mut buf, &values);
+
+    assert!(matches!(
+        it.next(),
+        Some(CfgVal::NavSpgDynModel(CfgNav5DynModel::Automotive))
+    ));
+    assert!(matches!(it.next(), Some(CfgVal::NavSpgInfilMinElev(-5))));
+    match it.next() {
+        Some(CfgVal::NavSpgUsrDatMajA(value)) => assert_eq!(value, 6_378_137.0),
+        _ => panic!(),
+    }
+    assert!(matches!(
+        it.next(),
+        Some(CfgVal::NavHpgDgnssMode(CfgDgnssMode::RtkFloat))
+    ));
+    assert!(matches!(
+        it.next(),
+        Some(CfgVal::TmodeMode(TmodeReceiverMode::SurveyIn))
+    ));
+    assert!(matches!(it.next(), Some(CfgVal::TmodeLat(-473_976_340))));
+    assert!(matches!(
+        it.next(),
+        Some(CfgVal::NmeaProtVer(NmeaVersion::V411))
+    ));
+    assert!(matches!(
+        it.next(),
+        Some(CfgVal::NavSpgDynModel(CfgNav5DynModel::Mower))
+    ));
+    assert!(matches!(
+        it.next(),
+        Some(CfgVal::NavSpgUtcStandard(CfgNav5UtcStandard::Eu))
+    ));
+    assert!(matches!(
+        it.next(),
+        Some(CfgVal::PmpDataRate(PmpRate::B2400))
+    ));
+    assert!(matches!(
+        it.next(),
+        Some(CfgVal::SpartnUseSource(SpartnSource::LBand))
+    ));
+    assert!(it.next().is_none());
+
+    // CFG-PMP-DATA_RATE is stored in two bytes
+    let mut buf = [0; 6];
+    CfgVal::PmpDataRate(PmpRate::B2400).write_to(&mut buf);
+    assert_eq!([0x13, 0x00, 0xb1, 0x30, 0x60, 0x09], buf);
+}
+
+#[test]
+fn test_parse_nav_rel_pos_ned() {
+    #[rustfmt::skip]
+    let bytes = [
+        // Version 1
+        0xb5, 0x62, 0x01, 0x3c, 0x40, 0x00,
+        0x01, 0x00, 0x05, 0x00, 0xe8, 0x03, 0x00, 0x00,
+        0x7b, 0x00, 0x00, 0x00, 0x38, 0xfe, 0xff, 0xff, 0x07, 0x00, 0x00, 0x00,
+        0x7c, 0x00, 0x00, 0x00, 0x40, 0x54, 0x89, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x2d, 0xf4, 0x03, 0xff,
+        0x0a, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00,
+        0x28, 0x00, 0x00, 0x00, 0x50, 0xc3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x37, 0x01, 0x00, 0x00,
+        0x8f, 0x79,
+        // Version 0
+        0xb5, 0x62, 0x01, 0x3c, 0x28, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0xe8, 0x03, 0x00, 0x00,
+        0x7b, 0x00, 0x00, 0x00, 0x38, 0xfe, 0xff, 0xff, 0x07, 0x00, 0x00, 0x00,
+        0x2d, 0xf4, 0x03, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00,
+        0x0d, 0x00, 0x00, 0x00,
+        0x78, 0xe0,
+    ];
+    let eps = 1e-9;
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::NavRelPosNedV1(pack))) => {
+            assert_eq!(1, pack.version());
+            assert_eq!(5, pack.ref_station_id());
+            assert_eq!(1000, pack.itow());
+            assert!((pack.rel_pos_n_meters() - 1.2345).abs() < eps);
+            assert!((pack.rel_pos_e_meters() + 4.5612).abs() < eps);
+            assert!((pack.rel_pos_d_meters() - 0.0703).abs() < eps);
+            assert!((pack.rel_pos_length_meters() - 1.2399).abs() < eps);
+            assert!((pack.rel_pos_heading_degrees() - 90.0).abs() < eps);
+            assert!((pack.acc_n() - 0.001).abs() < eps);
+            assert!((pack.acc_length() - 0.004).abs() < eps);
+            assert!((pack.acc_heading_degrees() - 0.5).abs() < eps);
+
+            let flags = pack.flags();
+            assert!(flags.gnss_fix_ok());
+            assert!(flags.diff_soln());
+            assert!(flags.rel_pos_valid());
+            assert_eq!(CarrierPhaseSolution::Fixed, flags.carr_soln());
+            assert!(flags.is_moving());
+            assert!(!flags.ref_pos_miss());
+            assert!(!flags.ref_obs_miss());
+            assert!(flags.rel_pos_heading_valid());
+            assert!(!flags.rel_pos_normalized());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::NavRelPosNedV0(pack))) => {
+            assert_eq!(0, pack.version());
+            assert!((pack.rel_pos_n_meters() - 1.2345).abs() < eps);
+            assert!((pack.acc_d() - 0.003).abs() < eps);
+
+            let flags = pack.flags();
+            assert!(flags.gnss_fix_ok());
+            assert!(flags.rel_pos_valid());
+            assert_eq!(CarrierPhaseSolution::Float, flags.carr_soln());
+            assert!(!flags.is_moving());
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_nav_ecef() {
+    #[rustfmt::skip]
+    let bytes = [
+        // NavHpPosECEF
+        0xb5, 0x62, 0x01, 0x13, 0x1c, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xe8, 0x03, 0x00, 0x00,
+        0x16, 0x43, 0x0a, 0x19, 0xc0, 0xff, 0x2d, 0x04, 0xc0, 0x09, 0x56, 0xe4,
+        0x0c, 0xde, 0x38, 0x00, 0x7b, 0x00, 0x00, 0x00,
+        0x27, 0xd4,
+        // NavVelECEF
+        0xb5, 0x62, 0x01, 0x11, 0x14, 0x00,
+        0xe8, 0x03, 0x00, 0x00, 0x6a, 0xff, 0xff, 0xff, 0x19, 0x00, 0x00, 0x00,
+        0xca, 0xfe, 0xff, 0xff, 0x28, 0x00, 0x00, 0x00,
+        0x7f, 0x29,
+    ];
+    let eps = 1e-6;
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::NavHpPosECEF(pack))) => {
+            assert_eq!(1000, pack.itow());
+            assert!(!pack.flags().contains(NavHpPosECEFFlags::INVALID_ECEF));
+            assert!((pack.position_accuracy() - 0.0123).abs() < eps);
+
+            let pos = PositionECEF::from(&pack);
+            assert!((pos.x - 4201029.3412).abs() < eps);
+            assert!((pos.y - 701234.5566).abs() < eps);
+            assert!((pos.z + 4641234.5544).abs() < eps);
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::NavVelECEF(pack))) => {
+            assert!((pack.ecef_vx() + 1.5).abs() < eps);
+            assert!((pack.ecef_vy() - 0.25).abs() < eps);
+            assert!((pack.ecef_vz() + 3.1).abs() < eps);
+            assert!((pack.s_acc() - 0.4).abs() < eps);
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_nav_sig() {
+    #[rustfmt::skip]
+    let bytes = [
+        0xb5, 0x62, 0x01, 0x43, 0x28, 0x00,
+        0xe8, 0x03, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x0c, 0x03, 0x00, 0xf1, 0xff, 0x2a, 0x07,
+        0x04, 0x08, 0x39, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x03, 0x00, 0x0a, 0x03, 0x00, 0x1e, 0x04,
+        0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x88,
+    ];
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::NavSig(pack))) => {
+            assert_eq!(1000, pack.itow());
+            assert_eq!(2, pack.num_sigs());
+
+            let mut sigs = pack.sigs();
+            let sig = sigs.next().unwrap();
+            assert_eq!(GnssId::Gps, sig.gnss_id());
+            assert_eq!(12, sig.sv_id());
+            assert_eq!(SignalId::GpsL2CL, sig.signal_id());
+            assert!((sig.pr_res() + 1.5).abs() < 1e-9);
+            assert_eq!(42, sig.cno());
+            assert!(matches!(
+                sig.quality_ind(),
+                NavSatQualityIndicator::CarrierLock
+            ));
+            assert_eq!(NavSigCorrectionSource::Rtcm3Osr, sig.corr_source());
+            assert_eq!(NavSigIonoModel::DualFrequency, sig.iono_model());
+            let flags = sig.sig_flags();
+            assert!(matches!(flags.health(), NavSatSvHealth::Healthy));
+            assert!(!flags.pr_smoothed());
+            assert!(flags.pr_used());
+            assert!(flags.cr_used());
+            assert!(flags.do_used());
+            assert!(!flags.pr_corr_used());
+
+            let sig = sigs.next().unwrap();
+            assert_eq!(GnssId::Glonass, sig.gnss_id());
+            assert_eq!(SignalId::GlonassL1OF, sig.signal_id());
+            assert_eq!(10, sig.freq_id());
+            assert!(matches!(
+                sig.quality_ind(),
+                NavSatQualityIndicator::CodeLock
+            ));
+            assert_eq!(NavSigIonoModel::KlobucharGps, sig.iono_model());
+            assert!(sigs.next().is_none());
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_nav_svin() {
+    #[rustfmt::skip]
+    let bytes = [
+        0xb5, 0x62, 0x01, 0x3b, 0x28, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xe8, 0x03, 0x00, 0x00,
+        0x2c, 0x01, 0x00, 0x00,
+        0x16, 0x43, 0x0a, 0x19, 0xc0, 0xff, 0x2d, 0x04, 0xc0, 0x09, 0x56, 0xe4,
+        0x0c, 0xde, 0x38, 0x00,
+        0x98, 0x3a, 0x00, 0x00, 0x27, 0x01, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+        0x08, 0xe4,
+    ];
+    let eps = 1e-6;
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::NavSvin(pack))) => {
+            assert_eq!(1000, pack.itow());
+            assert_eq!(300, pack.dur());
+            assert!((pack.mean_acc() - 1.5).abs() < eps);
+            assert_eq!(295, pack.obs());
+            assert!(pack.valid());
+            assert!(!pack.active());
+
+            let pos = PositionECEF::from(&pack);
+            assert!((pos.x - 4201029.3412).abs() < eps);
+            assert!((pos.y - 701234.5566).abs() < eps);
+            assert!((pos.z + 4641234.5544).abs() < eps);
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_nav_time_gnss() {
+    #[rustfmt::skip]
+    let bytes = [
+        // NavTimeGps
+        0xb5, 0x62, 0x01, 0x20, 0x10, 0x00,
+        0x00, 0x70, 0x99, 0x14, 0xfa, 0x00, 0x00, 0x00, 0x98, 0x08, 0x12, 0x07,
+        0x14, 0x00, 0x00, 0x00,
+        0x15, 0x15,
+        // NavTimeBds
+        0xb5, 0x62, 0x01, 0x24, 0x14, 0x00,
+        0x00, 0x70, 0x99, 0x14, 0xf2, 0x45, 0x05, 0x00, 0xfa, 0x00, 0x00, 0x00,
+        0x4c, 0x03, 0x04, 0x07, 0x14, 0x00, 0x00, 0x00,
+        0xfa, 0x9b,
+        // NavTimeGlo
+        0xb5, 0x62, 0x01, 0x23, 0x14, 0x00,
+        0x00, 0x70, 0x99, 0x14, 0x1e, 0x2a, 0x00, 0x00, 0xfa, 0x00, 0x00, 0x00,
+        0x20, 0x03, 0x07, 0x03, 0x14, 0x00, 0x00, 0x00,
+        0xd8, 0x07,
+        // NavTimeGal, without valid leap seconds
+        0xb5, 0x62, 0x01, 0x25, 0x14, 0x00,
+        0x00, 0x70, 0x99, 0x14, 0x00, 0x46, 0x05, 0x00, 0xfa, 0x00, 0x00, 0x00,
+        0x98, 0x04, 0x12, 0x03, 0x14, 0x00, 0x00, 0x00,
+        0x61, 0x48,
+    ];
+    let expected = Utc.from_utc_datetime(
+        &NaiveDate::from_ymd_opt(2022, 3, 9)
+            .and_then(|date| date.and_hms_nano_opt(23, 59, 42, 250))
+            .unwrap(),
+    );
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::NavTimeGps(pack))) => {
+            assert_eq!(2200, pack.week());
+            assert!(pack.valid().contains(NavTimeGpsFlags::LEAP_S_VALID));
+            let time = GnssTime::try_from(&pack).unwrap();
+            assert_eq!(
+                GnssTime {
+                    time_scale: TimeScale::Gps,
+                    week: 2200,
+                    tow: 345600,
+                    nanos: 250,
+                    leap_seconds: Some(18),
+                },
+                time
+            );
+            assert_eq!(expected, DateTime::<Utc>::try_from(&time).unwrap());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::NavTimeBds(pack))) => {
+            let time = GnssTime::try_from(&pack).unwrap();
+            assert_eq!(TimeScale::BeiDou, time.time_scale);
+            assert_eq!(expected, DateTime::<Utc>::try_from(&time).unwrap());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::NavTimeGlo(pack))) => {
+            assert_eq!(7, pack.n4());
+            assert_eq!(expected, DateTime::<Utc>::try_from(&pack).unwrap());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::NavTimeGal(pack))) => {
+            let time = GnssTime::try_from(&pack).unwrap();
+            assert_eq!(1176, time.week);
+            assert_eq!(None, time.leap_seconds);
+            assert!(matches!(
+                DateTime::<Utc>::try_from(&time),
+                Err(DateTimeError::UnknownLeapSeconds)
+            ));
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_mon_rf_comms_sys() {
+    #[rustfmt::skip]
+    let bytes = [
+        // MonRf
+        0xb5, 0x62, 0x0a, 0x38, 0x34, 0x00,
+        0x00, 0x02, 0x00, 0x00,
+        0x00, 0x02, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x59, 0x00, 0xa0, 0x0f, 0x0c, 0xfd, 0x78, 0x04, 0x76, 0x00, 0x00, 0x00,
+        0x01, 0x01, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x46, 0x00, 0xb8, 0x0b, 0x03, 0x00, 0x64, 0x00, 0x65, 0x00, 0x00, 0x00,
+        0x5b, 0x25,
+        // MonComms
+        0xb5, 0x62, 0x0a, 0x36, 0x30, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0xff,
+        0x00, 0x01, 0x00, 0x00, 0x40, 0xe2, 0x01, 0x00, 0x02, 0x0a, 0x00, 0x00,
+        0xf1, 0xfb, 0x09, 0x00, 0x01, 0x5a, 0x03, 0x00, 0x64, 0x00, 0x07, 0x00,
+        0x37, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00,
+        0xa4, 0xe3,
+        // MonSys
+        0xb5, 0x62, 0x0a, 0x39, 0x18, 0x00,
+        0x01, 0x02, 0x19, 0x3c, 0x28, 0x2d, 0x05, 0x1e, 0x10, 0x0e, 0x00, 0x00,
+        0x03, 0x00, 0x02, 0x00, 0x01, 0x00, 0xfb, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x4a, 0xa6,
+    ];
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::MonRf(pack))) => {
+            assert_eq!(2, pack.n_blocks());
+            let mut blocks = pack.blocks();
+            let block = blocks.next().unwrap();
+            assert_eq!(0, block.block_id());
+            assert_eq!(JammingState::Warning, block.jamming_state());
+            assert_eq!(AntennaStatus::Ok, block.ant_status());
+            assert_eq!(AntennaPower::On, block.ant_power());
+            assert_eq!(89, block.noise_per_ms());
+            assert_eq!(4000, block.agc_cnt());
+            assert_eq!(12, block.jam_ind());
+            assert_eq!(-3, block.ofs_i());
+            let block = blocks.next().unwrap();
+            assert_eq!(1, block.block_id());
+            assert_eq!(JammingState::Ok, block.jamming_state());
+            assert_eq!(AntennaStatus::Open, block.ant_status());
+            assert!(blocks.next().is_none());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::MonComms(pack))) => {
+            assert!(pack.tx_errors().is_empty());
+            assert_eq!([0, 1, 5, 0xff], pack.prot_ids());
+            let mut ports = pack.ports();
+            let port = ports.next().unwrap();
+            assert_eq!(0x0100, port.port_id());
+            assert_eq!(1, port.port_number());
+            assert_eq!(123456, port.tx_bytes());
+            assert_eq!(654321, port.rx_bytes());
+            assert_eq!(90, port.rx_peak_usage());
+            assert_eq!(3, port.overrun_errs());
+            assert_eq!([100, 7, 55, 0], port.msgs());
+            assert_eq!(9, port.skipped());
+            assert!(ports.next().is_none());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::MonSys(pack))) => {
+            assert_eq!(MonSysBootType::Watchdog, pack.boot_type());
+            assert_eq!(25, pack.cpu_load());
+            assert_eq!(3600, pack.run_time());
+            assert_eq!(1, pack.error_count());
+            assert_eq!(-5, pack.temp_value());
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_mon_span() {
+    let mut payload = vec![0, 1, 0, 0];
+    payload.extend((0..=255u8).map(|x| x / 2));
+    payload.extend_from_slice(&128_000_000u32.to_le_bytes());
+    payload.extend_from_slice(&500_000u32.to_le_bytes());
+    payload.extend_from_slice(&1_583_400_000u32.to_le_bytes());
+    payload.extend_from_slice(&[54, 0, 0, 0]);
+    let bytes = ubx_frame(0x0a, 0x31, &payload);
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::MonSpan(pack))) => {
+            assert_eq!(1, pack.num_rf_blocks());
+            let mut blocks = pack.rf_blocks();
+            let block = blocks.next().unwrap();
+            assert_eq!(256, block.spectrum().len());
+            assert_eq!(128_000_000, block.span());
+            assert_eq!(500_000, block.res());
+            assert_eq!(1_583_400_000, block.center());
+            assert_eq!(54, block.pga());
+
+            let spectrum: Vec<_> = block.spectrum_db().collect();
+            assert_eq!((1_519_400_000., 0.), spectrum[0]);
+            assert_eq!((1_583_400_000., 16.), spectrum[128]);
+            assert_eq!((1_646_900_000., 31.75), spectrum[255]);
+            assert!(blocks.next().is_none());
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_mon_port_statistics() {
+    let mut io = Vec::new();
+    for port in 0..6u32 {
+        io.extend_from_slice(&(1000 * port).to_le_bytes());
+        io.extend_from_slice(&(2000 * port).to_le_bytes());
+        io.extend_from_slice(&[0, 0, 0, 0, port as u8, 0, 0, 0, 0, 0, 0, 0]);
+    }
+    let mut bytes = ubx_frame(0x0a, 0x02, &io);
+
+    let mut rx_buf = Vec::new();
+    for port in 0..6u16 {
+        rx_buf.extend_from_slice(&(100 * port).to_le_bytes());
+    }
+    rx_buf.extend_from_slice(&[0, 10, 0, 30, 40, 0]);
+    rx_buf.extend_from_slice(&[0, 15, 0, 35, 45, 0]);
+    bytes.extend(ubx_frame(0x0a, 0x07, &rx_buf));
+
+    let mut tx_buf = rx_buf.clone();
+    tx_buf.extend_from_slice(&[40, 45, 0x42, 0]);
+    bytes.extend(ubx_frame(0x0a, 0x08, &tx_buf));
+
+    let mut msgpp = Vec::new();
+    for port in 0..6u16 {
+        for protocol in 0..8u16 {
+            msgpp.extend_from_slice(&(port * 10 + protocol).to_le_bytes());
+        }
+    }
+    for port in 0..6u32 {
+        msgpp.extend_from_slice(&(port * 7).to_le_bytes());
+    }
+    bytes.extend(ubx_frame(0x0a, 0x06, &msgpp));
+
+    #[rustfmt::skip]
+    let hw2 = [
+        0xfe, 0x80, 0x02, 0x7f, 114, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0x01, 0x00, 0x00, 0x00, 0, 0, 0, 0,
+    ];
+    bytes.extend(ubx_frame(0x0a, 0x0b, &hw2));
+    bytes.extend(ubx_frame(0x0a, 0x21, &[0x01]));
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::MonIo(pack))) => {
+            assert_eq!(6, pack.ports().count());
+            let uart2 = pack.port(UartPortId::Uart2).unwrap();
+            assert_eq!(2000, uart2.rx_bytes());
+            assert_eq!(4000, uart2.tx_bytes());
+            assert_eq!(2, uart2.overrun_errs());
+            assert_eq!(8000, pack.port(SpiPortId::Spi).unwrap().tx_bytes());
+            assert_eq!(3000, pack.port(UsbPortId::Usb).unwrap().rx_bytes());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::MonRxBuf(pack))) => {
+            assert_eq!(
+                MonBufPort {
+                    pending: 100,
+                    usage: 10,
+                    peak_usage: 15,
+                },
+                pack.port(UartPortId::Uart1)
+            );
+            assert_eq!(0, pack.port(I2cPortId::I2c).pending);
+            assert_eq!(300, pack.port(UsbPortId::Usb).pending);
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::MonTxBuf(pack))) => {
+            assert_eq!(45, pack.port(SpiPortId::Spi).peak_usage);
+            assert_eq!(45, pack.t_peak_usage());
+            let errors = pack.errors();
+            assert!(errors.limit(UartPortId::Uart1));
+            assert!(!errors.limit(UartPortId::Uart2));
+            assert!(!errors.limit(UsbPortId::Usb));
+            assert!(errors.mem());
+            assert!(!errors.alloc());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::MonMsgpp(pack))) => {
+            assert_eq!([10, 11, 12, 13, 14, 15, 16, 17], pack.msg2());
+            let usb = pack.port(UsbPortId::Usb);
+            assert_eq!([30, 31, 32, 33, 34, 35, 36, 37], usb.msgs);
+            assert_eq!(21, usb.skipped);
+            assert_eq!(usb, pack.port(UartPortId::Usb));
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::MonHw2(pack))) => {
+            assert_eq!(-2, pack.ofs_i());
+            assert_eq!(0x7f, pack.mag_q());
+            assert_eq!(MonHw2ConfigSource::Rom, pack.cfg_source());
+            assert_eq!(1, pack.post_status());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::MonRxr(pack))) => {
+            assert!(pack.flags().contains(MonRxrFlags::AWAKE));
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_rxm_measurement_extensions() {
+    #[rustfmt::skip]
+    let mut measx = vec![
+        0x01, 0, 0, 0,
+        0x10, 0x27, 0, 0, // gps_tow 10000
+        0x20, 0x4e, 0, 0, // glo_tow 20000
+        0x30, 0x75, 0, 0, // bds_tow 30000
+        0, 0, 0, 0,
+        0x40, 0x9c, 0, 0, // qzss_tow 40000
+        0x10, 0, 0x20, 0, 0x30, 0, 0, 0, 0x40, 0,
+        2, 0x02,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    for (gnss_id, sv_id) in [(0u8, 12u8), (6, 3)] {
+        measx.extend_from_slice(&[gnss_id, sv_id, 42, 2]);
+        measx.extend_from_slice(&(-250i32).to_le_bytes());
+        measx.extend_from_slice(&1000i32.to_le_bytes());
+        measx.extend_from_slice(&[0x0a, 0x02, 0x00, 0x02]);
+        measx.extend_from_slice(&(1u32 << 21).to_le_bytes());
+        measx.extend_from_slice(&[3, 5, 0, 0]);
+    }
+    let mut bytes = ubx_frame(0x02, 0x14, &measx);
+
+    #[rustfmt::skip]
+    let rlm = [
+        0x00, 0x01, 0x05, 0x00,
+        1, 2, 3, 4, 5, 6, 7, 8,
+        0x0c, 0xab, 0xcd, 0x00,
+    ];
+    bytes.extend(ubx_frame(0x02, 0x59, &rlm));
+
+    let mut rlm = vec![0x00, 0x02, 0x06, 0x00];
+    rlm.extend(1..=8);
+    rlm.push(0x0a);
+    rlm.extend(0x10..0x1c);
+    rlm.extend_from_slice(&[0, 0, 0]);
+    bytes.extend(ubx_frame(0x02, 0x59, &rlm));
+
+    // SPARTN from an external input, error free, used and decrypted
+    let status_info: u32 =
+        2 | (1 << 5) | (2 << 7) | (21 << 9) | (1 << 25) | (1 << 27) | (2 << 28) | (2 << 30);
+    let mut cor = vec![0x01, 80, 0, 0];
+    cor.extend_from_slice(&status_info.to_le_bytes());
+    cor.extend_from_slice(&[1, 0, 0, 0]);
+    bytes.extend(ubx_frame(0x02, 0x34, &cor));
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::RxmMeasx(pack))) => {
+            assert_eq!(1, pack.version());
+            assert_eq!(10000, pack.gps_tow());
+            assert_eq!(40000, pack.qzss_tow());
+            assert!((pack.gps_tow_acc() - 1.0).abs() < 1e-9);
+            assert!((pack.qzss_tow_acc() - 4.0).abs() < 1e-9);
+            assert_eq!(RxmMeasxTowSet::SetAndUsed, pack.flags());
+            let svs: Vec<_> = pack.svs().collect();
+            assert_eq!(2, svs.len());
+            assert_eq!(GnssId::Gps, svs[0].gnss_id());
+            assert_eq!(12, svs[0].sv_id());
+            assert_eq!(GnssId::Glonass, svs[1].gnss_id());
+            assert_eq!(42, svs[1].c_no());
+            assert_eq!(MultipathIndicator::Medium, svs[1].mpath_indic());
+            assert!((svs[1].doppler_ms() + 10.0).abs() < 1e-9);
+            assert!((svs[1].doppler_hz() - 200.0).abs() < 1e-9);
+            assert_eq!(522, svs[1].whole_chips());
+            assert_eq!(512, svs[1].frac_chips());
+            assert!((svs[1].code_phase() - 1.0).abs() < 1e-9);
+            assert_eq!(3, svs[1].int_code_phase());
+            assert_eq!(5, svs[1].pseu_range_rms_err());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::RxmRlmShort(pack))) => {
+            assert_eq!(1, pack.msg_type());
+            assert_eq!(5, pack.sv_id());
+            assert_eq!([1, 2, 3, 4, 5, 6, 7, 8], pack.beacon());
+            assert_eq!(0x0c, pack.message());
+            assert_eq!([0xab, 0xcd], pack.params());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::RxmRlmLong(pack))) => {
+            assert_eq!(2, pack.msg_type());
+            assert_eq!(6, pack.sv_id());
+            assert_eq!(0x0a, pack.message());
+            assert_eq!(
+                [0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b],
+                pack.params()
+            );
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::RxmCor(pack))) => {
+            assert!((pack.ebno() - 10.0).abs() < 1e-6);
+            let status = pack.status_info();
+            assert_eq!(CorrectionProtocol::Spartn, status.protocol());
+            assert_eq!(Some(false), status.is_erroneous());
+            assert_eq!(Some(true), status.is_used());
+            assert_eq!(21, status.correction_id());
+            assert!(status.msg_type_valid());
+            assert!(!status.msg_sub_type_valid());
+            assert!(status.msg_input_handle());
+            assert_eq!(Some(true), status.is_encrypted());
+            assert_eq!(Some(true), status.is_decrypted());
+            assert_eq!(1, pack.msg_type());
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_rxm_pmreq_builder() {
+    let packet = RxmPmreqBuilder {
+        duration: 60_000,
+        flags: RxmPmreqFlags::BACKUP | RxmPmreqFlags::FORCE,
+        wakeup_sources: RxmPmreqWakeupSources::UART_RX | RxmPmreqWakeupSources::EXTINT0,
+        ..RxmPmreqBuilder::default()
+    }
+    .into_packet_bytes();
+
+    #[rustfmt::skip]
+    let payload = [
+        0, 0, 0, 0,
+        0x60, 0xea, 0, 0,
+        0x06, 0, 0, 0,
+        0x28, 0, 0, 0,
+    ];
+    assert_eq!(ubx_frame(0x02, 0x41, &payload), packet.to_vec());
+}
+
+#[test]
+fn test_rxm_spartn() {
+    let current = [0x11; 16];
+    let next = [0x22; 32];
+    let keys = [
+        SpartnKey {
+            valid_from_wno: 2250,
+            valid_from_tow: 86400,
+            key: &current,
+        },
+        SpartnKey {
+            valid_from_wno: 2254,
+            valid_from_tow: 0,
+            key: &next,
+        },
+    ];
+    let mut bytes = ubx_frame(0x02, 0x33, &[0x01, 0x04, 0x01, 0x00, 0, 0, 0x02, 0x00]);
+    let spartn_key = RxmSpartnKeyBuilder {
+        version: 1,
+        keys: &keys,
+    }
+    .into_packet_vec()
+    .unwrap();
+    assert_eq!(4 + 2 * 8 + 16 + 32 + 8, spartn_key.len());
+    assert_eq!([0x01, 0x02, 0x00, 0x00, 0x00, 0x10], spartn_key[6..12]);
+    bytes.extend(spartn_key);
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::RxmSpartn(pack))) => {
+            assert_eq!(1, pack.version());
+            assert_eq!(Some(true), pack.msg_used());
+            assert_eq!(1, pack.sub_type());
+            assert_eq!(2, pack.msg_type());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::RxmSpartnKey(pack))) => {
+            assert_eq!(2, pack.num_keys());
+            assert_eq!(keys.to_vec(), pack.keys().collect::<Vec<_>>());
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+
+    // The key is shorter than the length in its description, so the packet isn't recognized
+    let truncated = [0x01, 0x01, 0, 0, 0, 0x02, 0, 0, 0, 0, 0, 0, 0xaa];
+    let bytes = ubx_frame(0x02, 0x36, &truncated);
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::Unknown(pack))) => assert_eq!((0x02, 0x36), (pack.class, pack.msg_id)),
+        _ => panic!(),
+    }
+
+    let long = [0x33; 256];
+    let keys = [SpartnKey {
+        valid_from_wno: 2250,
+        valid_from_tow: 0,
+        key: &long,
+    }];
+    let mut out = Vec::new();
+    let builder = RxmSpartnKeyBuilder {
+        version: 1,
+        keys: &keys,
+    };
+    assert_eq!(
+        builder.extend_to(&mut out),
+        Err(BuilderError::TooLong {
+            field: "key",
+            len: 256,
+            max: 255
+        })
+    );
+    assert!(out.is_empty());
+
+    let keys = [SpartnKey {
+        valid_from_wno: 2250,
+        valid_from_tow: 0,
+        key: &long[..255],
+    }; 9];
+    let builder = RxmSpartnKeyBuilder {
+        version: 1,
+        keys: &keys,
+    };
+    assert!(matches!(
+        builder.into_packet_vec(),
+        Err(BuilderError::TooLong {
+            field: "payload",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_parse_esf_status_alg_cal() {
+    #[rustfmt::skip]
+    let status = [
+        0xe8, 0x03, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+        1, 0, 0, 2,
+        // Calibrated gyroscope, used and ready, time tagged by the sensor
+        0xce, 0x0e, 100, 0x00,
+        // Wheel ticks being calibrated, with missing measurements
+        0x86, 0x05, 10, 0x04,
+    ];
+    let mut bytes = ubx_frame(0x10, 0x10, &status);
+
+    #[rustfmt::skip]
+    let alg = [
+        0xe8, 0x03, 0, 0, 1,
+        0x09, 0x02, 0,
+        0x28, 0x23, 0, 0, // yaw 90.00
+        0x9c, 0xff,       // pitch -1.00
+        0xf4, 0x01,       // roll 5.00
+    ];
+    bytes.extend(ubx_frame(0x10, 0x14, &alg));
+
+    #[rustfmt::skip]
+    let cal = [
+        0x10, 0x27, 0, 0,
+        0x00, 0x10, 0x00, 0x0e, 0, 0, 0, 0,
+        0xff, 0xff, 0xff, 0x10, 0, 0, 0, 0,
+    ];
+    bytes.extend(ubx_frame(0x10, 0x04, &cal));
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::EsfStatus(pack))) => {
+            assert_eq!(1000, pack.itow());
+            assert_eq!(2, pack.version());
+            assert_eq!(EsfFusionMode::Fusion, pack.fusion_mode());
+            assert_eq!(2, pack.num_sens());
+            let sensors: Vec<_> = pack.sensors().collect();
+            assert_eq!(2, sensors.len());
+
+            let status = sensors[0].sens_status();
+            assert_eq!(EsfSensorType::GyroX, status.sensor_type());
+            assert!(status.used());
+            assert!(status.ready());
+            assert_eq!(EsfCalibStatus::Calibrated, status.calib_status());
+            assert_eq!(EsfTimeStatus::TimeTag, status.time_status());
+            assert_eq!(100, sensors[0].freq());
+            assert!(sensors[0].faults().is_empty());
+
+            let status = sensors[1].sens_status();
+            assert_eq!(EsfSensorType::FrontLeftWheelTicks, status.sensor_type());
+            assert!(!status.used());
+            assert_eq!(EsfCalibStatus::Calibrating, status.calib_status());
+            assert_eq!(EsfTimeStatus::FirstByteReception, status.time_status());
+            assert_eq!(EsfSensorFaults::MISSING_MEAS, sensors[1].faults());
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::EsfAlg(pack))) => {
+            let flags = pack.flags();
+            assert!(flags.auto_mnt_alg_on());
+            assert_eq!(EsfAlgStatus::FineAlignment, flags.status());
+            assert_eq!(EsfAlgErrors::YAW_ALG_ERROR, pack.errors());
+            assert!((pack.yaw() - 90.0).abs() < 1e-9);
+            assert!((pack.pitch() + 1.0).abs() < 1e-9);
+            assert!((pack.roll() - 5.0).abs() < 1e-9);
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::EsfCal(pack))) => {
+            assert_eq!(10000, pack.s_ttag());
+            let data: Vec<_> = pack.data().collect();
+            assert_eq!(2, data.len());
+            assert_eq!(EsfSensorType::GyroX, data[0].sensor_type());
+            assert_eq!(0x1000, data[0].data_field);
+            assert_eq!(EsfSensorType::AccX, data[1].sensor_type());
+            assert_eq!(0xffffff, data[1].data_field);
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_parse_log_packets() {
+    let mut info = vec![0; 48];
+    info[0] = 1;
+    info[4..8].copy_from_slice(&0x10_0000u32.to_le_bytes());
+    info[24..28].copy_from_slice(&42u32.to_le_bytes());
+    info[28..36].copy_from_slice(&[0xe6, 0x07, 6, 15, 12, 30, 0, 0]);
+    info[44] = 0x28;
+    let mut bytes = ubx_frame(0x21, 0x08, &info);
+
+    bytes.extend(ubx_frame(0x21, 0x0e, &[1, 1, 0, 0, 7, 0, 0, 0]));
+
+    #[rustfmt::skip]
+    let pos = [
+        0x05, 0, 0, 0,
+        0x80, 0x96, 0x98, 0x00, // lon 1.0
+        0x00, 0x2d, 0x31, 0x01, // lat 2.0
+        0x88, 0x13, 0, 0,       // h_msl 5 m
+        0xe8, 0x03, 0, 0,       // h_acc 1 m
+        0xd0, 0x07, 0, 0,       // g_speed 2 m/s
+        0x40, 0x4b, 0x4c, 0x00, // heading 50 degrees
+        0, 3,
+        0xe6, 0x07, 6, 15, 12, 30, 45,
+        0, 9, 0,
+    ];
+    bytes.extend(ubx_frame(0x21, 0x0b, &pos));
+
+    let mut pos_extra = vec![6, 0, 0, 0, 0, 0, 0xe6, 0x07, 6, 15, 12, 31, 0, 0, 0, 0];
+    pos_extra.extend_from_slice(&1500u32.to_le_bytes());
+    pos_extra.extend_from_slice(&[0; 12]);
+    bytes.extend(ubx_frame(0x21, 0x0f, &pos_extra));
+
+    let mut batch = vec![0; 100];
+    batch[1] = 0x03;
+    batch[2..4].copy_from_slice(&7u16.to_le_bytes());
+    batch[24] = 3;
+    batch[80..82].copy_from_slice(&150u16.to_le_bytes());
+    batch[84..88].copy_from_slice(&1200u32.to_le_bytes());
+    bytes.extend(ubx_frame(0x21, 0x11, &batch));
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::LogInfo(pack))) => {
+            assert_eq!(0x10_0000, pack.filestore_capacity());
+            assert_eq!(42, pack.entry_count());
+            assert_eq!(2022, pack.oldest_year());
+            assert_eq!(30, pack.oldest_minute());
+            assert_eq!(
+                LogInfoStatus::RECORDING | LogInfoStatus::CIRCULAR,
+                pack.status()
+            );
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::LogFindTimeResponse(pack))) => assert_eq!(7, pack.entry_number()),
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::LogRetrievePos(pack))) => {
+            assert_eq!(5, pack.entry_index());
+            assert_eq!(LogFixType::Fix3D, pack.fix_type());
+            assert_eq!(9, pack.num_sv());
+            let position = Position::from(&pack);
+            assert!((position.lon - 1.0).abs() < 1e-9);
+            assert!((position.lat - 2.0).abs() < 1e-9);
+            assert!((position.alt - 5.0).abs() < 1e-9);
+            assert!((pack.h_acc() - 1.0).abs() < 1e-9);
+            let velocity = Velocity::from(&pack);
+            assert!((velocity.speed - 2.0).abs() < 1e-9);
+            assert!((velocity.heading - 50.0).abs() < 1e-9);
+            assert_eq!(
+                Utc.from_utc_datetime(
+                    &NaiveDate::from_ymd_opt(2022, 6, 15)
+                        .and_then(|date| date.and_hms_opt(12, 30, 45))
+                        .unwrap()
+                ),
+                DateTime::<Utc>::try_from(&pack).unwrap()
+            );
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::LogRetrievePosExtra(pack))) => {
+            assert_eq!(6, pack.entry_index());
+            assert_eq!(1500, pack.distance());
+            assert_eq!(
+                Utc.from_utc_datetime(
+                    &NaiveDate::from_ymd_opt(2022, 6, 15)
+                        .and_then(|date| date.and_hms_opt(12, 31, 0))
+                        .unwrap()
+                ),
+                DateTime::<Utc>::try_from(&pack).unwrap()
+            );
+        }
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(PacketRef::LogBatch(pack))) => {
+            assert_eq!(
+                LogBatchContentValid::EXTRA_PVT | LogBatchContentValid::EXTRA_ODO,
+                pack.content_valid()
+            );
+            assert_eq!(7, pack.msg_cnt());
+            assert_eq!(GpsFix::Fix3D, pack.fix_type());
+            assert!((pack.p_dop() - 1.5).abs() < 1e-9);
+            assert_eq!(1200, pack.distance());
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_log_requests() {
+    let packet = LogCreateBuilder {
+        version: 0,
+        log_cfg: LogCfg::CIRCULAR,
+        reserved1: 0,
+        log_size: LogSize::UserDefined,
+        user_defined_size: 0x8000,
+    }
+    .into_packet_bytes();
+    assert_eq!(
+        ubx_frame(0x21, 0x07, &[0, 1, 0, 2, 0, 0x80, 0, 0]),
+        packet.to_vec()
+    );
+
+    let packet = LogStringBuilder { bytes: b"lap 1" }.into_packet_vec();
+    assert_eq!(Ok(ubx_frame(0x21, 0x04, b"lap 1")), packet);
+    assert_eq!(
+        LogStringBuilder {
+            bytes: &[b'a'; 257]
+        }
+        .into_packet_vec(),
+        Err(BuilderError::TooLong {
+            field: "bytes",
+            len: 257,
+            max: 256
+        })
+    );
+
+    let time = Utc.from_utc_datetime(
+        &NaiveDate::from_ymd_opt(2022, 6, 15)
+            .and_then(|date| date.and_hms_opt(12, 30, 45))
+            .unwrap(),
+    );
+    let packet = LogFindTimeBuilder::at(&time).into_packet_bytes();
+    assert_eq!(
+        ubx_frame(0x21, 0x0e, &[0, 0, 0, 0, 0xe6, 0x07, 6, 15, 12, 30, 45, 0]),
+        packet.to_vec()
+    );
+
+    let string = ubx_frame(
+        0x21,
+        0x0d,
+        &[
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, b'a', b'b', b'c',
+        ],
+    );
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&string);
+    match it.next() {
+        Some(Ok(PacketRef::LogRetrieveString(pack))) => {
+            assert_eq!(1, pack.entry_index());
+            assert_eq!(3, pack.byte_count());
+            assert_eq!(
+                vec![b'a', b'b', b'c'],
+                pack.bytes().copied().collect::<Vec<_>>()
+            );
+            assert_eq!(Some("abc"), pack.as_str());
+            assert!(matches!(
+                DateTime::<Utc>::try_from(&pack),
+                Err(DateTimeError::InvalidDate)
+            ));
+        }
+        _ => panic!(),
+    }
+
+    // byte_count doesn't match the size of the string
+    let string = ubx_frame(
+        0x21,
+        0x0d,
+        &[
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, b'a', b'b', b'c',
+        ],
+    );
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&string);
+    assert!(matches!(it.next(), Some(Ok(PacketRef::Unknown(_)))));
+}
+
+#[test]
+fn test_cfg_gnss() {
+    #[rustfmt::skip]
+    let payload = [
+        0x00, 0x20, 0x20, 0x03,
+        // GPS: 8..16 channels, enabled, L1C/A
+        0x00, 0x08, 0x10, 0x00, 0x01, 0x00, 0x01, 0x00,
+        // SBAS: 1..3 channels, enabled, L1C/A
+        0x01, 0x01, 0x03, 0x00, 0x01, 0x00, 0x01, 0x00,
+        // GLONASS: 8..14 channels, disabled, L1 and L2
+        0x06, 0x08, 0x0e, 0x00, 0x00, 0x00, 0x11, 0x00,
+    ];
+    let bytes = ubx_frame(0x06, 0x3e, &payload);
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    let current = match it.next() {
+        Some(Ok(PacketRef::CfgGnss(pack))) => pack,
+        _ => panic!(),
+    };
+    assert_eq!(current.num_trk_ch_hw(), 32);
+    assert_eq!(current.num_config_blocks(), 3);
+    let mut blocks: Vec<CfgGnssBlock> = current.blocks().collect();
+    assert_eq!(
+        blocks[0],
+        CfgGnssBlock {
+            res_trk_ch: 8,
+            max_trk_ch: 16,
+            enable: true,
+            signals: CfgGnssSignals::Gps(CfgGnssGpsSignals::L1CA),
+        }
+    );
+    assert_eq!(blocks[1].gnss_id(), GnssId::Sbas);
+    assert_eq!(
+        blocks[2].signals,
+        CfgGnssSignals::Glonass(CfgGnssGlonassSignals::L1 | CfgGnssGlonassSignals::L2)
+    );
+    assert!(!blocks[2].enable);
+
+    // Invalid size of the blocks
+    let invalid = ubx_frame(0x06, 0x3e, &payload[..18]);
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&invalid);
+    assert!(matches!(it.next(), Some(Ok(PacketRef::Unknown(_)))));
+
+    // The number of hardware channels is read only, and sent as 0
+    let builder = CfgGnssBuilder {
+        msg_ver: 0,
+        num_trk_ch_use: 32,
+        blocks: &blocks,
+    };
+    let mut expected = payload.to_vec();
+    expected[1] = 0;
+    assert_eq!(
+        builder.into_packet_vec(),
+        Ok(ubx_frame(0x06, 0x3e, &expected))
+    );
+
+    // Supports GPS, GLONASS and Galileo, two of them simultaneously
+    let mon_gnss = ubx_frame(0x0a, 0x28, &[0x00, 0x0b, 0x03, 0x03, 0x02, 0, 0, 0]);
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&mon_gnss);
+    let mon_gnss = match it.next() {
+        Some(Ok(PacketRef::MonGnss(pack))) => pack,
+        _ => panic!(),
+    };
+
+    blocks[2].enable = true;
+    let builder = CfgGnssBuilder {
+        msg_ver: 0,
+        num_trk_ch_use: 0xff,
+        blocks: &blocks,
+    };
+    assert_eq!(builder.validate(&current, &mon_gnss), Ok(()));
+
+    let builder = CfgGnssBuilder {
+        num_trk_ch_use: 33,
+        ..builder
+    };
+    assert_eq!(
+        builder.validate(&current, &mon_gnss),
+        Err(CfgGnssError::TooManyChannels {
+            channels: 33,
+            hardware: 32
+        })
+    );
+
+    let builder = CfgGnssBuilder {
+        num_trk_ch_use: 16,
+        ..builder
+    };
+    assert_eq!(
+        builder.validate(&current, &mon_gnss),
+        Err(CfgGnssError::ReservedChannels {
+            reserved: 17,
+            channels: 16
+        })
+    );
+
+    blocks.push(CfgGnssBlock {
+        res_trk_ch: 4,
+        max_trk_ch: 8,
+        enable: true,
+        signals: CfgGnssSignals::Galileo(CfgGnssGalileoSignals::E1),
+    });
+    let builder = CfgGnssBuilder {
+        msg_ver: 0,
+        num_trk_ch_use: 32,
+        blocks: &blocks,
+    };
+    assert_eq!(
+        builder.validate(&current, &mon_gnss),
+        Err(CfgGnssError::TooManyGnss {
+            enabled: 3,
+            simultaneous: 2
+        })
+    );
+
+    blocks[3].signals = CfgGnssSignals::BeiDou(CfgGnssBeiDouSignals::B1I);
+    let builder = CfgGnssBuilder {
+        msg_ver: 0,
+        num_trk_ch_use: 32,
+        blocks: &blocks,
+    };
+    assert_eq!(
+        builder.validate(&current, &mon_gnss),
+        Err(CfgGnssError::UnsupportedGnss {
+            gnss_id: GnssId::BeiDou
+        })
+    );
+
+    let blocks = vec![blocks[0]; 256];
+    let builder = CfgGnssBuilder {
+        msg_ver: 0,
+        num_trk_ch_use: 32,
+        blocks: &blocks,
+    };
+    assert_eq!(
+        builder.into_packet_vec(),
+        Err(BuilderError::TooLong {
+            field: "blocks",
+            len: 256,
+            max: 255
+        })
+    );
+}
+
+#[test]
+fn test_cfg_power_management() {
+    let pms = CfgPmsBuilder {
+        power_setup_value: CfgPmsPowerSetup::Interval,
+        period: 60,
+        on_time: 10,
+        ..CfgPmsBuilder::default()
+    }
+    .into_packet_bytes();
+    assert_eq!(
+        pms.to_vec(),
+        ubx_frame(
+            0x06,
+            0x86,
+            &[0x00, 0x02, 0x3c, 0x00, 0x0a, 0x00, 0x00, 0x00]
+        )
+    );
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&pms);
+    match it.next() {
+        Some(Ok(PacketRef::CfgPms(pack))) => {
+            assert_eq!(pack.power_setup_value(), CfgPmsPowerSetup::Interval);
+            assert_eq!(pack.period(), 60);
+            assert_eq!(pack.on_time(), 10);
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+
+    // Reserved power setup value
+    let invalid = ubx_frame(0x06, 0x86, &[0x00, 0x06, 0, 0, 0, 0, 0, 0]);
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&invalid);
+    assert!(matches!(it.next(), Some(Ok(PacketRef::Unknown(_)))));
+
+    let flags = CfgPm2Flags {
+        extint_wake: true,
+        update_rtc: true,
+        update_eph: true,
+        mode: CfgPm2Mode::CyclicTracking,
+        ..CfgPm2Flags::default()
+    };
+    let pm2 = CfgPm2Builder {
+        flags,
+        update_period: 10_000,
+        search_period: 20_000,
+        on_time: 2,
+        ..CfgPm2Builder::default()
+    }
+    .into_packet_bytes();
+    let mut payload = vec![0x02, 0x00, 0x00, 0x00, 0x20, 0x18, 0x02, 0x00];
+    payload.extend_from_slice(&[0x10, 0x27, 0x00, 0x00, 0x20, 0x4e, 0x00, 0x00]);
+    payload.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00]);
+    payload.extend_from_slice(&[0; 24]);
+    assert_eq!(pm2.to_vec(), ubx_frame(0x06, 0x3b, &payload));
+
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&pm2);
+    match it.next() {
+        Some(Ok(PacketRef::CfgPm2(pack))) => {
+            assert_eq!(pack.flags(), flags);
+            assert_eq!(pack.update_period(), 10_000);
+            assert_eq!(pack.search_period(), 20_000);
+            assert_eq!(pack.on_time(), 2);
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_sbas_dgnss() {
+    let sbas = CfgSbasBuilder {
+        mode: CfgSbasMode::ENABLED,
+        usage: CfgSbasUsage::RANGE | CfgSbasUsage::DIFF_CORR | CfgSbasUsage::INTEGRITY,
+        max_sbas: 3,
+        scanmode2: CfgSbasScanMode2::empty(),
+        scanmode1: CfgSbasScanMode1::PRN123 | CfgSbasScanMode1::PRN136,
+    }
+    .into_packet_bytes();
+    assert_eq!(
+        sbas.to_vec(),
+        ubx_frame(
+            0x06,
+            0x16,
+            &[0x01, 0x07, 0x03, 0x00, 0x08, 0x00, 0x01, 0x00]
+        )
+    );
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&sbas);
+    match it.next() {
+        Some(Ok(PacketRef::CfgSbas(pack))) => {
+            assert_eq!(pack.mode(), CfgSbasMode::ENABLED);
+            assert!(pack.usage().contains(CfgSbasUsage::INTEGRITY));
+            assert_eq!(
+                pack.scanmode1(),
+                CfgSbasScanMode1::PRN123 | CfgSbasScanMode1::PRN136
+            );
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+
+    let dgnss = CfgDgnssBuilder {
+        dgnss_mode: CfgDgnssMode::RtkFloat,
+        ..CfgDgnssBuilder::default()
+    }
+    .into_packet_bytes();
+    assert_eq!(dgnss.to_vec(), ubx_frame(0x06, 0x70, &[0x02, 0, 0, 0]));
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&dgnss);
+    match it.next() {
+        Some(Ok(PacketRef::CfgDgnss(pack))) => {
+            assert_eq!(pack.dgnss_mode(), CfgDgnssMode::RtkFloat)
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+
+    #[rustfmt::skip]
+    let payload = [
+        // iTOW, EGNOS GEO 136 with integrity, ranging, corrections and integrity services
+        0x10, 0x27, 0x00, 0x00, 0x88, 0x01, 0x01, 0x07, 0x02, 0x02, 0x00, 0x00,
+        // GPS 5, PRC 1.5 m, IC -0.25 m
+        0x05, 0x00, 0x02, 0x10, 0x06, 0x00, 0x96, 0x00, 0x00, 0x00, 0xe7, 0xff,
+        // GPS 12, monitoring status 15
+        0x0c, 0x00, 0x0f, 0x10, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let bytes = ubx_frame(0x01, 0x32, &payload);
+    let mut parser = Parser::default();
+    let mut it = parser.consume(&bytes);
+    match it.next() {
+        Some(Ok(PacketRef::NavSbas(pack))) => {
+            assert_eq!(pack.itow(), 10_000);
+            assert_eq!(pack.geo(), 136);
+            assert_eq!(pack.mode(), NavSbasMode::EnabledIntegrity);
+            assert_eq!(pack.sys(), SbasSystem::Egnos);
+            assert_eq!(
+                pack.service(),
+                SbasService::RANGING | SbasService::CORRECTIONS | SbasService::INTEGRITY
+            );
+            assert_eq!(pack.cnt(), 2);
+            assert_eq!(pack.integrity_used(), Some(true));
+
+            let svs: Vec<_> = pack.svs().collect();
+            assert_eq!(svs.len(), 2);
+            assert_eq!(svs[0].sv_id(), 5);
+            assert_eq!(svs[0].udre(), 2);
+            assert_eq!(svs[0].sv_sys(), SbasSystem::Gps);
+            assert_eq!(
+                svs[0].sv_service(),
+                SbasService::CORRECTIONS | SbasService::INTEGRITY
+            );
+            assert!((svs[0].prc() - 1.5).abs() < 1e-9);
+            assert!((svs[0].ic() + 0.25).abs() < 1e-9);
+            assert_eq!(svs[1].sv_id(), 12);
+        }
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
 #[test]
 #[cfg(feature = "serde")]
 fn test_esf_meas_serialize() {
@@ -229,6 +1770,8 @@ fn test_esf_meas_serialize() {
                 let actual = serde_json::to_value(&pack).unwrap();
                 assert_eq!(expected, actual);
                 if let PacketRef::EsfMeas(pack) = pack {
+                    let data: Vec<_> = pack.data().collect();
+                    assert_eq!(EsfSensorType::Speed, data[0].sensor_type());
                     let expected = serde_json::json! {
                         {
                           "time_tag": 25262579,
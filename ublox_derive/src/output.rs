@@ -63,6 +63,63 @@ fn generate_serialize_impl(
     }
 }
 
+fn generate_owned_impl(pack_descr: &PackDesc, ref_name: &Ident) -> TokenStream {
+    let owned_name = format_ident!("{}Owned", pack_descr.name);
+    let struct_comment = &pack_descr.comment;
+    quote! {
+        #[doc = #struct_comment]
+        #[doc = "Owns a copy of the packet payload, use `as_packet_ref` to access the data."]
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        #[derive(Clone)]
+        pub struct #owned_name(Vec<u8>);
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl #owned_name {
+            #[inline]
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            #[inline]
+            pub fn as_packet_ref(&self) -> #ref_name<'_> {
+                #ref_name(&self.0)
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl<'a> #ref_name<'a> {
+            #[inline]
+            pub fn to_owned(&self) -> #owned_name {
+                #owned_name(self.0.to_vec())
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl core::fmt::Debug for #owned_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.as_packet_ref().fmt(f)
+            }
+        }
+
+        #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+        impl serde::Serialize for #owned_name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.as_packet_ref().serialize(serializer)
+            }
+        }
+    }
+}
+
+/// `PacketRef` -> `PacketOwned`, `UbxUnknownPacketRef` -> `UbxUnknownPacketOwned`
+fn owned_ident(ref_ident: &Ident) -> Ident {
+    let name = ref_ident.to_string();
+    let name = name.strip_suffix("Ref").unwrap_or(&name);
+    format_ident!("{}Owned", name)
+}
+
 pub fn generate_recv_code_for_packet(pack_descr: &PackDesc) -> TokenStream {
     let pack_name = &pack_descr.name;
     let ref_name = format_ident!("{}Ref", pack_descr.name);
@@ -241,6 +298,7 @@ pub fn generate_recv_code_for_packet(pack_descr: &PackDesc) -> TokenStream {
 
     let debug_impl = generate_debug_impl(pack_name, &ref_name, pack_descr);
     let serialize_impl = generate_serialize_impl(pack_name, &ref_name, pack_descr);
+    let owned_impl = generate_owned_impl(pack_descr, &ref_name);
 
     quote! {
         #[doc = #struct_comment]
@@ -259,6 +317,7 @@ pub fn generate_recv_code_for_packet(pack_descr: &PackDesc) -> TokenStream {
 
         #debug_impl
         #serialize_impl
+        #owned_impl
     }
 }
 
@@ -444,6 +503,7 @@ pub fn generate_send_code_for_packet(pack_descr: &PackDesc) -> TokenStream {
     } else {
         ret.extend(quote! {
           impl #payload_struct_lifetime #payload_struct #payload_struct_lifetime {
+              #[cfg(any(feature = "std", feature = "alloc"))]
               #[inline]
               pub fn into_packet_vec(self) -> Vec<u8> {
                 let mut vec = Vec::new();
@@ -702,17 +762,35 @@ pub fn generate_code_to_extend_bitflags(bitflags: BitFlagsMacro) -> syn::Result<
 
 pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
     let union_enum_name = &recv_packs.union_enum_name;
+    let owned_enum_name = owned_ident(union_enum_name);
 
     let mut pack_enum_variants = Vec::with_capacity(recv_packs.all_packets.len());
     let mut matches = Vec::with_capacity(recv_packs.all_packets.len());
     let mut class_id_matches = Vec::with_capacity(recv_packs.all_packets.len());
     let mut serializers = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut owned_enum_variants = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut owned_class_id_matches = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut to_owned_matches = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut as_ref_matches = Vec::with_capacity(recv_packs.all_packets.len());
 
     for name in &recv_packs.all_packets {
         let ref_name = format_ident!("{}Ref", name);
+        let owned_name = format_ident!("{}Owned", name);
         pack_enum_variants.push(quote! {
             #name(#ref_name <'a>)
         });
+        owned_enum_variants.push(quote! {
+            #name(#owned_name)
+        });
+        owned_class_id_matches.push(quote! {
+            #owned_enum_name::#name(_) => (#name::CLASS, #name::ID)
+        });
+        to_owned_matches.push(quote! {
+            #union_enum_name::#name(ref pack) => #owned_enum_name::#name(pack.to_owned())
+        });
+        as_ref_matches.push(quote! {
+            #owned_enum_name::#name(ref pack) => #union_enum_name::#name(pack.as_packet_ref())
+        });
 
         matches.push(quote! {
             (#name::CLASS, #name::ID) if <#ref_name>::validate(payload).is_ok()  => {
@@ -735,6 +813,7 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
     }
 
     let unknown_var = &recv_packs.unknown_ty;
+    let unknown_owned = owned_ident(unknown_var);
 
     let max_payload_len_calc = recv_packs
         .all_packets
@@ -795,6 +874,51 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
                 }
             }
         }
+
+        #[doc = "All possible packets enum, owning the packet data"]
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        #[derive(Debug, Clone)]
+        pub enum #owned_enum_name {
+            #(#owned_enum_variants),*,
+            Unknown(#unknown_owned)
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl #owned_enum_name {
+            pub fn class_and_msg_id(&self) -> (u8, u8) {
+                match *self {
+                    #(#owned_class_id_matches),*,
+                    #owned_enum_name::Unknown(ref pack) => (pack.class, pack.msg_id),
+                }
+            }
+
+            pub fn as_packet_ref(&self) -> #union_enum_name<'_> {
+                match *self {
+                    #(#as_ref_matches),*,
+                    #owned_enum_name::Unknown(ref pack) => #union_enum_name::Unknown(pack.as_packet_ref()),
+                }
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl<'a> #union_enum_name<'a> {
+            pub fn to_owned(&self) -> #owned_enum_name {
+                match *self {
+                    #(#to_owned_matches),*,
+                    #union_enum_name::Unknown(ref pack) => #owned_enum_name::Unknown(pack.to_owned()),
+                }
+            }
+        }
+
+        #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+        impl serde::Serialize for #owned_enum_name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.as_packet_ref().serialize(serializer)
+            }
+        }
     }
 }
 
@@ -187,6 +187,49 @@ fn test_ubx_packet_recv_simple() {
                     state.end()
                 }
             }
+            #[doc = "Some comment"]
+            #[doc = "Owns a copy of the packet payload, use `as_packet_ref` to access the data."]
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            #[derive(Clone)]
+            pub struct TestOwned(Vec<u8>);
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            impl TestOwned {
+                #[inline]
+                pub fn as_bytes(&self) -> &[u8] {
+                    &self.0
+                }
+
+                #[inline]
+                pub fn as_packet_ref(&self) -> TestRef<'_> {
+                    TestRef(&self.0)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            impl<'a> TestRef<'a> {
+                #[inline]
+                pub fn to_owned(&self) -> TestOwned {
+                    TestOwned(self.0.to_vec())
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            impl core::fmt::Debug for TestOwned {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.as_packet_ref().fmt(f)
+                }
+            }
+
+            #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+            impl serde::Serialize for TestOwned {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    self.as_packet_ref().serialize(serializer)
+                }
+            }
         },
     );
 }
@@ -294,6 +337,49 @@ fn test_ubx_packet_recv_dyn_len() {
                     state.end()
                 }
             }
+            #[doc = ""]
+            #[doc = "Owns a copy of the packet payload, use `as_packet_ref` to access the data."]
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            #[derive(Clone)]
+            pub struct TestOwned(Vec<u8>);
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            impl TestOwned {
+                #[inline]
+                pub fn as_bytes(&self) -> &[u8] {
+                    &self.0
+                }
+
+                #[inline]
+                pub fn as_packet_ref(&self) -> TestRef<'_> {
+                    TestRef(&self.0)
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            impl<'a> TestRef<'a> {
+                #[inline]
+                pub fn to_owned(&self) -> TestOwned {
+                    TestOwned(self.0.to_vec())
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            impl core::fmt::Debug for TestOwned {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.as_packet_ref().fmt(f)
+                }
+            }
+
+            #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+            impl serde::Serialize for TestOwned {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    self.as_packet_ref().serialize(serializer)
+                }
+            }
         },
     );
 }
@@ -587,6 +673,55 @@ fn test_define_recv_packets() {
                     }
                 }
             }
+
+            #[doc = "All possible packets enum, owning the packet data"]
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            #[derive(Debug, Clone)]
+            pub enum PacketOwned {
+                Pack1(Pack1Owned),
+                Pack2(Pack2Owned),
+                Unknown(UnknownPacketOwned),
+            }
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            impl PacketOwned {
+                pub fn class_and_msg_id(&self) -> (u8, u8) {
+                    match *self {
+                        PacketOwned::Pack1(_) => (Pack1::CLASS, Pack1::ID),
+                        PacketOwned::Pack2(_) => (Pack2::CLASS, Pack2::ID),
+                        PacketOwned::Unknown(ref pack) => (pack.class, pack.msg_id),
+                    }
+                }
+
+                pub fn as_packet_ref(&self) -> PacketRef<'_> {
+                    match *self {
+                        PacketOwned::Pack1(ref pack) => PacketRef::Pack1(pack.as_packet_ref()),
+                        PacketOwned::Pack2(ref pack) => PacketRef::Pack2(pack.as_packet_ref()),
+                        PacketOwned::Unknown(ref pack) => PacketRef::Unknown(pack.as_packet_ref()),
+                    }
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            impl<'a> PacketRef<'a> {
+                pub fn to_owned(&self) -> PacketOwned {
+                    match *self {
+                        PacketRef::Pack1(ref pack) => PacketOwned::Pack1(pack.to_owned()),
+                        PacketRef::Pack2(ref pack) => PacketOwned::Pack2(pack.to_owned()),
+                        PacketRef::Unknown(ref pack) => PacketOwned::Unknown(pack.to_owned()),
+                    }
+                }
+            }
+
+            #[cfg(all(feature = "serde", any(feature = "std", feature = "alloc")))]
+            impl serde::Serialize for PacketOwned {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    self.as_packet_ref().serialize(serializer)
+                }
+            }
         },
     );
 }